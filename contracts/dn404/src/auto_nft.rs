@@ -0,0 +1,181 @@
+use crate::{available_tokens::TokenIdNoncePair, NFT_AMOUNT};
+
+use multiversx_sc::imports::*;
+
+/// Fractal tokens routed through the contract are kept on an internal balance.
+/// Every whole unit of that balance is mirrored by an NFT taken from the basket of goods,
+/// in basket order, so the selection is deterministic.
+/// Only NFTs whose price is covered by one unit are released.
+/// An NFT is reclaimed when the balance drops below a whole unit:
+/// the sender has to return it together with the call that lowers the balance.
+#[multiversx_sc::module]
+pub trait AutoNftModule:
+    crate::available_tokens::AvailableTokensModule
+    + crate::price::PriceModule
+    + crate::fee::FeeModule
+    + multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
+    + multiversx_sc_modules::pause::PauseModule
+    + multiversx_sc_modules::only_admin::OnlyAdminModule
+{
+    /// Amount of fractal tokens mirrored by one NFT. 0 disables the auto mint mode.
+    #[only_owner]
+    #[endpoint(setAutoNftUnit)]
+    fn set_auto_nft_unit(&self, unit_amount: BigUint) {
+        self.auto_nft_unit().set(unit_amount);
+    }
+
+    /// Users that opt out do not receive NFTs anymore. NFTs they already hold still have to be returned.
+    #[endpoint(setAutoNftOptOut)]
+    fn set_auto_nft_opt_out(&self, opt_out: bool) {
+        let caller = self.blockchain().get_caller();
+        self.auto_nft_opt_out(&caller).set(opt_out);
+    }
+
+    /// Fractal tokens in the payment are added to the caller's balance, NFTs in the payment are returned.
+    /// Then `amount` is moved from the caller's balance to the receiver's.
+    #[payable]
+    #[endpoint(transferFractal)]
+    fn transfer_fractal(&self, to: ManagedAddress, amount: BigUint) {
+        self.require_not_paused();
+        let unit_amount = self.require_auto_nft_enabled();
+
+        let caller = self.blockchain().get_caller();
+        self.process_routed_payments(&caller);
+
+        let sender_balance_mapper = self.routed_balance(&caller);
+        let sender_balance = sender_balance_mapper.get();
+        require!(amount <= sender_balance, "Not enough tokens");
+
+        sender_balance_mapper.set(sender_balance - &amount);
+        self.routed_balance(&to)
+            .update(|balance| *balance += amount);
+
+        self.sync_linked_nfts(&caller, &unit_amount);
+        if to != caller {
+            self.sync_linked_nfts(&to, &unit_amount);
+        }
+    }
+
+    /// Sends fractal tokens from the caller's balance back to its wallet.
+    #[payable]
+    #[endpoint(withdrawFractal)]
+    fn withdraw_fractal(&self, amount: BigUint) {
+        self.require_not_paused();
+        let unit_amount = self.require_auto_nft_enabled();
+
+        let caller = self.blockchain().get_caller();
+        self.process_routed_payments(&caller);
+
+        let balance_mapper = self.routed_balance(&caller);
+        let balance = balance_mapper.get();
+        require!(amount <= balance, "Not enough tokens");
+
+        balance_mapper.set(balance - &amount);
+        self.sync_linked_nfts(&caller, &unit_amount);
+
+        if amount > 0 {
+            let token_id = self.fractal_token().get_token_id();
+            self.tx()
+                .to(ToCaller)
+                .single_esdt(&token_id, 0, &amount)
+                .transfer();
+        }
+    }
+
+    fn require_auto_nft_enabled(&self) -> BigUint {
+        let unit_amount = self.auto_nft_unit().get();
+        require!(unit_amount > 0, "Auto NFT mode is disabled");
+
+        unit_amount
+    }
+
+    fn process_routed_payments(&self, caller: &ManagedAddress) {
+        let payments = self.call_value().all_esdt_transfers().clone();
+        let fractal_token_id = self.fractal_token().get_token_id();
+        let mut linked_nfts = self.linked_nfts(caller);
+        let mut basket = self.basket_of_goods();
+        for payment in payments {
+            if payment.token_identifier == fractal_token_id {
+                self.routed_balance(caller)
+                    .update(|balance| *balance += &payment.amount);
+
+                continue;
+            }
+
+            require!(payment.amount == NFT_AMOUNT, "Invalid payment");
+            require!(
+                linked_nfts.swap_remove(&TokenIdNoncePair {
+                    token_id: payment.token_identifier.clone(),
+                    nonce: payment.token_nonce,
+                }),
+                "NFT was not received from the auto mint"
+            );
+
+            self.add_tokens(&mut basket, payment);
+        }
+    }
+
+    /// The address must hold at most one linked NFT for each whole unit of its balance.
+    /// Addresses that did not opt out receive NFTs priced at most one unit until every whole unit is mirrored.
+    fn sync_linked_nfts(&self, address: &ManagedAddress, unit_amount: &BigUint) {
+        let units = self.routed_balance(address).get() / unit_amount;
+        let mut linked_nfts = self.linked_nfts(address);
+        let linked_count = BigUint::from(linked_nfts.len());
+        require!(
+            linked_count <= units,
+            "Linked NFTs have to be returned when the balance drops below a whole unit"
+        );
+
+        if self.auto_nft_opt_out(address).get() {
+            return;
+        }
+
+        let mut basket = self.basket_of_goods();
+        let mut nfts_to_send = ManagedVec::<Self::Api, EsdtTokenPayment>::new();
+        let mut missing_count = units - linked_count;
+        let mut index = 1;
+        while missing_count > 0 && index <= basket.len() {
+            // an address holds at most one unit of each basket item
+            let next_token = basket.get_by_index(index);
+            if linked_nfts.contains(&next_token)
+                || &self.try_get_price(&next_token.token_id, next_token.nonce) > unit_amount
+            {
+                index += 1;
+                continue;
+            }
+
+            self.remove_token(&mut basket, next_token.token_id.clone(), next_token.nonce);
+
+            nfts_to_send.push(EsdtTokenPayment::new(
+                next_token.token_id.clone(),
+                next_token.nonce,
+                BigUint::from(NFT_AMOUNT),
+            ));
+            let _ = linked_nfts.insert(next_token);
+            missing_count -= 1u32;
+        }
+
+        if !nfts_to_send.is_empty() {
+            self.tx().to(address).payment(&nfts_to_send).transfer();
+        }
+    }
+
+    #[view(getAutoNftUnit)]
+    #[storage_mapper("autoNftUnit")]
+    fn auto_nft_unit(&self) -> SingleValueMapper<BigUint>;
+
+    #[view(isAutoNftOptOut)]
+    #[storage_mapper("autoNftOptOut")]
+    fn auto_nft_opt_out(&self, address: &ManagedAddress) -> SingleValueMapper<bool>;
+
+    #[view(getRoutedBalance)]
+    #[storage_mapper("routedBalance")]
+    fn routed_balance(&self, address: &ManagedAddress) -> SingleValueMapper<BigUint>;
+
+    #[view(getLinkedNfts)]
+    #[storage_mapper("linkedNfts")]
+    fn linked_nfts(
+        &self,
+        address: &ManagedAddress,
+    ) -> UnorderedSetMapper<TokenIdNoncePair<Self::Api>>;
+}
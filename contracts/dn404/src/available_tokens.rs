@@ -40,7 +40,9 @@ pub trait AvailableTokensModule:
         let mut basket = self.basket_of_goods();
         let mut total_output_payment = BigUint::zero();
         for payment in payments {
-            let price = self.try_get_price(&payment.token_identifier, payment.token_nonce);
+            let price = self
+                .get_price(&payment.token_identifier, payment.token_nonce)
+                .unwrap_or_else(|| sc_panic!("No valid price, deposit refused"));
             let mut price_as_payment = EsdtTokenPayment::new(token_id.clone(), 0, price);
 
             let fee_for_token = self.get_fee(&payment.token_identifier, payment.token_nonce);
@@ -81,7 +83,7 @@ pub trait AvailableTokensModule:
         let mut basket = self.basket_of_goods();
         for token_to_claim in tokens_to_claim {
             let token_as_payment = token_to_claim.into_inner();
-            let price = self.try_get_price(
+            let price = self.get_named_claim_price(
                 &token_as_payment.token_identifier,
                 token_as_payment.token_nonce,
             );
@@ -110,6 +112,58 @@ pub trait AvailableTokensModule:
         self.tx().to(ToCaller).payment(&tokens_vec).transfer();
     }
 
+    /// Draws `count` random tokens from the basket, each paid at the floor price of its collection.
+    /// Smart contracts cannot claim, so the draw cannot be inspected and reverted by the caller.
+    #[payable]
+    #[endpoint(claimRandom)]
+    fn claim_random(&self, count: usize) {
+        self.require_not_paused();
+        require!(count > 0, "No tokens to claim");
+
+        let caller = self.blockchain().get_caller();
+        require!(
+            !self.blockchain().is_smart_contract(&caller),
+            "Only user accounts can claim random tokens"
+        );
+
+        let token_mapper = self.fractal_token();
+        let (payment_token, payment_amount) = self.call_value().single_fungible_esdt();
+        token_mapper.require_same_token(&payment_token);
+
+        let mut total_cost = BigUint::zero();
+        let mut tokens_vec = ManagedVec::<Self::Api, EsdtTokenPayment>::new();
+        let mut basket = self.basket_of_goods();
+        let mut rand_source = RandomnessSource::new();
+        for _ in 0..count {
+            require!(!basket.is_empty(), "Not enough tokens to claim");
+
+            let rand_index = rand_source.next_usize_in_range(1, basket.len() + 1);
+            let token = basket.get_by_index(rand_index);
+            total_cost += self.try_get_floor_price(&token.token_id);
+
+            self.remove_token(&mut basket, token.token_id.clone(), token.nonce);
+
+            tokens_vec.push(EsdtTokenPayment::new(
+                token.token_id,
+                token.nonce,
+                BigUint::from(NFT_AMOUNT),
+            ));
+        }
+
+        require!(*payment_amount >= total_cost, "Not enough tokens");
+
+        token_mapper.burn(&total_cost);
+
+        let remaining_tokens = payment_amount.clone() - total_cost;
+        let remaining_tokens_payment =
+            EsdtTokenPayment::new(payment_token.clone(), 0, remaining_tokens);
+        self.tx()
+            .to(ToCaller)
+            .payment(&remaining_tokens_payment)
+            .transfer();
+        self.tx().to(ToCaller).payment(&tokens_vec).transfer();
+    }
+
     fn add_tokens(
         &self,
         mapper: &mut UnorderedSetMapper<TokenIdNoncePair<Self::Api>>,
@@ -140,6 +194,53 @@ pub trait AvailableTokensModule:
         }
     }
 
+    /// Number of tokens left in the basket for each collection
+    #[view(getBasketComposition)]
+    fn get_basket_composition(&self) -> MultiValueEncoded<MultiValue2<TokenIdentifier, BigUint>> {
+        let mut collections = ManagedVec::<Self::Api, TokenIdentifier>::new();
+        let mut amounts = ManagedVec::<Self::Api, BigUint>::new();
+        for token in self.basket_of_goods().iter() {
+            let amount = self.remaining_tokens(&token.token_id, token.nonce).get();
+            match collections
+                .iter()
+                .position(|collection| *collection == token.token_id)
+            {
+                Some(index) => {
+                    let total_amount = amounts.get(index).clone() + amount;
+                    let _ = amounts.set(index, total_amount);
+                }
+                None => {
+                    collections.push(token.token_id);
+                    amounts.push(amount);
+                }
+            }
+        }
+
+        let mut composition = MultiValueEncoded::new();
+        for (collection, amount) in collections.iter().zip(amounts.iter()) {
+            composition.push((collection.clone(), amount.clone()).into());
+        }
+
+        composition
+    }
+
+    /// Nonces and amounts of the tokens of the collection left in the basket
+    #[view(getBasketTokensForCollection)]
+    fn get_basket_tokens_for_collection(
+        &self,
+        collection: TokenIdentifier,
+    ) -> MultiValueEncoded<MultiValue2<Nonce, BigUint>> {
+        let mut tokens = MultiValueEncoded::new();
+        for token in self.basket_of_goods().iter() {
+            if token.token_id == collection {
+                let amount = self.remaining_tokens(&token.token_id, token.nonce).get();
+                tokens.push((token.nonce, amount).into());
+            }
+        }
+
+        tokens
+    }
+
     #[view(getBasketOfGoods)]
     #[storage_mapper("basketOfGoods")]
     fn basket_of_goods(&self) -> UnorderedSetMapper<TokenIdNoncePair<Self::Api>>;
@@ -117,6 +117,44 @@ where
             .original_result()
     }
 
+    /// Draws `count` random tokens from the basket, each paid at the floor price of its collection. 
+    /// Smart contracts cannot claim, so the draw cannot be inspected and reverted by the caller. 
+    pub fn claim_random<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        count: Arg0,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("claimRandom")
+            .argument(&count)
+            .original_result()
+    }
+
+    /// Number of tokens left in the basket for each collection 
+    pub fn get_basket_composition(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<TokenIdentifier<Env::Api>, BigUint<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getBasketComposition")
+            .original_result()
+    }
+
+    /// Nonces and amounts of the tokens of the collection left in the basket 
+    pub fn get_basket_tokens_for_collection<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+    >(
+        self,
+        collection: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<u64, BigUint<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getBasketTokensForCollection")
+            .argument(&collection)
+            .original_result()
+    }
+
     pub fn basket_of_goods(
         self,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, TokenIdNoncePair<Env::Api>>> {
@@ -142,6 +180,112 @@ where
             .original_result()
     }
 
+    /// Amount of fractal tokens mirrored by one NFT. 0 disables the auto mint mode. 
+    pub fn set_auto_nft_unit<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        unit_amount: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setAutoNftUnit")
+            .argument(&unit_amount)
+            .original_result()
+    }
+
+    /// Users that opt out do not receive NFTs anymore. NFTs they already hold still have to be returned. 
+    pub fn set_auto_nft_opt_out<
+        Arg0: ProxyArg<bool>,
+    >(
+        self,
+        opt_out: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setAutoNftOptOut")
+            .argument(&opt_out)
+            .original_result()
+    }
+
+    /// Fractal tokens in the payment are added to the caller's balance, NFTs in the payment are returned. 
+    /// Then `amount` is moved from the caller's balance to the receiver's. 
+    pub fn transfer_fractal<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        to: Arg0,
+        amount: Arg1,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("transferFractal")
+            .argument(&to)
+            .argument(&amount)
+            .original_result()
+    }
+
+    /// Sends fractal tokens from the caller's balance back to its wallet. 
+    pub fn withdraw_fractal<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        amount: Arg0,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("withdrawFractal")
+            .argument(&amount)
+            .original_result()
+    }
+
+    pub fn auto_nft_unit(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAutoNftUnit")
+            .original_result()
+    }
+
+    pub fn auto_nft_opt_out<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isAutoNftOptOut")
+            .argument(&address)
+            .original_result()
+    }
+
+    pub fn routed_balance<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getRoutedBalance")
+            .argument(&address)
+            .original_result()
+    }
+
+    pub fn linked_nfts<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, TokenIdNoncePair<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getLinkedNfts")
+            .argument(&address)
+            .original_result()
+    }
+
     pub fn set_internal_price_for_token<
         Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
         Arg1: ProxyArg<u64>,
@@ -177,6 +321,63 @@ where
             .original_result()
     }
 
+    /// The aggregator price takes precedence over the internal collection price, as long as it is not stale. 
+    /// The aggregator must be on the same shard, as its price is read through a synchronous call. 
+    pub fn set_price_source_for_collection<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg2: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg3: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg4: ProxyArg<u64>,
+        Arg5: ProxyArg<u8>,
+    >(
+        self,
+        token_id: Arg0,
+        aggregator: Arg1,
+        from: Arg2,
+        to: Arg3,
+        max_price_age: Arg4,
+        decimals: Arg5,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setPriceSourceForCollection")
+            .argument(&token_id)
+            .argument(&aggregator)
+            .argument(&from)
+            .argument(&to)
+            .argument(&max_price_age)
+            .argument(&decimals)
+            .original_result()
+    }
+
+    pub fn remove_price_source_for_collection<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("removePriceSourceForCollection")
+            .argument(&token_id)
+            .original_result()
+    }
+
+    /// Extra percentage paid on top of the price when claiming a specific token 
+    pub fn set_named_claim_premium<
+        Arg0: ProxyArg<u32>,
+    >(
+        self,
+        premium_percentage: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setNamedClaimPremium")
+            .argument(&premium_percentage)
+            .original_result()
+    }
+
     pub fn try_get_price<
         Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
         Arg1: ProxyArg<u64>,
@@ -193,6 +394,35 @@ where
             .original_result()
     }
 
+    pub fn try_get_floor_price<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getFloorPrice")
+            .argument(&token_id)
+            .original_result()
+    }
+
+    pub fn get_named_claim_price<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        token_id: Arg0,
+        nonce: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getNamedClaimPrice")
+            .argument(&token_id)
+            .argument(&nonce)
+            .original_result()
+    }
+
     pub fn fractal_token(
         self,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, TokenIdentifier<Env::Api>> {
@@ -202,6 +432,28 @@ where
             .original_result()
     }
 
+    pub fn named_claim_premium(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u32> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getNamedClaimPremium")
+            .original_result()
+    }
+
+    pub fn price_source<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+    >(
+        self,
+        collection: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, PriceSource<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPriceSourceForCollection")
+            .argument(&collection)
+            .original_result()
+    }
+
     pub fn set_fee_for_fractionalizing_nft<
         Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
         Arg1: ProxyArg<u64>,
@@ -361,6 +613,22 @@ where
     pub nonce: u64,
 }
 
+/// Floor price feed of a collection, read from the `from`/`to` pair of the price aggregator. 
+/// Prices older than `max_price_age` seconds are ignored. 
+/// The feed price is rescaled to the `decimals` of the fractal token. 
+#[type_abi]
+#[derive(TopEncode, TopDecode)]
+pub struct PriceSource<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub aggregator: ManagedAddress<Api>,
+    pub from: ManagedBuffer<Api>,
+    pub to: ManagedBuffer<Api>,
+    pub max_price_age: u64,
+    pub decimals: u8,
+}
+
 #[type_abi]
 #[derive(TopEncode, TopDecode, NestedEncode, NestedDecode)]
 pub enum FeeType<Api>
@@ -2,10 +2,12 @@
 
 use multiversx_sc::imports::*;
 
+pub mod auto_nft;
 pub mod available_tokens;
 pub mod dn404_proxy;
 pub mod fee;
 pub mod price;
+pub mod price_aggregator_proxy;
 
 pub type Nonce = u64;
 pub type Percentage = u32;
@@ -16,6 +18,7 @@ pub const NFT_AMOUNT: u32 = 1;
 #[multiversx_sc::contract]
 pub trait Dn404:
     available_tokens::AvailableTokensModule
+    + auto_nft::AutoNftModule
     + price::PriceModule
     + fee::FeeModule
     + multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
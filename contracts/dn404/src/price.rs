@@ -1,7 +1,21 @@
-use crate::Nonce;
+use crate::{price_aggregator_proxy, Nonce, Percentage, MAX_PERCENTAGE};
 
+use multiversx_sc::derive_imports::*;
 use multiversx_sc::imports::*;
 
+/// Floor price feed of a collection, read from the `from`/`to` pair of the price aggregator.
+/// Prices older than `max_price_age` seconds are ignored.
+/// The feed price is rescaled to the `decimals` of the fractal token.
+#[type_abi]
+#[derive(TopEncode, TopDecode)]
+pub struct PriceSource<M: ManagedTypeApi> {
+    pub aggregator: ManagedAddress<M>,
+    pub from: ManagedBuffer<M>,
+    pub to: ManagedBuffer<M>,
+    pub max_price_age: u64,
+    pub decimals: u8,
+}
+
 #[multiversx_sc::module]
 pub trait PriceModule {
     #[only_owner]
@@ -21,23 +35,149 @@ pub trait PriceModule {
         self.price_for_collection(&token_id).set(price);
     }
 
+    /// The aggregator price takes precedence over the internal collection price, as long as it is not stale.
+    /// The aggregator must be on the same shard, as its price is read through a synchronous call.
+    #[only_owner]
+    #[endpoint(setPriceSourceForCollection)]
+    fn set_price_source_for_collection(
+        &self,
+        token_id: TokenIdentifier,
+        aggregator: ManagedAddress,
+        from: ManagedBuffer,
+        to: ManagedBuffer,
+        max_price_age: u64,
+        decimals: u8,
+    ) {
+        require!(
+            self.blockchain().is_smart_contract(&aggregator),
+            "Invalid aggregator address"
+        );
+        let own_address = self.blockchain().get_sc_address();
+        require!(
+            self.blockchain().get_shard_of_address(&aggregator)
+                == self.blockchain().get_shard_of_address(&own_address),
+            "The aggregator must be on the same shard"
+        );
+        require!(max_price_age > 0, "Invalid max price age");
+
+        self.price_source(&token_id).set(PriceSource {
+            aggregator,
+            from,
+            to,
+            max_price_age,
+            decimals,
+        });
+    }
+
+    #[only_owner]
+    #[endpoint(removePriceSourceForCollection)]
+    fn remove_price_source_for_collection(&self, token_id: TokenIdentifier) {
+        self.price_source(&token_id).clear();
+    }
+
+    /// Extra percentage paid on top of the price when claiming a specific token
+    #[only_owner]
+    #[endpoint(setNamedClaimPremium)]
+    fn set_named_claim_premium(&self, premium_percentage: Percentage) {
+        require!(
+            premium_percentage <= MAX_PERCENTAGE,
+            "Invalid premium percentage"
+        );
+
+        self.named_claim_premium().set(premium_percentage);
+    }
+
     #[view(getPriceForToken)]
     fn try_get_price(&self, token_id: &TokenIdentifier, nonce: Nonce) -> BigUint {
+        self.get_price(token_id, nonce)
+            .unwrap_or_else(|| sc_panic!("No price set for token"))
+    }
+
+    #[view(getFloorPrice)]
+    fn try_get_floor_price(&self, token_id: &TokenIdentifier) -> BigUint {
+        self.get_collection_price(token_id)
+            .unwrap_or_else(|| sc_panic!("No price set for collection"))
+    }
+
+    fn get_price(&self, token_id: &TokenIdentifier, nonce: Nonce) -> Option<BigUint> {
         let price_for_token = self.price_for_token(token_id, nonce).get();
         if price_for_token > 0 {
-            return price_for_token;
+            return Some(price_for_token);
+        }
+
+        self.get_collection_price(token_id)
+    }
+
+    /// Fresh aggregator price if the collection has a price source, the internal collection price otherwise
+    fn get_collection_price(&self, token_id: &TokenIdentifier) -> Option<BigUint> {
+        if let Some(aggregator_price) = self.get_aggregator_price(token_id) {
+            return Some(aggregator_price);
         }
 
         let price_for_collection = self.price_for_collection(token_id).get();
-        require!(price_for_collection > 0, "No price set for token");
+        if price_for_collection == 0 {
+            return None;
+        }
+
+        Some(price_for_collection)
+    }
+
+    fn get_aggregator_price(&self, token_id: &TokenIdentifier) -> Option<BigUint> {
+        let price_source_mapper = self.price_source(token_id);
+        if price_source_mapper.is_empty() {
+            return None;
+        }
+
+        let price_source = price_source_mapper.get();
+        let price_feed = self
+            .tx()
+            .to(&price_source.aggregator)
+            .typed(price_aggregator_proxy::PriceAggregatorProxy)
+            .latest_price_feed_optional(price_source.from, price_source.to)
+            .returns(ReturnsResult)
+            .sync_call_readonly()
+            .into_option()?;
+        let (_, _, _, timestamp, price, feed_decimals) = price_feed.into_tuple();
 
-        price_for_collection
+        let current_timestamp = self.blockchain().get_block_timestamp();
+        if timestamp > current_timestamp
+            || current_timestamp - timestamp > price_source.max_price_age
+        {
+            return None;
+        }
+
+        let price = price * BigUint::from(10u32).pow(price_source.decimals as u32)
+            / BigUint::from(10u32).pow(feed_decimals as u32);
+        if price == 0 {
+            return None;
+        }
+
+        Some(price)
+    }
+
+    #[view(getNamedClaimPrice)]
+    fn get_named_claim_price(&self, token_id: &TokenIdentifier, nonce: Nonce) -> BigUint {
+        let price = self.try_get_price(token_id, nonce);
+        let premium_percentage = self.named_claim_premium().get();
+
+        &price + &(&price * premium_percentage / MAX_PERCENTAGE)
     }
 
     #[view(getFractalTokenId)]
     #[storage_mapper("fractalToken")]
     fn fractal_token(&self) -> FungibleTokenMapper;
 
+    #[view(getNamedClaimPremium)]
+    #[storage_mapper("namedClaimPremium")]
+    fn named_claim_premium(&self) -> SingleValueMapper<Percentage>;
+
+    #[view(getPriceSourceForCollection)]
+    #[storage_mapper("priceSource")]
+    fn price_source(
+        &self,
+        collection: &TokenIdentifier,
+    ) -> SingleValueMapper<PriceSource<Self::Api>>;
+
     #[storage_mapper("priceColl")]
     fn price_for_collection(&self, token_id: &TokenIdentifier) -> SingleValueMapper<BigUint>;
 
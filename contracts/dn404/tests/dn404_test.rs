@@ -1,16 +1,29 @@
 mod setup;
 
-use dn404::dn404_proxy;
+use dn404::{dn404_proxy, price_aggregator_proxy};
 use multiversx_sc::{
-    codec::IntoMultiValue,
-    types::{EsdtTokenPayment, MultiValueEncoded, ReturnsResultUnmanaged, TestEsdtTransfer},
+    codec::{multi_types::MultiValueVec, IntoMultiValue},
+    types::{
+        EgldOrEsdtTokenIdentifier, EsdtTokenPayment, MultiValueEncoded, ReturnsResult,
+        ReturnsResultUnmanaged, TestAddress, TestEsdtTransfer, TestSCAddress,
+    },
+};
+use multiversx_sc_scenario::{
+    imports::MxscPath, managed_biguint, rust_biguint, ExpectMessage, ScenarioTxRun,
 };
-use multiversx_sc_scenario::{managed_biguint, rust_biguint, ScenarioTxRun};
 use setup::{
-    Dn404Setup, DN404_ADDRESS, FEE_COL, FEE_NONCE_2, FIRST_USER, FRACTAL_TOKEN_ID, NFT_TOKEN_ID,
-    PRICE_COL, PRICE_NONCE_2, SECOND_USER, USER_BALANCE,
+    Dn404Setup, CODE_PATH, DN404_ADDRESS, FEE_COL, FEE_NONCE_2, FIRST_USER, FRACTAL_TOKEN_ID,
+    NFT_TOKEN_ID, OTHER_SC_ADDRESS, OWNER, PRICE_COL, PRICE_NONCE_2, SECOND_USER, USER_BALANCE,
 };
 
+const PRICE_AGGREGATOR_ADDRESS: TestSCAddress = TestSCAddress::new("price-aggregator");
+const PRICE_AGGREGATOR_CODE_PATH: MxscPath =
+    MxscPath::new("../price-aggregator/output/multiversx-price-aggregator-sc.mxsc.json");
+const ORACLE_NAMES: [&str; 4] = ["oracle1", "oracle2", "oracle3", "oracle4"];
+const AGGREGATOR_PRICE: u64 = 40;
+// the last byte of the address decides the shard, unlike the padded names of the other contracts
+const OTHER_SHARD_AGGREGATOR_ADDRESS: TestSCAddress = TestSCAddress::new("other-shard-aggregator");
+
 #[test]
 fn setup_test() {
     Dn404Setup::new();
@@ -90,3 +103,463 @@ fn user_deposit_test() {
         .check_account(FIRST_USER)
         .esdt_balance(FRACTAL_TOKEN_ID, expected_user_balance);
 }
+
+#[test]
+fn auto_nft_test() {
+    let mut setup = Dn404Setup::new();
+    setup
+        .b_mock
+        .tx()
+        .from(OWNER)
+        .to(DN404_ADDRESS)
+        .typed(dn404_proxy::Dn404Proxy)
+        .set_auto_nft_unit(400u64)
+        .run();
+
+    // first user crosses two whole units and receives the first two NFTs of the basket
+    setup
+        .b_mock
+        .tx()
+        .from(SECOND_USER)
+        .to(DN404_ADDRESS)
+        .typed(dn404_proxy::Dn404Proxy)
+        .transfer_fractal(FIRST_USER, 900u64)
+        .with_esdt_transfer(TestEsdtTransfer(FRACTAL_TOKEN_ID, 0, USER_BALANCE))
+        .run();
+
+    setup
+        .b_mock
+        .check_account(FIRST_USER)
+        .esdt_nft_balance_and_attributes(NFT_TOKEN_ID, 5, 1, "")
+        .esdt_nft_balance_and_attributes(NFT_TOKEN_ID, 8, 1, "");
+
+    // dropping below two whole units requires returning one of the NFTs
+    setup
+        .b_mock
+        .tx()
+        .from(FIRST_USER)
+        .to(DN404_ADDRESS)
+        .typed(dn404_proxy::Dn404Proxy)
+        .transfer_fractal(SECOND_USER, 200u64)
+        .with_result(ExpectMessage(
+            "Linked NFTs have to be returned when the balance drops below a whole unit",
+        ))
+        .run();
+
+    setup
+        .b_mock
+        .tx()
+        .from(FIRST_USER)
+        .to(DN404_ADDRESS)
+        .typed(dn404_proxy::Dn404Proxy)
+        .transfer_fractal(SECOND_USER, 200u64)
+        .with_esdt_transfer(TestEsdtTransfer(NFT_TOKEN_ID, 5, 1))
+        .run();
+
+    // opted out users do not receive NFTs
+    setup
+        .b_mock
+        .tx()
+        .from(SECOND_USER)
+        .to(DN404_ADDRESS)
+        .typed(dn404_proxy::Dn404Proxy)
+        .set_auto_nft_opt_out(true)
+        .run();
+    setup
+        .b_mock
+        .tx()
+        .from(FIRST_USER)
+        .to(DN404_ADDRESS)
+        .typed(dn404_proxy::Dn404Proxy)
+        .transfer_fractal(SECOND_USER, 300u64)
+        .run();
+
+    let linked_nfts = setup
+        .b_mock
+        .query()
+        .to(DN404_ADDRESS)
+        .typed(dn404_proxy::Dn404Proxy)
+        .linked_nfts(SECOND_USER)
+        .returns(ReturnsResultUnmanaged)
+        .run();
+    assert!(linked_nfts.is_empty());
+
+    let basket_of_good = setup
+        .b_mock
+        .query()
+        .to(DN404_ADDRESS)
+        .typed(dn404_proxy::Dn404Proxy)
+        .basket_of_goods()
+        .returns(ReturnsResultUnmanaged)
+        .run();
+    assert_eq!(basket_of_good.len(), 3);
+
+    setup
+        .b_mock
+        .tx()
+        .from(SECOND_USER)
+        .to(DN404_ADDRESS)
+        .typed(dn404_proxy::Dn404Proxy)
+        .withdraw_fractal(600u64)
+        .run();
+
+    setup
+        .b_mock
+        .check_account(SECOND_USER)
+        .esdt_balance(FRACTAL_TOKEN_ID, 600);
+}
+
+#[test]
+fn auto_nft_unit_below_price_test() {
+    let mut setup = Dn404Setup::new();
+    setup
+        .b_mock
+        .tx()
+        .from(OWNER)
+        .to(DN404_ADDRESS)
+        .typed(dn404_proxy::Dn404Proxy)
+        .set_auto_nft_unit(PRICE_COL - 1)
+        .run();
+
+    // one unit does not cover the price of any basket NFT, so none is released
+    setup
+        .b_mock
+        .tx()
+        .from(SECOND_USER)
+        .to(DN404_ADDRESS)
+        .typed(dn404_proxy::Dn404Proxy)
+        .transfer_fractal(FIRST_USER, 900u64)
+        .with_esdt_transfer(TestEsdtTransfer(FRACTAL_TOKEN_ID, 0, USER_BALANCE))
+        .run();
+
+    let linked_nfts = setup
+        .b_mock
+        .query()
+        .to(DN404_ADDRESS)
+        .typed(dn404_proxy::Dn404Proxy)
+        .linked_nfts(FIRST_USER)
+        .returns(ReturnsResultUnmanaged)
+        .run();
+    assert!(linked_nfts.is_empty());
+
+    let basket_of_good = setup
+        .b_mock
+        .query()
+        .to(DN404_ADDRESS)
+        .typed(dn404_proxy::Dn404Proxy)
+        .basket_of_goods()
+        .returns(ReturnsResultUnmanaged)
+        .run();
+    assert_eq!(basket_of_good.len(), 4);
+}
+
+#[test]
+fn claim_random_test() {
+    let mut setup = Dn404Setup::new();
+
+    setup
+        .b_mock
+        .tx()
+        .from(SECOND_USER)
+        .to(DN404_ADDRESS)
+        .typed(dn404_proxy::Dn404Proxy)
+        .claim_random(2usize)
+        .with_esdt_transfer(TestEsdtTransfer(FRACTAL_TOKEN_ID, 0, USER_BALANCE))
+        .run();
+
+    setup
+        .b_mock
+        .check_account(SECOND_USER)
+        .esdt_balance(FRACTAL_TOKEN_ID, USER_BALANCE - 2 * PRICE_COL);
+
+    let basket_composition = setup
+        .b_mock
+        .query()
+        .to(DN404_ADDRESS)
+        .typed(dn404_proxy::Dn404Proxy)
+        .get_basket_composition()
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(basket_composition.len(), 1);
+
+    let (collection, amount) = basket_composition.into_iter().next().unwrap().into_tuple();
+    assert_eq!(collection, NFT_TOKEN_ID.to_token_identifier());
+    assert_eq!(amount, managed_biguint!(2));
+
+    setup
+        .b_mock
+        .tx()
+        .from(SECOND_USER)
+        .to(DN404_ADDRESS)
+        .typed(dn404_proxy::Dn404Proxy)
+        .claim_random(3usize)
+        .with_esdt_transfer(TestEsdtTransfer(FRACTAL_TOKEN_ID, 0, 3 * PRICE_COL))
+        .with_result(ExpectMessage("Not enough tokens to claim"))
+        .run();
+
+    // smart contracts could revert an unfavourable draw
+    setup
+        .b_mock
+        .account(OTHER_SC_ADDRESS)
+        .code(CODE_PATH)
+        .esdt_balance(FRACTAL_TOKEN_ID, USER_BALANCE);
+    setup
+        .b_mock
+        .tx()
+        .from(OTHER_SC_ADDRESS)
+        .to(DN404_ADDRESS)
+        .typed(dn404_proxy::Dn404Proxy)
+        .claim_random(1usize)
+        .with_esdt_transfer(TestEsdtTransfer(FRACTAL_TOKEN_ID, 0, PRICE_COL))
+        .with_result(ExpectMessage("Only user accounts can claim random tokens"))
+        .run();
+}
+
+#[test]
+fn named_claim_premium_test() {
+    let mut setup = Dn404Setup::new();
+    setup
+        .b_mock
+        .tx()
+        .from(OWNER)
+        .to(DN404_ADDRESS)
+        .typed(dn404_proxy::Dn404Proxy)
+        .set_named_claim_premium(2_000u32)
+        .run();
+
+    let mut tokens_to_claim = MultiValueEncoded::new();
+    let token = EsdtTokenPayment::new(NFT_TOKEN_ID.to_token_identifier(), 5, managed_biguint!(1));
+    tokens_to_claim.push(token.into_multi_value());
+
+    setup
+        .b_mock
+        .tx()
+        .from(SECOND_USER)
+        .to(DN404_ADDRESS)
+        .typed(dn404_proxy::Dn404Proxy)
+        .claim_basket_of_goods(tokens_to_claim.clone())
+        .with_esdt_transfer(TestEsdtTransfer(FRACTAL_TOKEN_ID, 0, PRICE_COL))
+        .with_result(ExpectMessage("Not enough tokens"))
+        .run();
+
+    // 20% premium over the collection price
+    let named_claim_price = PRICE_COL * 12 / 10;
+    setup
+        .b_mock
+        .tx()
+        .from(SECOND_USER)
+        .to(DN404_ADDRESS)
+        .typed(dn404_proxy::Dn404Proxy)
+        .claim_basket_of_goods(tokens_to_claim)
+        .with_esdt_transfer(TestEsdtTransfer(FRACTAL_TOKEN_ID, 0, named_claim_price))
+        .run();
+
+    setup
+        .b_mock
+        .check_account(SECOND_USER)
+        .esdt_balance(FRACTAL_TOKEN_ID, USER_BALANCE - named_claim_price)
+        .esdt_nft_balance_and_attributes(NFT_TOKEN_ID, 5, 1, "");
+}
+
+#[test]
+fn aggregator_on_other_shard_test() {
+    let mut setup = Dn404Setup::new();
+    setup.b_mock.register_contract(
+        PRICE_AGGREGATOR_CODE_PATH,
+        multiversx_price_aggregator_sc::ContractBuilder,
+    );
+    setup
+        .b_mock
+        .account(OTHER_SHARD_AGGREGATOR_ADDRESS)
+        .code(PRICE_AGGREGATOR_CODE_PATH)
+        .owner(OWNER);
+
+    setup
+        .b_mock
+        .tx()
+        .from(OWNER)
+        .to(DN404_ADDRESS)
+        .typed(dn404_proxy::Dn404Proxy)
+        .set_price_source_for_collection(
+            NFT_TOKEN_ID,
+            OTHER_SHARD_AGGREGATOR_ADDRESS,
+            "NFT",
+            "FRACTAL",
+            60u64,
+            0u8,
+        )
+        .with_result(ExpectMessage("The aggregator must be on the same shard"))
+        .run();
+}
+
+#[test]
+fn aggregator_price_test() {
+    let mut setup = Dn404Setup::new();
+    setup.b_mock.register_contract(
+        PRICE_AGGREGATOR_CODE_PATH,
+        multiversx_price_aggregator_sc::ContractBuilder,
+    );
+    setup.b_mock.current_block().block_timestamp(100);
+
+    let oracles: Vec<TestAddress> = ORACLE_NAMES
+        .iter()
+        .map(|name| TestAddress::new(name))
+        .collect();
+    for oracle in oracles.iter() {
+        setup.b_mock.account(*oracle).nonce(1).balance(20u64);
+    }
+
+    setup
+        .b_mock
+        .tx()
+        .from(OWNER)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .init(
+            EgldOrEsdtTokenIdentifier::egld(),
+            20u64,
+            10u64,
+            3usize,
+            3usize,
+            MultiValueVec::from(
+                oracles
+                    .iter()
+                    .map(|oracle| oracle.to_address())
+                    .collect::<Vec<_>>(),
+            ),
+        )
+        .code(PRICE_AGGREGATOR_CODE_PATH)
+        .new_address(PRICE_AGGREGATOR_ADDRESS)
+        .run();
+
+    for oracle in oracles.iter() {
+        setup
+            .b_mock
+            .tx()
+            .from(*oracle)
+            .to(PRICE_AGGREGATOR_ADDRESS)
+            .typed(price_aggregator_proxy::PriceAggregatorProxy)
+            .stake()
+            .egld(20u64)
+            .run();
+    }
+
+    setup
+        .b_mock
+        .tx()
+        .from(OWNER)
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .set_pair_decimals("NFT", "FRACTAL", 0u8)
+        .run();
+    setup
+        .b_mock
+        .tx()
+        .from(OWNER)
+        .to(PRICE_AGGREGATOR_ADDRESS)
+        .typed(price_aggregator_proxy::PriceAggregatorProxy)
+        .unpause_endpoint()
+        .run();
+
+    for oracle in oracles.iter().take(3) {
+        setup
+            .b_mock
+            .tx()
+            .from(*oracle)
+            .to(PRICE_AGGREGATOR_ADDRESS)
+            .typed(price_aggregator_proxy::PriceAggregatorProxy)
+            .submit("NFT", "FRACTAL", 100u64, AGGREGATOR_PRICE, 0u8)
+            .run();
+    }
+
+    setup
+        .b_mock
+        .tx()
+        .from(OWNER)
+        .to(DN404_ADDRESS)
+        .typed(dn404_proxy::Dn404Proxy)
+        .set_price_source_for_collection(
+            NFT_TOKEN_ID,
+            PRICE_AGGREGATOR_ADDRESS,
+            "NFT",
+            "FRACTAL",
+            60u64,
+            0u8,
+        )
+        .run();
+
+    // the aggregator price replaces the internal collection price
+    setup
+        .b_mock
+        .tx()
+        .from(FIRST_USER)
+        .to(DN404_ADDRESS)
+        .typed(dn404_proxy::Dn404Proxy)
+        .deposit_basket_of_goods()
+        .esdt(TestEsdtTransfer(NFT_TOKEN_ID, 1, 1))
+        .run();
+    setup
+        .b_mock
+        .check_account(FIRST_USER)
+        .esdt_balance(FRACTAL_TOKEN_ID, AGGREGATOR_PRICE - FEE_COL);
+
+    // stale prices fall back to the internal collection price
+    setup.b_mock.current_block().block_timestamp(200);
+    setup
+        .b_mock
+        .tx()
+        .from(FIRST_USER)
+        .to(DN404_ADDRESS)
+        .typed(dn404_proxy::Dn404Proxy)
+        .deposit_basket_of_goods()
+        .esdt(TestEsdtTransfer(NFT_TOKEN_ID, 3, 1))
+        .run();
+    setup.b_mock.check_account(FIRST_USER).esdt_balance(
+        FRACTAL_TOKEN_ID,
+        AGGREGATOR_PRICE - FEE_COL + PRICE_COL - FEE_COL,
+    );
+
+    // token pairs unknown to the aggregator also fall back to the internal collection price
+    setup
+        .b_mock
+        .tx()
+        .from(OWNER)
+        .to(DN404_ADDRESS)
+        .typed(dn404_proxy::Dn404Proxy)
+        .set_price_source_for_collection(
+            NFT_TOKEN_ID,
+            PRICE_AGGREGATOR_ADDRESS,
+            "NFT",
+            "USD",
+            60u64,
+            0u8,
+        )
+        .run();
+    let floor_price = setup
+        .b_mock
+        .query()
+        .to(DN404_ADDRESS)
+        .typed(dn404_proxy::Dn404Proxy)
+        .try_get_floor_price(NFT_TOKEN_ID)
+        .returns(ReturnsResultUnmanaged)
+        .run();
+    assert_eq!(floor_price, rust_biguint!(PRICE_COL));
+
+    setup
+        .b_mock
+        .tx()
+        .from(OWNER)
+        .to(DN404_ADDRESS)
+        .typed(dn404_proxy::Dn404Proxy)
+        .set_internal_price_for_collection(NFT_TOKEN_ID, 0u64)
+        .run();
+    setup
+        .b_mock
+        .tx()
+        .from(FIRST_USER)
+        .to(DN404_ADDRESS)
+        .typed(dn404_proxy::Dn404Proxy)
+        .deposit_basket_of_goods()
+        .esdt(TestEsdtTransfer(NFT_TOKEN_ID, 4, 1))
+        .with_result(ExpectMessage("No valid price, deposit refused"))
+        .run();
+}
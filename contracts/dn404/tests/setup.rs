@@ -17,6 +17,7 @@ pub const OWNER: TestAddress = TestAddress::new("owner");
 pub const FIRST_USER: TestAddress = TestAddress::new("first");
 pub const SECOND_USER: TestAddress = TestAddress::new("second");
 pub const DN404_ADDRESS: TestSCAddress = TestSCAddress::new("dn404");
+pub const OTHER_SC_ADDRESS: TestSCAddress = TestSCAddress::new("other-sc");
 pub const CODE_PATH: MxscPath = MxscPath::new("output/dn404.mxsc.json");
 
 fn world() -> ScenarioWorld {
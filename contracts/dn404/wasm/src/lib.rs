@@ -6,9 +6,9 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                           21
+// Endpoints:                           39
 // Async Callback:                       1
-// Total number of exported functions:  24
+// Total number of exported functions:  42
 
 #![no_std]
 
@@ -23,12 +23,30 @@ multiversx_sc_wasm_adapter::endpoints! {
         deposit => deposit
         depositBasketOfGoods => deposit_basket_of_goods
         claimBasketOfGoods => claim_basket_of_goods
+        claimRandom => claim_random
+        getBasketComposition => get_basket_composition
+        getBasketTokensForCollection => get_basket_tokens_for_collection
         getBasketOfGoods => basket_of_goods
         getRemainingTokens => remaining_tokens
+        setAutoNftUnit => set_auto_nft_unit
+        setAutoNftOptOut => set_auto_nft_opt_out
+        transferFractal => transfer_fractal
+        withdrawFractal => withdraw_fractal
+        getAutoNftUnit => auto_nft_unit
+        isAutoNftOptOut => auto_nft_opt_out
+        getRoutedBalance => routed_balance
+        getLinkedNfts => linked_nfts
         setInternalPriceForToken => set_internal_price_for_token
         setInternalPriceForCollection => set_internal_price_for_collection
+        setPriceSourceForCollection => set_price_source_for_collection
+        removePriceSourceForCollection => remove_price_source_for_collection
+        setNamedClaimPremium => set_named_claim_premium
         getPriceForToken => try_get_price
+        getFloorPrice => try_get_floor_price
+        getNamedClaimPrice => get_named_claim_price
         getFractalTokenId => fractal_token
+        getNamedClaimPremium => named_claim_premium
+        getPriceSourceForCollection => price_source
         setFeeForFractionalisingNft => set_fee_for_fractionalizing_nft
         setFeeForFactionalisingCollection => set_fee_for_fractionalizing_collection
         setFeeForDepositBaskedOfGoods => set_fee_for_deposit_basket_of_goods
@@ -0,0 +1,213 @@
+use multiversx_sc::imports::*;
+
+/// Agents enforce regulatory decisions: they freeze investors, force transfers and recover lost wallets.
+/// Tokens cannot be taken out of an investor's wallet, so forced transfers and recoveries move the ledger balance
+/// and pay the receiver out of the recovery reserve held by this contract, which keeps the supply unchanged.
+/// They require this contract to hold the transfer role of the token, so that tokens only move through it:
+/// the tokens left behind are no longer backed by the ledger and cannot be sent.
+#[multiversx_sc::module]
+pub trait AgentsModule:
+    crate::identity_registry::IdentityRegistryModule
+    + crate::compliance::ComplianceModule
+    + crate::token::TokenModule
+    + crate::hooks::call_hook::CallHookModule
+    + multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
+{
+    #[only_owner]
+    #[endpoint(addAgent)]
+    fn add_agent(&self, agent: ManagedAddress) {
+        require!(self.agents().insert(agent), "Address is already an agent");
+    }
+
+    #[only_owner]
+    #[endpoint(removeAgent)]
+    fn remove_agent(&self, agent: ManagedAddress) {
+        require!(self.agents().swap_remove(&agent), "Address is not an agent");
+    }
+
+    /// Mints tokens to this contract, to be paid out by forced transfers and wallet recoveries.
+    #[only_owner]
+    #[endpoint(mintRecoveryReserve)]
+    fn mint_recovery_reserve(&self, amount: BigUint) {
+        require!(amount > 0, "Amount cannot be zero");
+
+        let _ = self.token().mint(amount);
+    }
+
+    /// A frozen address can neither send nor receive tokens.
+    #[endpoint(setAddressFrozen)]
+    fn set_address_frozen(&self, investor: ManagedAddress, frozen: bool) {
+        self.require_agent();
+
+        self.address_frozen(&investor).set(frozen);
+        self.address_frozen_event(&investor, &self.blockchain().get_caller(), frozen);
+    }
+
+    /// Frozen tokens stay in the investor's balance, but cannot be sent.
+    #[endpoint(freezePartialTokens)]
+    fn freeze_partial_tokens(&self, investor: ManagedAddress, amount: BigUint) {
+        self.require_agent();
+
+        let frozen_tokens_mapper = self.frozen_tokens(&investor);
+        let frozen_tokens = frozen_tokens_mapper.get() + amount;
+        require!(
+            frozen_tokens <= self.balance(&investor).get(),
+            "Cannot freeze more than the balance"
+        );
+
+        frozen_tokens_mapper.set(frozen_tokens);
+    }
+
+    #[endpoint(unfreezePartialTokens)]
+    fn unfreeze_partial_tokens(&self, investor: ManagedAddress, amount: BigUint) {
+        self.require_agent();
+
+        let frozen_tokens_mapper = self.frozen_tokens(&investor);
+        let frozen_tokens = frozen_tokens_mapper.get();
+        require!(amount <= frozen_tokens, "Amount exceeds the frozen tokens");
+
+        frozen_tokens_mapper.set(frozen_tokens - amount);
+    }
+
+    /// Moves tokens between two investors without checking compliance or address freezes.
+    /// Frozen tokens of the sender are unfrozen if its free balance is not enough.
+    #[endpoint(forcedTransfer)]
+    fn forced_transfer(&self, from: ManagedAddress, to: ManagedAddress, amount: BigUint) {
+        self.require_agent();
+        self.require_transfer_role();
+        require!(amount > 0, "Amount cannot be zero");
+        self.require_verified(&to);
+
+        let balance = self.balance(&from).get();
+        require!(amount <= balance, "Insufficient balance");
+
+        let frozen_tokens_mapper = self.frozen_tokens(&from);
+        let frozen_tokens = frozen_tokens_mapper.get();
+        let free_balance = &balance - &frozen_tokens;
+        if amount > free_balance {
+            frozen_tokens_mapper.set(frozen_tokens - (&amount - &free_balance));
+        }
+
+        self.decrease_balance(&from, &amount);
+        self.increase_balance(&to, &amount);
+        self.pay_from_recovery_reserve(&to, &amount);
+
+        self.forced_transfer_event(&from, &to, &self.blockchain().get_caller(), &amount);
+    }
+
+    /// Moves the balance, the frozen state and the locked up tokens of a lost wallet to a new verified one.
+    #[endpoint(recoveryAddress)]
+    fn recovery_address(&self, lost_wallet: ManagedAddress, new_wallet: ManagedAddress) {
+        self.require_agent();
+        self.require_transfer_role();
+        require!(lost_wallet != new_wallet, "Wallets must be different");
+        self.require_verified(&new_wallet);
+        require!(
+            self.balance(&new_wallet).is_empty(),
+            "New wallet already holds tokens"
+        );
+
+        let balance = self.balance(&lost_wallet).get();
+        require!(balance > 0, "Lost wallet holds no tokens");
+
+        self.decrease_balance(&lost_wallet, &balance);
+        self.increase_balance(&new_wallet, &balance);
+        self.frozen_tokens(&new_wallet)
+            .set(self.frozen_tokens(&lost_wallet).take());
+        self.address_frozen(&new_wallet)
+            .set(self.address_frozen(&lost_wallet).take());
+        self.locked_tokens(&new_wallet)
+            .set(self.locked_tokens(&lost_wallet).take());
+
+        self.pay_from_recovery_reserve(&new_wallet, &balance);
+
+        self.recovery_event(
+            &lost_wallet,
+            &new_wallet,
+            &self.blockchain().get_caller(),
+            &balance,
+        );
+    }
+
+    fn pay_from_recovery_reserve(&self, to: &ManagedAddress, amount: &BigUint) {
+        require!(
+            &self.get_recovery_reserve() >= amount,
+            "Not enough tokens in the recovery reserve"
+        );
+
+        let token_id = self.token().get_token_id();
+        self.tx()
+            .to(to)
+            .single_esdt(&token_id, 0, amount)
+            .transfer();
+    }
+
+    fn require_agent(&self) {
+        let caller = self.blockchain().get_caller();
+        require!(
+            self.agents().contains(&caller),
+            "Only agents can call this function"
+        );
+    }
+
+    fn require_address_not_frozen(&self, address: &ManagedAddress) {
+        require!(!self.address_frozen(address).get(), "Address is frozen");
+    }
+
+    /// The investor must not be frozen and must hold `amount` tokens that are not frozen.
+    fn require_unfrozen_balance(&self, investor: &ManagedAddress, amount: &BigUint) {
+        self.require_address_not_frozen(investor);
+
+        let balance = self.balance(investor).get();
+        let frozen_tokens = self.frozen_tokens(investor).get();
+        require!(
+            &frozen_tokens + amount <= balance,
+            "Insufficient unfrozen balance"
+        );
+    }
+
+    #[event("addressFrozen")]
+    fn address_frozen_event(
+        &self,
+        #[indexed] investor: &ManagedAddress,
+        #[indexed] agent: &ManagedAddress,
+        frozen: bool,
+    );
+
+    #[event("forcedTransfer")]
+    fn forced_transfer_event(
+        &self,
+        #[indexed] from: &ManagedAddress,
+        #[indexed] to: &ManagedAddress,
+        #[indexed] agent: &ManagedAddress,
+        amount: &BigUint,
+    );
+
+    #[event("recovery")]
+    fn recovery_event(
+        &self,
+        #[indexed] lost_wallet: &ManagedAddress,
+        #[indexed] new_wallet: &ManagedAddress,
+        #[indexed] agent: &ManagedAddress,
+        amount: &BigUint,
+    );
+
+    #[view(getRecoveryReserve)]
+    fn get_recovery_reserve(&self) -> BigUint {
+        let token_id = self.token().get_token_id();
+        self.blockchain()
+            .get_sc_balance(&EgldOrEsdtTokenIdentifier::esdt(token_id), 0)
+    }
+
+    #[view(getAgents)]
+    #[storage_mapper("agents")]
+    fn agents(&self) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[view(isFrozen)]
+    #[storage_mapper("addressFrozen")]
+    fn address_frozen(&self, investor: &ManagedAddress) -> SingleValueMapper<bool>;
+
+    #[view(getFrozenTokens)]
+    #[storage_mapper("frozenTokens")]
+    fn frozen_tokens(&self, investor: &ManagedAddress) -> SingleValueMapper<BigUint>;
+}
@@ -0,0 +1,393 @@
+use multiversx_sc::derive_imports::*;
+use multiversx_sc::imports::*;
+
+pub const SECONDS_PER_DAY: u64 = 86_400;
+pub const SECONDS_PER_MONTH: u64 = 30 * SECONDS_PER_DAY;
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Debug)]
+pub enum ComplianceModuleType {
+    MaxHolders,
+    MaxBalance,
+    CountryAllowList,
+    CountryDenyList,
+    VolumeLimits,
+    LockUp,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone)]
+pub struct LockedTokens<M: ManagedTypeApi> {
+    pub amount: BigUint<M>,
+    pub unlock_timestamp: u64,
+}
+
+/// Amounts sent by an investor in the current day and in the current 30 day period.
+#[type_abi]
+#[derive(TopEncode, TopDecode)]
+pub struct TransferVolume<M: ManagedTypeApi> {
+    pub day: u64,
+    pub daily_amount: BigUint<M>,
+    pub month: u64,
+    pub monthly_amount: BigUint<M>,
+}
+
+/// Built-in compliance rules, each enabled and configured by the owner.
+/// The contract keeps its own ledger of investor balances, since all transfers go through it.
+/// That only holds while the contract has the transfer role of the token,
+/// so minting and transfers are rejected without it.
+#[multiversx_sc::module]
+pub trait ComplianceModule: crate::identity_registry::IdentityRegistryModule {
+    #[only_owner]
+    #[endpoint(enableComplianceModule)]
+    fn enable_compliance_module(&self, module: ComplianceModuleType) {
+        require!(
+            self.enabled_compliance_modules().insert(module),
+            "Compliance module already enabled"
+        );
+    }
+
+    #[only_owner]
+    #[endpoint(disableComplianceModule)]
+    fn disable_compliance_module(&self, module: ComplianceModuleType) {
+        require!(
+            self.enabled_compliance_modules().swap_remove(&module),
+            "Compliance module not enabled"
+        );
+    }
+
+    /// Maximum number of investors holding tokens at the same time.
+    #[only_owner]
+    #[endpoint(setMaxHolders)]
+    fn set_max_holders(&self, max_holders: usize) {
+        self.max_holders().set(max_holders);
+    }
+
+    /// Maximum balance of a single investor.
+    #[only_owner]
+    #[endpoint(setMaxBalance)]
+    fn set_max_balance(&self, max_balance: BigUint) {
+        self.max_balance().set(max_balance);
+    }
+
+    #[only_owner]
+    #[endpoint(addAllowedCountries)]
+    fn add_allowed_countries(&self, countries: MultiValueEncoded<u16>) {
+        let mut mapper = self.allowed_countries();
+        for country in countries {
+            mapper.insert(country);
+        }
+    }
+
+    #[only_owner]
+    #[endpoint(removeAllowedCountries)]
+    fn remove_allowed_countries(&self, countries: MultiValueEncoded<u16>) {
+        let mut mapper = self.allowed_countries();
+        for country in countries {
+            require!(mapper.swap_remove(&country), "Country not in list");
+        }
+    }
+
+    #[only_owner]
+    #[endpoint(addDeniedCountries)]
+    fn add_denied_countries(&self, countries: MultiValueEncoded<u16>) {
+        let mut mapper = self.denied_countries();
+        for country in countries {
+            mapper.insert(country);
+        }
+    }
+
+    #[only_owner]
+    #[endpoint(removeDeniedCountries)]
+    fn remove_denied_countries(&self, countries: MultiValueEncoded<u16>) {
+        let mut mapper = self.denied_countries();
+        for country in countries {
+            require!(mapper.swap_remove(&country), "Country not in list");
+        }
+    }
+
+    /// Limits the amount an investor can send per day and per 30 day period. Zero means no limit.
+    #[only_owner]
+    #[endpoint(setVolumeLimits)]
+    fn set_volume_limits(&self, daily_limit: BigUint, monthly_limit: BigUint) {
+        self.daily_volume_limit().set(daily_limit);
+        self.monthly_volume_limit().set(monthly_limit);
+    }
+
+    /// Newly issued tokens cannot be sent by the investor for this many seconds.
+    #[only_owner]
+    #[endpoint(setLockUpPeriod)]
+    fn set_lock_up_period(&self, lock_up_period: u64) {
+        self.lock_up_period().set(lock_up_period);
+    }
+
+    /// Evaluates all the enabled compliance modules for a transfer of `amount` tokens.
+    /// The sender is `None` when the tokens are issued.
+    fn check_compliance(
+        &self,
+        opt_from: Option<&ManagedAddress>,
+        to: &ManagedAddress,
+        amount: &BigUint,
+    ) {
+        let enabled_modules = self.enabled_compliance_modules();
+        let to_balance = self.balance(to).get();
+
+        if enabled_modules.contains(&ComplianceModuleType::MaxHolders) {
+            let mut holder_count = self.holder_count().get();
+            if to_balance == 0 {
+                holder_count += 1;
+            }
+            if let Some(from) = opt_from {
+                let from_balance = self.balance(from).get();
+                if from != to && from_balance > 0 && &from_balance <= amount {
+                    holder_count -= 1;
+                }
+            }
+            require!(
+                holder_count <= self.max_holders().get(),
+                "Maximum number of holders reached"
+            );
+        }
+
+        if enabled_modules.contains(&ComplianceModuleType::MaxBalance) {
+            require!(
+                to_balance + amount <= self.max_balance().get(),
+                "Investor balance cap exceeded"
+            );
+        }
+
+        let country = self.identity(to).get().country;
+        if enabled_modules.contains(&ComplianceModuleType::CountryAllowList) {
+            require!(
+                self.allowed_countries().contains(&country),
+                "Receiver country is not allowed"
+            );
+        }
+        if enabled_modules.contains(&ComplianceModuleType::CountryDenyList) {
+            require!(
+                !self.denied_countries().contains(&country),
+                "Receiver country is denied"
+            );
+        }
+
+        let from = match opt_from {
+            Some(from) => from,
+            None => return,
+        };
+
+        if enabled_modules.contains(&ComplianceModuleType::VolumeLimits) {
+            let volume = self.get_current_transfer_volume(from);
+            let daily_limit = self.daily_volume_limit().get();
+            require!(
+                daily_limit == 0 || volume.daily_amount + amount <= daily_limit,
+                "Daily transfer volume limit exceeded"
+            );
+            let monthly_limit = self.monthly_volume_limit().get();
+            require!(
+                monthly_limit == 0 || volume.monthly_amount + amount <= monthly_limit,
+                "Monthly transfer volume limit exceeded"
+            );
+        }
+
+        if enabled_modules.contains(&ComplianceModuleType::LockUp) {
+            require!(
+                &self.get_unlocked_balance(from) >= amount,
+                "Tokens are locked up"
+            );
+        }
+    }
+
+    /// Updates the ledger after `sent_amount` tokens left the sender and `received_amount` tokens reached the receiver.
+    /// The two amounts only differ if hooks took fees. Issued tokens are locked up if the lock-up module is enabled.
+    fn record_transfer(
+        &self,
+        opt_from: Option<&ManagedAddress>,
+        to: &ManagedAddress,
+        sent_amount: &BigUint,
+        received_amount: &BigUint,
+    ) {
+        match opt_from {
+            Some(from) => {
+                self.decrease_balance(from, sent_amount);
+
+                let mut volume = self.get_current_transfer_volume(from);
+                volume.daily_amount += sent_amount;
+                volume.monthly_amount += sent_amount;
+                self.transfer_volume(from).set(volume);
+
+                self.remove_expired_locks(from);
+            }
+            None => {
+                let lock_up_period = self.lock_up_period().get();
+                if lock_up_period > 0
+                    && self
+                        .enabled_compliance_modules()
+                        .contains(&ComplianceModuleType::LockUp)
+                {
+                    let unlock_timestamp = self.blockchain().get_block_timestamp() + lock_up_period;
+                    self.locked_tokens(to).update(|locked_tokens| {
+                        locked_tokens.push(LockedTokens {
+                            amount: received_amount.clone(),
+                            unlock_timestamp,
+                        })
+                    });
+                }
+            }
+        }
+
+        self.increase_balance(to, received_amount);
+    }
+
+    fn increase_balance(&self, investor: &ManagedAddress, amount: &BigUint) {
+        if amount == &0 {
+            return;
+        }
+
+        let balance_mapper = self.balance(investor);
+        if balance_mapper.is_empty() {
+            self.holder_count()
+                .update(|holder_count| *holder_count += 1);
+        }
+        balance_mapper.update(|balance| *balance += amount);
+    }
+
+    fn decrease_balance(&self, investor: &ManagedAddress, amount: &BigUint) {
+        let balance_mapper = self.balance(investor);
+        let balance = balance_mapper.get();
+        if balance == 0 {
+            return;
+        }
+
+        if &balance <= amount {
+            balance_mapper.clear();
+            self.holder_count()
+                .update(|holder_count| *holder_count -= 1);
+        } else {
+            balance_mapper.set(balance - amount);
+        }
+    }
+
+    fn get_current_transfer_volume(&self, investor: &ManagedAddress) -> TransferVolume<Self::Api> {
+        let current_timestamp = self.blockchain().get_block_timestamp();
+        let day = current_timestamp / SECONDS_PER_DAY;
+        let month = current_timestamp / SECONDS_PER_MONTH;
+
+        let volume_mapper = self.transfer_volume(investor);
+        if volume_mapper.is_empty() {
+            return TransferVolume {
+                day,
+                daily_amount: BigUint::zero(),
+                month,
+                monthly_amount: BigUint::zero(),
+            };
+        }
+
+        let mut volume = volume_mapper.get();
+        if volume.day != day {
+            volume.day = day;
+            volume.daily_amount = BigUint::zero();
+        }
+        if volume.month != month {
+            volume.month = month;
+            volume.monthly_amount = BigUint::zero();
+        }
+
+        volume
+    }
+
+    fn remove_expired_locks(&self, investor: &ManagedAddress) {
+        let current_timestamp = self.blockchain().get_block_timestamp();
+        self.locked_tokens(investor).update(|locked_tokens| {
+            let mut still_locked = ManagedVec::new();
+            for locked in locked_tokens.iter() {
+                if locked.unlock_timestamp > current_timestamp {
+                    still_locked.push(locked.clone());
+                }
+            }
+            *locked_tokens = still_locked;
+        });
+    }
+
+    #[view(getLockedBalance)]
+    fn get_locked_balance(&self, investor: ManagedAddress) -> BigUint {
+        let current_timestamp = self.blockchain().get_block_timestamp();
+        let mut locked_balance = BigUint::zero();
+        for locked in self.locked_tokens(&investor).get().iter() {
+            if locked.unlock_timestamp > current_timestamp {
+                locked_balance += &locked.amount;
+            }
+        }
+
+        locked_balance
+    }
+
+    fn get_unlocked_balance(&self, investor: &ManagedAddress) -> BigUint {
+        let balance = self.balance(investor).get();
+        let locked_balance = self.get_locked_balance(investor.clone());
+        if balance <= locked_balance {
+            return BigUint::zero();
+        }
+
+        balance - locked_balance
+    }
+
+    /// Amounts sent by the investor in the current day and in the current 30 day period.
+    #[view(getTransferVolume)]
+    fn get_transfer_volume(&self, investor: ManagedAddress) -> MultiValue2<BigUint, BigUint> {
+        let volume = self.get_current_transfer_volume(&investor);
+
+        (volume.daily_amount, volume.monthly_amount).into()
+    }
+
+    #[view(getEnabledComplianceModules)]
+    #[storage_mapper("enabledComplianceModules")]
+    fn enabled_compliance_modules(&self) -> UnorderedSetMapper<ComplianceModuleType>;
+
+    #[view(getBalance)]
+    #[storage_mapper("balance")]
+    fn balance(&self, investor: &ManagedAddress) -> SingleValueMapper<BigUint>;
+
+    #[view(getHolderCount)]
+    #[storage_mapper("holderCount")]
+    fn holder_count(&self) -> SingleValueMapper<usize>;
+
+    #[view(getMaxHolders)]
+    #[storage_mapper("maxHolders")]
+    fn max_holders(&self) -> SingleValueMapper<usize>;
+
+    #[view(getMaxBalance)]
+    #[storage_mapper("maxBalance")]
+    fn max_balance(&self) -> SingleValueMapper<BigUint>;
+
+    #[view(getAllowedCountries)]
+    #[storage_mapper("allowedCountries")]
+    fn allowed_countries(&self) -> UnorderedSetMapper<u16>;
+
+    #[view(getDeniedCountries)]
+    #[storage_mapper("deniedCountries")]
+    fn denied_countries(&self) -> UnorderedSetMapper<u16>;
+
+    #[view(getDailyVolumeLimit)]
+    #[storage_mapper("dailyVolumeLimit")]
+    fn daily_volume_limit(&self) -> SingleValueMapper<BigUint>;
+
+    #[view(getMonthlyVolumeLimit)]
+    #[storage_mapper("monthlyVolumeLimit")]
+    fn monthly_volume_limit(&self) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("transferVolume")]
+    fn transfer_volume(
+        &self,
+        investor: &ManagedAddress,
+    ) -> SingleValueMapper<TransferVolume<Self::Api>>;
+
+    #[view(getLockUpPeriod)]
+    #[storage_mapper("lockUpPeriod")]
+    fn lock_up_period(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("lockedTokens")]
+    fn locked_tokens(
+        &self,
+        investor: &ManagedAddress,
+    ) -> SingleValueMapper<ManagedVec<LockedTokens<Self::Api>>>;
+}
@@ -9,6 +9,11 @@ pub type EndpointName<M> = ManagedBuffer<M>;
 #[multiversx_sc::module]
 pub trait ExchangeActionsModule:
     crate::users::UsersModule
+    + crate::identity_registry::IdentityRegistryModule
+    + crate::compliance::ComplianceModule
+    + crate::agents::AgentsModule
+    + crate::token::TokenModule
+    + multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
     + crate::hooks::call_hook::CallHookModule
     + multiversx_sc_modules::pause::PauseModule
 {
@@ -41,6 +46,7 @@ pub trait ExchangeActionsModule:
     }
 
     /// forward an execute on dest context call on an exchange SC
+    /// The tokens received back must pass the compliance modules, like any other transfer
     #[payable]
     #[endpoint(forwardExecuteOnDest)]
     fn forward_execute_on_dest(
@@ -52,13 +58,16 @@ pub trait ExchangeActionsModule:
         self.require_not_paused();
         self.require_known_endpoint(&dest, &endpoint_name);
 
-        let egld_value = self.call_value().egld().clone();
+        let egld_value = self.call_value().egld_direct_non_strict().clone();
         require!(egld_value == 0, "Invalid payment");
 
         let caller = self.blockchain().get_caller();
         self.require_whitelisted(&caller);
 
         let payments = self.call_value().all_esdt_transfers().clone();
+        let sent_amount = self.get_token_amount(&payments);
+        self.require_unfrozen_balance(&caller, &sent_amount);
+        self.decrease_balance(&caller, &sent_amount);
         let payments_after_hook = self.call_hook(
             ErcHookType::BeforeExchangeAction,
             caller.clone(),
@@ -82,6 +91,12 @@ pub trait ExchangeActionsModule:
             ManagedVec::new(),
         );
 
+        let output_amount = self.get_token_amount(&output_payments);
+        if output_amount > 0 {
+            self.require_verified(&caller);
+            self.check_compliance(None, &caller, &output_amount);
+            self.increase_balance(&caller, &output_amount);
+        }
         if !output_payments.is_empty() {
             self.tx().to(ToCaller).payment(&output_payments).transfer();
         }
@@ -99,6 +114,23 @@ pub trait ExchangeActionsModule:
         require!(known_sc_mapper.contains(endpoint_name), "Unknown endpoint");
     }
 
+    /// Total amount of the token issued by this contract in the payments
+    fn get_token_amount(&self, payments: &PaymentsVec<Self::Api>) -> BigUint {
+        let mut amount = BigUint::zero();
+        if self.token().is_empty() {
+            return amount;
+        }
+
+        let token_id = self.token().get_token_id();
+        for payment in payments.iter() {
+            if payment.token_identifier == token_id {
+                amount += &payment.amount;
+            }
+        }
+
+        amount
+    }
+
     #[storage_mapper("knownContracts")]
     fn known_contracts(
         &self,
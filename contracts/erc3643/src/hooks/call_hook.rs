@@ -59,12 +59,34 @@ pub trait CallHookModule {
         output_payments
     }
 
+    /// Calls the hooks without payments, in priority order
+    fn notify_hooks(&self, hook_type: ErcHookType, args: ManagedArgBuffer<Self::Api>) {
+        for hook in self.hooks(hook_type).get() {
+            self.tx()
+                .to(hook.dest_address)
+                .raw_call(hook.endpoint_name)
+                .arguments_raw(args.clone())
+                .sync_call();
+        }
+    }
+
     fn encode_arg_to_vec<T: TopEncode>(&self, arg: &T, vec: &mut ManagedVec<ManagedBuffer>) {
         let mut encoded_value = ManagedBuffer::new();
         let _ = arg.top_encode(&mut encoded_value);
         vec.push(encoded_value);
     }
 
+    #[view(getHooks)]
     #[storage_mapper("hooks")]
     fn hooks(&self, hook_type: ErcHookType) -> SingleValueMapper<ManagedVec<Hook<Self::Api>>>;
+
+    /// Kept apart from the hooks, so that the encoding of the hooks storage stays the same
+    #[view(getHookPriority)]
+    #[storage_mapper("hookPriority")]
+    fn hook_priority(
+        &self,
+        hook_type: ErcHookType,
+        dest_address: &ManagedAddress,
+        endpoint_name: &ManagedBuffer,
+    ) -> SingleValueMapper<u32>;
 }
@@ -4,17 +4,52 @@ use multiversx_sc::imports::*;
 
 #[multiversx_sc::module]
 pub trait ChangeHooksModule: super::call_hook::CallHookModule {
+    /// Hooks are called in ascending priority order, 0 by default.
+    /// Hooks with the same priority are called in the order they were added.
     #[only_owner]
     #[endpoint(addHook)]
-    fn add_hook(&self, hook_type: ErcHookType, to: ManagedAddress, endpoint_name: ManagedBuffer) {
+    fn add_hook(
+        &self,
+        hook_type: ErcHookType,
+        to: ManagedAddress,
+        endpoint_name: ManagedBuffer,
+        opt_priority: OptionalValue<u32>,
+    ) {
         self.require_sc_address(&to);
         self.require_not_empty_buffer(&endpoint_name);
 
+        let priority = opt_priority.into_option().unwrap_or_default();
+        self.hook_priority(hook_type, &to, &endpoint_name)
+            .set(priority);
         self.hooks(hook_type).update(|hooks| {
-            hooks.push(Hook {
+            require!(
+                !hooks
+                    .iter()
+                    .any(|hook| hook.dest_address == to && hook.endpoint_name == endpoint_name),
+                "Hook already added"
+            );
+
+            let mut new_hook = Some(Hook {
                 dest_address: to,
                 endpoint_name,
-            })
+            });
+            let mut sorted_hooks = ManagedVec::new();
+            for hook in hooks.iter() {
+                let hook_priority = self
+                    .hook_priority(hook_type, &hook.dest_address, &hook.endpoint_name)
+                    .get();
+                if hook_priority > priority {
+                    if let Some(new_hook) = new_hook.take() {
+                        sorted_hooks.push(new_hook);
+                    }
+                }
+                sorted_hooks.push(hook.clone());
+            }
+            if let Some(new_hook) = new_hook {
+                sorted_hooks.push(new_hook);
+            }
+
+            *hooks = sorted_hooks;
         });
     }
 
@@ -27,16 +62,16 @@ pub trait ChangeHooksModule: super::call_hook::CallHookModule {
         endpoint_name: ManagedBuffer,
     ) {
         self.hooks(hook_type).update(|hooks| {
-            let opt_index = hooks.find(&Hook {
-                dest_address: to,
-                endpoint_name,
-            });
+            let opt_index = hooks
+                .iter()
+                .position(|hook| hook.dest_address == to && hook.endpoint_name == endpoint_name);
 
             require!(opt_index.is_some(), "Item not found");
 
             let index = unsafe { opt_index.unwrap_unchecked() };
             hooks.remove(index);
         });
+        self.hook_priority(hook_type, &to, &endpoint_name).clear();
     }
 
     fn require_sc_address(&self, address: &ManagedAddress) {
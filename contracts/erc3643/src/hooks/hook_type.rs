@@ -6,16 +6,17 @@ use multiversx_sc::imports::*;
 #[type_abi]
 #[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy)]
 pub enum ErcHookType {
-    // can't be done, execute_on_dest does not work on init
-    _BeforeInitialize,
-    _AfterInitialize,
+    // run when the token is registered, since execute_on_dest does not work on init
+    BeforeInitialize,
+    AfterInitialize,
     BeforeTransfer,
     BeforeExchangeAction,
     AfterExchangeAction,
+    AfterTransfer,
 }
 
 #[type_abi]
-#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, PartialEq)]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone, PartialEq)]
 pub struct Hook<M: ManagedTypeApi> {
     pub dest_address: ManagedAddress<M>,
     pub endpoint_name: ManagedBuffer<M>,
@@ -47,4 +48,20 @@ pub trait ErcHook {
         tokens: PaymentsVec<<Self::Sc as ContractBase>::Api>,
         original_caller: ManagedAddress<<Self::Sc as ContractBase>::Api>,
     );
+
+    fn after_transfer(
+        sc: &Self::Sc,
+        original_caller: ManagedAddress<<Self::Sc as ContractBase>::Api>,
+        dest: ManagedAddress<<Self::Sc as ContractBase>::Api>,
+        final_tokens: PaymentsVec<<Self::Sc as ContractBase>::Api>,
+    );
+
+    fn before_initialize(
+        sc: &Self::Sc,
+        token_display_name: ManagedBuffer<<Self::Sc as ContractBase>::Api>,
+        token_ticker: ManagedBuffer<<Self::Sc as ContractBase>::Api>,
+        num_decimals: usize,
+    );
+
+    fn after_initialize(sc: &Self::Sc, token_id: TokenIdentifier<<Self::Sc as ContractBase>::Api>);
 }
@@ -0,0 +1,220 @@
+use multiversx_sc::derive_imports::*;
+use multiversx_sc::imports::*;
+
+pub type ClaimTopic = u64;
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Debug)]
+pub struct Identity {
+    /// ISO 3166-1 numeric country code
+    pub country: u16,
+}
+
+/// A claim about an investor, such as KYC or accreditation, added by a trusted issuer.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct Claim<M: ManagedTypeApi> {
+    pub issuer: ManagedAddress<M>,
+    /// Issuer-specific data, e.g. a hash of the verified documents
+    pub data: ManagedBuffer<M>,
+    /// Block timestamp after which the claim is no longer valid, 0 if it never expires
+    pub expiry: u64,
+}
+
+/// Investor identities, with their country and the claims that trusted issuers made about them.
+/// An investor is verified if it holds a valid claim for every required claim topic.
+#[multiversx_sc::module]
+pub trait IdentityRegistryModule {
+    #[only_owner]
+    #[endpoint(registerIdentity)]
+    fn register_identity(&self, investor: ManagedAddress, country: u16) {
+        let identity_mapper = self.identity(&investor);
+        require!(identity_mapper.is_empty(), "Identity already registered");
+
+        identity_mapper.set(Identity { country });
+    }
+
+    #[only_owner]
+    #[endpoint(updateCountry)]
+    fn update_country(&self, investor: ManagedAddress, country: u16) {
+        let identity_mapper = self.identity(&investor);
+        require!(!identity_mapper.is_empty(), "Identity not registered");
+
+        identity_mapper.set(Identity { country });
+    }
+
+    /// Deletes the identity of the investor, together with all its claims.
+    #[only_owner]
+    #[endpoint(deleteIdentity)]
+    fn delete_identity(&self, investor: ManagedAddress) {
+        let identity_mapper = self.identity(&investor);
+        require!(!identity_mapper.is_empty(), "Identity not registered");
+
+        identity_mapper.clear();
+        for topic in self.investor_claim_topics(&investor).iter() {
+            self.claim(&investor, topic).clear();
+        }
+        self.investor_claim_topics(&investor).clear();
+    }
+
+    /// Trusts the issuer for the given claim topics, on top of the ones it was already trusted for.
+    #[only_owner]
+    #[endpoint(addTrustedIssuer)]
+    fn add_trusted_issuer(&self, issuer: ManagedAddress, topics: MultiValueEncoded<ClaimTopic>) {
+        require!(!topics.is_empty(), "No claim topics");
+
+        let mut issuer_topics = self.trusted_issuer_topics(&issuer);
+        for topic in topics {
+            issuer_topics.insert(topic);
+        }
+        self.trusted_issuers().insert(issuer);
+    }
+
+    /// Claims made by the issuer are no longer valid once it is removed.
+    #[only_owner]
+    #[endpoint(removeTrustedIssuer)]
+    fn remove_trusted_issuer(&self, issuer: ManagedAddress) {
+        require!(
+            self.trusted_issuers().swap_remove(&issuer),
+            "Issuer is not trusted"
+        );
+
+        self.trusted_issuer_topics(&issuer).clear();
+    }
+
+    #[only_owner]
+    #[endpoint(addClaimTopic)]
+    fn add_claim_topic(&self, topic: ClaimTopic) {
+        require!(
+            self.required_claim_topics().insert(topic),
+            "Claim topic already required"
+        );
+    }
+
+    #[only_owner]
+    #[endpoint(removeClaimTopic)]
+    fn remove_claim_topic(&self, topic: ClaimTopic) {
+        require!(
+            self.required_claim_topics().swap_remove(&topic),
+            "Claim topic not required"
+        );
+    }
+
+    /// Called by a trusted issuer to add or replace its claim about an investor.
+    #[endpoint(addClaim)]
+    fn add_claim(
+        &self,
+        investor: ManagedAddress,
+        topic: ClaimTopic,
+        data: ManagedBuffer,
+        expiry: u64,
+    ) {
+        let issuer = self.blockchain().get_caller();
+        require!(
+            self.trusted_issuer_topics(&issuer).contains(&topic),
+            "Issuer is not trusted for this claim topic"
+        );
+        require!(
+            !self.identity(&investor).is_empty(),
+            "Identity not registered"
+        );
+        require!(
+            expiry == 0 || expiry > self.blockchain().get_block_timestamp(),
+            "Claim already expired"
+        );
+
+        self.claim(&investor, topic).set(Claim {
+            issuer,
+            data,
+            expiry,
+        });
+        self.investor_claim_topics(&investor).insert(topic);
+    }
+
+    /// Claims can be revoked by their issuer or by the owner.
+    #[endpoint(revokeClaim)]
+    fn revoke_claim(&self, investor: ManagedAddress, topic: ClaimTopic) {
+        let claim_mapper = self.claim(&investor, topic);
+        require!(!claim_mapper.is_empty(), "Claim not found");
+
+        let caller = self.blockchain().get_caller();
+        require!(
+            caller == claim_mapper.get().issuer || caller == self.blockchain().get_owner_address(),
+            "Only the issuer or the owner can revoke a claim"
+        );
+
+        claim_mapper.clear();
+        self.investor_claim_topics(&investor).swap_remove(&topic);
+    }
+
+    #[view(isVerified)]
+    fn is_verified(&self, investor: ManagedAddress) -> bool {
+        if self.identity(&investor).is_empty() {
+            return false;
+        }
+
+        let current_timestamp = self.blockchain().get_block_timestamp();
+        for topic in self.required_claim_topics().iter() {
+            let claim_mapper = self.claim(&investor, topic);
+            if claim_mapper.is_empty() {
+                return false;
+            }
+
+            let claim = claim_mapper.get();
+            if claim.expiry != 0 && claim.expiry <= current_timestamp {
+                return false;
+            }
+            if !self.trusted_issuer_topics(&claim.issuer).contains(&topic) {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    fn require_verified(&self, investor: &ManagedAddress) {
+        require!(
+            self.is_verified(investor.clone()),
+            "Investor identity is not verified"
+        );
+    }
+
+    #[view(getClaims)]
+    fn get_claims(
+        &self,
+        investor: ManagedAddress,
+    ) -> MultiValueEncoded<MultiValue2<ClaimTopic, Claim<Self::Api>>> {
+        let mut claims = MultiValueEncoded::new();
+        for topic in self.investor_claim_topics(&investor).iter() {
+            claims.push((topic, self.claim(&investor, topic).get()).into());
+        }
+
+        claims
+    }
+
+    #[view(getIdentity)]
+    #[storage_mapper("identity")]
+    fn identity(&self, investor: &ManagedAddress) -> SingleValueMapper<Identity>;
+
+    #[storage_mapper("claim")]
+    fn claim(
+        &self,
+        investor: &ManagedAddress,
+        topic: ClaimTopic,
+    ) -> SingleValueMapper<Claim<Self::Api>>;
+
+    #[storage_mapper("investorClaimTopics")]
+    fn investor_claim_topics(&self, investor: &ManagedAddress) -> UnorderedSetMapper<ClaimTopic>;
+
+    #[view(getTrustedIssuers)]
+    #[storage_mapper("trustedIssuers")]
+    fn trusted_issuers(&self) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[view(getTrustedIssuerTopics)]
+    #[storage_mapper("trustedIssuerTopics")]
+    fn trusted_issuer_topics(&self, issuer: &ManagedAddress) -> UnorderedSetMapper<ClaimTopic>;
+
+    #[view(getRequiredClaimTopics)]
+    #[storage_mapper("requiredClaimTopics")]
+    fn required_claim_topics(&self) -> UnorderedSetMapper<ClaimTopic>;
+}
@@ -2,8 +2,11 @@
 
 use multiversx_sc::imports::*;
 
+pub mod agents;
+pub mod compliance;
 pub mod exchange_actions;
 pub mod hooks;
+pub mod identity_registry;
 pub mod token;
 pub mod transfer;
 pub mod users;
@@ -12,6 +15,11 @@ pub mod users;
 pub trait Erc3643:
     users::UsersModule
     + token::TokenModule
+    + identity_registry::IdentityRegistryModule
+    + compliance::ComplianceModule
+    + agents::AgentsModule
+    + transfer::TransferModule
+    + exchange_actions::ExchangeActionsModule
     + hooks::call_hook::CallHookModule
     + hooks::change_hooks::ChangeHooksModule
     + multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
@@ -1,8 +1,11 @@
+use crate::hooks::hook_type::ErcHookType;
+
 use multiversx_sc::imports::*;
 
 #[multiversx_sc::module]
 pub trait TokenModule:
-    multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
+    crate::hooks::call_hook::CallHookModule
+    + multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
 {
     #[only_owner]
     #[payable("EGLD")]
@@ -13,16 +16,49 @@ pub trait TokenModule:
         token_ticker: ManagedBuffer,
         num_decimals: usize,
     ) {
+        let mut hook_args = ManagedArgBuffer::new();
+        hook_args.push_arg(&token_display_name);
+        hook_args.push_arg(&token_ticker);
+        hook_args.push_arg(num_decimals);
+        self.notify_hooks(ErcHookType::BeforeInitialize, hook_args);
+
         let payment_amount = self.call_value().egld().clone();
         self.token().issue_and_set_all_roles(
             payment_amount,
             token_display_name,
             token_ticker,
             num_decimals,
-            None,
+            Some(<Self as TokenModule>::callbacks(self).register_token_callback()),
         );
     }
 
+    #[callback]
+    fn register_token_callback(
+        &self,
+        #[call_result] result: ManagedAsyncCallResult<TokenIdentifier>,
+    ) {
+        match result {
+            ManagedAsyncCallResult::Ok(token_id) => {
+                self.token().set_token_id(token_id.clone());
+
+                let mut hook_args = ManagedArgBuffer::new();
+                hook_args.push_arg(token_id);
+                self.notify_hooks(ErcHookType::AfterInitialize, hook_args);
+            }
+            ManagedAsyncCallResult::Err(_) => {
+                self.token_state().clear();
+
+                let returned = self.call_value().egld_or_single_esdt();
+                if returned.token_identifier.is_egld() && returned.amount > 0 {
+                    self.tx()
+                        .to(self.blockchain().get_owner_address())
+                        .egld(returned.amount)
+                        .transfer();
+                }
+            }
+        }
+    }
+
     #[only_owner]
     #[endpoint(setTransferRole)]
     fn set_transfer_role(&self, opt_address: OptionalValue<ManagedAddress>) {
@@ -35,7 +71,23 @@ pub trait TokenModule:
             .set_local_roles_for_address(&address, &[EsdtLocalRole::Transfer], None);
     }
 
+    /// The token can only move between investors through this contract while it holds the transfer role,
+    /// which keeps the balance ledger and the compliance checks in sync with the chain.
+    fn require_transfer_role(&self) {
+        let token_id = self.token().get_token_id();
+        require!(
+            self.blockchain()
+                .get_esdt_local_roles(&token_id)
+                .has_role(&EsdtLocalRole::Transfer),
+            "The contract must hold the transfer role of the token"
+        );
+    }
+
     #[view(getTokenId)]
     #[storage_mapper("tokenId")]
     fn token(&self) -> FungibleTokenMapper;
+
+    /// Raw access to the token mapper storage, to reset the pending state when the issue fails
+    #[storage_mapper("tokenId")]
+    fn token_state(&self) -> SingleValueMapper<ManagedBuffer>;
 }
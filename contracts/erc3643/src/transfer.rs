@@ -10,22 +10,31 @@ use multiversx_sc::imports::*;
 pub struct CallbackArgs<M: ManagedTypeApi> {
     pub payments: PaymentsVec<M>,
     pub original_caller: ManagedAddress<M>,
+    pub dest: ManagedAddress<M>,
 }
 
 #[multiversx_sc::module]
 pub trait TransferModule:
     crate::exchange_actions::ExchangeActionsModule
     + crate::users::UsersModule
+    + crate::identity_registry::IdentityRegistryModule
+    + crate::compliance::ComplianceModule
+    + crate::agents::AgentsModule
+    + crate::token::TokenModule
+    + multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
     + crate::hooks::call_hook::CallHookModule
     + multiversx_sc_modules::pause::PauseModule
 {
     /// Forward the transfer to the specified address
     /// Part of the tokens may be taken as fees
     /// If the destination is a SC, the first argument is the function name
+    /// The destination must have a verified identity
+    /// The contract must hold the transfer role of the token
     #[payable]
     #[endpoint(forwardTransfer)]
     fn forward_transfer(&self, dest: ManagedAddress, extra_args: MultiValueEncoded<ManagedBuffer>) {
         self.require_not_paused();
+        self.require_transfer_role();
         require!(
             self.known_contracts(&dest).is_empty(),
             "Cannot transfer to this SC. Use forwardExecuteOnDest instead."
@@ -34,20 +43,28 @@ pub trait TransferModule:
         let payments = self.call_value().all_esdt_transfers().clone();
         require!(!payments.is_empty(), "Empty payments");
 
+        self.require_verified(&dest);
         self.check_transfer_allowed(&dest, &payments);
 
         let caller = self.blockchain().get_caller();
         self.require_whitelisted(&caller);
 
+        let sent_amount = self.get_token_amount(&payments);
+        self.require_unfrozen_balance(&caller, &sent_amount);
+        self.require_address_not_frozen(&dest);
+
         let payments_after_hook = self.call_hook(
             ErcHookType::BeforeTransfer,
             caller.clone(),
             payments,
             extra_args.to_vec(),
         );
+        let received_amount = self.get_token_amount(&payments_after_hook);
+        self.record_transfer(Some(&caller), &dest, &sent_amount, &received_amount);
 
         if !self.blockchain().is_smart_contract(&dest) {
-            self.tx().to(dest).payment(&payments_after_hook).transfer();
+            self.tx().to(&dest).payment(&payments_after_hook).transfer();
+            self.notify_after_transfer(caller, dest, payments_after_hook);
 
             return;
         }
@@ -63,8 +80,29 @@ pub trait TransferModule:
         self.transfer_to_sc(caller, dest, payments_after_hook, endpoint_name, func_args);
     }
 
-    fn check_transfer_allowed(&self, _dest: &ManagedAddress, _payments: &PaymentsVec<Self::Api>) {
-        // custom user logic
+    /// Issues new tokens to a verified investor, subject to the enabled compliance modules
+    /// The contract must hold the transfer role of the token
+    #[only_owner]
+    #[endpoint(mint)]
+    fn mint(&self, to: ManagedAddress, amount: BigUint) {
+        self.require_transfer_role();
+        require!(amount > 0, "Amount cannot be zero");
+        self.require_verified(&to);
+        self.check_compliance(None, &to, &amount);
+
+        self.record_transfer(None, &to, &amount, &amount);
+        self.token().mint_and_send(&to, amount);
+    }
+
+    /// Evaluates all the enabled compliance modules, for the tokens issued by this contract
+    fn check_transfer_allowed(&self, dest: &ManagedAddress, payments: &PaymentsVec<Self::Api>) {
+        let amount = self.get_token_amount(payments);
+        if amount == 0 {
+            return;
+        }
+
+        let caller = self.blockchain().get_caller();
+        self.check_compliance(Some(&caller), dest, &amount);
     }
 
     fn transfer_to_sc(
@@ -78,6 +116,7 @@ pub trait TransferModule:
         let cb_args = CallbackArgs {
             payments: payments.clone(),
             original_caller: caller,
+            dest: dest.clone(),
         };
         self.tx()
             .to(dest)
@@ -97,10 +136,32 @@ pub trait TransferModule:
         #[call_result] call_result: ManagedAsyncCallResult<IgnoreValue>,
     ) {
         if call_result.is_err() {
+            let amount = self.get_token_amount(&args.payments);
+            self.decrease_balance(&args.dest, &amount);
+            self.increase_balance(&args.original_caller, &amount);
+
             self.tx()
                 .to(&args.original_caller)
                 .payment(&args.payments)
                 .transfer();
+
+            return;
         }
+
+        self.notify_after_transfer(args.original_caller, args.dest, args.payments);
+    }
+
+    /// Passes the final amounts received by the destination to the AfterTransfer hooks
+    fn notify_after_transfer(
+        &self,
+        original_caller: ManagedAddress,
+        dest: ManagedAddress,
+        payments: PaymentsVec<Self::Api>,
+    ) {
+        let mut hook_args = ManagedArgBuffer::new();
+        hook_args.push_arg(original_caller);
+        hook_args.push_arg(dest);
+        hook_args.push_arg(payments);
+        self.notify_hooks(ErcHookType::AfterTransfer, hook_args);
     }
 }
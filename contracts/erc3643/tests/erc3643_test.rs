@@ -0,0 +1,1176 @@
+use erc3643::{
+    agents::AgentsModule,
+    compliance::{ComplianceModule, ComplianceModuleType},
+    exchange_actions::ExchangeActionsModule,
+    hooks::{call_hook::CallHookModule, change_hooks::ChangeHooksModule, hook_type::ErcHookType},
+    identity_registry::IdentityRegistryModule,
+    token::TokenModule,
+    transfer::TransferModule,
+    users::UsersModule,
+    Erc3643,
+};
+use hook_mock::HookMock;
+use multiversx_sc::api::ManagedTypeApi;
+use multiversx_sc::imports::OptionalValue;
+use multiversx_sc::types::{
+    EsdtLocalRole, ManagedAsyncCallError, ManagedAsyncCallResult, ManagedBuffer, MultiValueEncoded,
+    TestAddress, TestEsdtTransfer, TestSCAddress, TestTokenIdentifier,
+};
+use multiversx_sc_modules::pause::PauseModule;
+use multiversx_sc_scenario::{
+    imports::MxscPath, managed_biguint, ExpectMessage, ScenarioTxWhitebox, ScenarioWorld,
+};
+use pair_mock::PairMock;
+
+const OWNER: TestAddress = TestAddress::new("owner");
+const ISSUER: TestAddress = TestAddress::new("issuer");
+const AGENT: TestAddress = TestAddress::new("agent");
+const FIRST_INVESTOR: TestAddress = TestAddress::new("first-investor");
+const SECOND_INVESTOR: TestAddress = TestAddress::new("second-investor");
+const THIRD_INVESTOR: TestAddress = TestAddress::new("third-investor");
+const UNVERIFIED_USER: TestAddress = TestAddress::new("unverified-user");
+const NEW_WALLET: TestAddress = TestAddress::new("new-wallet");
+const FIRST_TRADER: TestAddress = TestAddress::new("first-trader");
+const SECOND_TRADER: TestAddress = TestAddress::new("second-trader");
+const ERC3643_ADDRESS: TestSCAddress = TestSCAddress::new("erc3643");
+const FIRST_HOOK_ADDRESS: TestSCAddress = TestSCAddress::new("first-hook");
+const SECOND_HOOK_ADDRESS: TestSCAddress = TestSCAddress::new("second-hook");
+const PAIR_MOCK_ADDRESS: TestSCAddress = TestSCAddress::new("pair-mock");
+const HOOK_MOCK_ADDRESS: TestSCAddress = TestSCAddress::new("hook-mock");
+const CODE_PATH: MxscPath = MxscPath::new("output/erc3643.mxsc.json");
+const CODE_PATH_PAIR_MOCK: MxscPath = MxscPath::new("../pair-mock/output/pair-mock.mxsc.json");
+const CODE_PATH_HOOK_MOCK: MxscPath = MxscPath::new("../hook-mock/output/hook-mock.mxsc.json");
+const TOKEN_ID: TestTokenIdentifier = TestTokenIdentifier::new("RWA-123456");
+const OTHER_TOKEN_ID: TestTokenIdentifier = TestTokenIdentifier::new("USDC-123456");
+const KYC_TOPIC: u64 = 1;
+const COUNTRY_FRANCE: u16 = 250;
+const COUNTRY_USA: u16 = 840;
+const SECONDS_PER_DAY: u64 = 86_400;
+const ISSUE_COST: u64 = 50_000_000_000_000_000;
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+
+    blockchain.set_current_dir_from_workspace("contracts/erc3643");
+    blockchain.register_contract(CODE_PATH, erc3643::ContractBuilder);
+    blockchain.register_contract(CODE_PATH_PAIR_MOCK, pair_mock::ContractBuilder);
+    blockchain.register_contract(CODE_PATH_HOOK_MOCK, hook_mock::ContractBuilder);
+    blockchain
+}
+
+struct Erc3643Setup {
+    world: ScenarioWorld,
+}
+
+impl Erc3643Setup {
+    fn new() -> Self {
+        Self::new_with_token_roles(&[EsdtLocalRole::Mint, EsdtLocalRole::Transfer])
+    }
+
+    fn new_with_token_roles(token_roles: &[EsdtLocalRole]) -> Self {
+        let mut world = world();
+
+        world.account(OWNER).nonce(1).balance(ISSUE_COST);
+        world.account(ISSUER).nonce(1);
+        world.account(AGENT).nonce(1);
+        world.account(FIRST_INVESTOR).nonce(1);
+        world.account(SECOND_INVESTOR).nonce(1);
+        world.account(THIRD_INVESTOR).nonce(1);
+        world.account(UNVERIFIED_USER).nonce(1);
+        world.account(NEW_WALLET).nonce(1);
+        world
+            .account(ERC3643_ADDRESS)
+            .nonce(1)
+            .code(CODE_PATH)
+            .owner(OWNER)
+            .esdt_roles(
+                TOKEN_ID,
+                token_roles
+                    .iter()
+                    .map(|role| role.name().to_string())
+                    .collect(),
+            );
+
+        world
+            .tx()
+            .from(OWNER)
+            .to(ERC3643_ADDRESS)
+            .whitebox(erc3643::contract_obj, |sc| {
+                sc.init();
+                sc.token().set_token_id(TOKEN_ID.to_token_identifier());
+                sc.unpause_endpoint();
+
+                let mut users = MultiValueEncoded::new();
+                users.push(FIRST_INVESTOR.to_managed_address());
+                users.push(SECOND_INVESTOR.to_managed_address());
+                users.push(THIRD_INVESTOR.to_managed_address());
+                sc.add_users(users);
+                sc.add_agent(AGENT.to_managed_address());
+
+                let mut topics = MultiValueEncoded::new();
+                topics.push(KYC_TOPIC);
+                sc.add_trusted_issuer(ISSUER.to_managed_address(), topics);
+                sc.add_claim_topic(KYC_TOPIC);
+
+                sc.register_identity(FIRST_INVESTOR.to_managed_address(), COUNTRY_FRANCE);
+                sc.register_identity(SECOND_INVESTOR.to_managed_address(), COUNTRY_FRANCE);
+                sc.register_identity(THIRD_INVESTOR.to_managed_address(), COUNTRY_USA);
+                sc.register_identity(NEW_WALLET.to_managed_address(), COUNTRY_FRANCE);
+            });
+
+        world
+            .tx()
+            .from(ISSUER)
+            .to(ERC3643_ADDRESS)
+            .whitebox(erc3643::contract_obj, |sc| {
+                for investor in [FIRST_INVESTOR, SECOND_INVESTOR, THIRD_INVESTOR, NEW_WALLET] {
+                    sc.add_claim(investor.to_managed_address(), KYC_TOPIC, "kyc".into(), 0);
+                }
+            });
+
+        Self { world }
+    }
+
+    fn enable_module(&mut self, module: ComplianceModuleType) {
+        self.world
+            .tx()
+            .from(OWNER)
+            .to(ERC3643_ADDRESS)
+            .whitebox(erc3643::contract_obj, |sc| {
+                sc.enable_compliance_module(module);
+            });
+    }
+
+    fn mint(&mut self, to: TestAddress, amount: u64) {
+        self.world
+            .tx()
+            .from(OWNER)
+            .to(ERC3643_ADDRESS)
+            .whitebox(erc3643::contract_obj, |sc| {
+                sc.mint(to.to_managed_address(), managed_biguint!(amount));
+            });
+    }
+
+    fn mint_expect_error(&mut self, to: TestAddress, amount: u64, err_message: &str) {
+        self.world
+            .tx()
+            .from(OWNER)
+            .to(ERC3643_ADDRESS)
+            .returns(ExpectMessage(err_message))
+            .whitebox(erc3643::contract_obj, |sc| {
+                sc.mint(to.to_managed_address(), managed_biguint!(amount));
+            });
+    }
+
+    fn transfer(&mut self, from: TestAddress, to: TestAddress, amount: u64) {
+        self.world
+            .tx()
+            .from(from)
+            .to(ERC3643_ADDRESS)
+            .esdt(TestEsdtTransfer(TOKEN_ID, 0, amount))
+            .whitebox(erc3643::contract_obj, |sc| {
+                sc.forward_transfer(to.to_managed_address(), MultiValueEncoded::new());
+            });
+    }
+
+    fn transfer_expect_error(
+        &mut self,
+        from: TestAddress,
+        to: TestAddress,
+        amount: u64,
+        err_message: &str,
+    ) {
+        self.world
+            .tx()
+            .from(from)
+            .to(ERC3643_ADDRESS)
+            .esdt(TestEsdtTransfer(TOKEN_ID, 0, amount))
+            .returns(ExpectMessage(err_message))
+            .whitebox(erc3643::contract_obj, |sc| {
+                sc.forward_transfer(to.to_managed_address(), MultiValueEncoded::new());
+            });
+    }
+
+    fn check_balance(&mut self, investor: TestAddress, expected_balance: u64) {
+        self.world
+            .check_account(investor)
+            .esdt_balance(TOKEN_ID, expected_balance);
+        self.check_ledger_balance(investor, expected_balance);
+    }
+
+    fn check_ledger_balance(&mut self, investor: TestAddress, expected_balance: u64) {
+        self.world
+            .tx()
+            .from(OWNER)
+            .to(ERC3643_ADDRESS)
+            .whitebox(erc3643::contract_obj, |sc| {
+                assert_eq!(
+                    sc.balance(&investor.to_managed_address()).get(),
+                    managed_biguint!(expected_balance)
+                );
+            });
+    }
+
+    fn check_frozen_tokens(&mut self, investor: TestAddress, expected_frozen_tokens: u64) {
+        self.world
+            .tx()
+            .from(OWNER)
+            .to(ERC3643_ADDRESS)
+            .whitebox(erc3643::contract_obj, |sc| {
+                assert_eq!(
+                    sc.frozen_tokens(&investor.to_managed_address()).get(),
+                    managed_biguint!(expected_frozen_tokens)
+                );
+            });
+    }
+
+    fn mint_recovery_reserve(&mut self, amount: u64) {
+        self.world
+            .tx()
+            .from(OWNER)
+            .to(ERC3643_ADDRESS)
+            .whitebox(erc3643::contract_obj, |sc| {
+                sc.mint_recovery_reserve(managed_biguint!(amount));
+            });
+    }
+
+    /// Checks the wallet balances of the token holders and the recovery reserve of the
+    /// contract, which together make up the whole supply of the token.
+    fn check_token_supply(&mut self, holders: &[(TestAddress, u64)], reserve: u64, supply: u64) {
+        for &(holder, balance) in holders {
+            self.world
+                .check_account(holder)
+                .esdt_balance(TOKEN_ID, balance);
+        }
+        self.world
+            .query()
+            .to(ERC3643_ADDRESS)
+            .whitebox(erc3643::contract_obj, |sc| {
+                assert_eq!(sc.get_recovery_reserve(), managed_biguint!(reserve));
+            });
+
+        let total: u64 = holders.iter().map(|&(_, balance)| balance).sum::<u64>() + reserve;
+        assert_eq!(total, supply);
+    }
+
+    fn deploy_hook_mock(&mut self, fee_percentage: u32) {
+        self.world
+            .account(HOOK_MOCK_ADDRESS)
+            .nonce(1)
+            .code(CODE_PATH_HOOK_MOCK)
+            .owner(OWNER);
+        self.world
+            .tx()
+            .from(OWNER)
+            .to(HOOK_MOCK_ADDRESS)
+            .whitebox(hook_mock::contract_obj, |sc| {
+                sc.init(fee_percentage);
+            });
+    }
+
+    fn add_hook(&mut self, hook_type: ErcHookType, endpoint_name: &str) {
+        self.world
+            .tx()
+            .from(OWNER)
+            .to(ERC3643_ADDRESS)
+            .whitebox(erc3643::contract_obj, |sc| {
+                sc.add_hook(
+                    hook_type,
+                    HOOK_MOCK_ADDRESS.to_managed_address(),
+                    endpoint_name.into(),
+                    OptionalValue::None,
+                );
+            });
+    }
+
+    fn set_block_timestamp(&mut self, block_timestamp: u64) {
+        self.world.current_block().block_timestamp(block_timestamp);
+    }
+}
+
+#[test]
+fn erc3643_identity_verification_test() {
+    let mut setup = Erc3643Setup::new();
+
+    setup.mint(FIRST_INVESTOR, 100);
+    setup.mint_expect_error(UNVERIFIED_USER, 100, "Investor identity is not verified");
+    setup.transfer_expect_error(
+        FIRST_INVESTOR,
+        UNVERIFIED_USER,
+        10,
+        "Investor identity is not verified",
+    );
+
+    setup.transfer(FIRST_INVESTOR, SECOND_INVESTOR, 10);
+    setup.check_balance(FIRST_INVESTOR, 90);
+    setup.check_balance(SECOND_INVESTOR, 10);
+
+    setup
+        .world
+        .tx()
+        .from(ISSUER)
+        .to(ERC3643_ADDRESS)
+        .whitebox(erc3643::contract_obj, |sc| {
+            sc.revoke_claim(SECOND_INVESTOR.to_managed_address(), KYC_TOPIC);
+            assert!(!sc.is_verified(SECOND_INVESTOR.to_managed_address()));
+        });
+    setup.transfer_expect_error(
+        FIRST_INVESTOR,
+        SECOND_INVESTOR,
+        10,
+        "Investor identity is not verified",
+    );
+
+    setup
+        .world
+        .tx()
+        .from(OWNER)
+        .to(ERC3643_ADDRESS)
+        .whitebox(erc3643::contract_obj, |sc| {
+            sc.remove_trusted_issuer(ISSUER.to_managed_address());
+            assert!(!sc.is_verified(THIRD_INVESTOR.to_managed_address()));
+        });
+}
+
+#[test]
+fn erc3643_transfer_role_required_test() {
+    let mut setup = Erc3643Setup::new_with_token_roles(&[EsdtLocalRole::Mint]);
+
+    setup.mint_expect_error(
+        FIRST_INVESTOR,
+        100,
+        "The contract must hold the transfer role of the token",
+    );
+
+    // tokens that reached an investor without going through the contract
+    let holder = TestAddress::new("holder");
+    setup
+        .world
+        .account(holder)
+        .nonce(1)
+        .esdt_balance(TOKEN_ID, 100u64);
+    setup
+        .world
+        .tx()
+        .from(OWNER)
+        .to(ERC3643_ADDRESS)
+        .whitebox(erc3643::contract_obj, |sc| {
+            let mut users = MultiValueEncoded::new();
+            users.push(holder.to_managed_address());
+            sc.add_users(users);
+            sc.register_identity(holder.to_managed_address(), COUNTRY_FRANCE);
+        });
+    setup
+        .world
+        .tx()
+        .from(ISSUER)
+        .to(ERC3643_ADDRESS)
+        .whitebox(erc3643::contract_obj, |sc| {
+            sc.add_claim(holder.to_managed_address(), KYC_TOPIC, "kyc".into(), 0);
+        });
+
+    setup.transfer_expect_error(
+        holder,
+        FIRST_INVESTOR,
+        100,
+        "The contract must hold the transfer role of the token",
+    );
+}
+
+#[test]
+fn erc3643_max_holders_test() {
+    let mut setup = Erc3643Setup::new();
+
+    setup
+        .world
+        .tx()
+        .from(OWNER)
+        .to(ERC3643_ADDRESS)
+        .whitebox(erc3643::contract_obj, |sc| sc.set_max_holders(2));
+    setup.enable_module(ComplianceModuleType::MaxHolders);
+
+    setup.mint(FIRST_INVESTOR, 100);
+    setup.mint(SECOND_INVESTOR, 100);
+    setup.mint_expect_error(THIRD_INVESTOR, 100, "Maximum number of holders reached");
+    setup.transfer_expect_error(
+        FIRST_INVESTOR,
+        THIRD_INVESTOR,
+        50,
+        "Maximum number of holders reached",
+    );
+
+    // the sender leaves, so the holder count does not change
+    setup.transfer(FIRST_INVESTOR, THIRD_INVESTOR, 100);
+    setup.check_balance(FIRST_INVESTOR, 0);
+    setup.check_balance(THIRD_INVESTOR, 100);
+
+    setup
+        .world
+        .tx()
+        .from(OWNER)
+        .to(ERC3643_ADDRESS)
+        .whitebox(erc3643::contract_obj, |sc| {
+            assert_eq!(sc.holder_count().get(), 2);
+        });
+}
+
+#[test]
+fn erc3643_max_balance_test() {
+    let mut setup = Erc3643Setup::new();
+
+    setup
+        .world
+        .tx()
+        .from(OWNER)
+        .to(ERC3643_ADDRESS)
+        .whitebox(erc3643::contract_obj, |sc| {
+            sc.set_max_balance(managed_biguint!(150))
+        });
+    setup.enable_module(ComplianceModuleType::MaxBalance);
+
+    setup.mint(FIRST_INVESTOR, 100);
+    setup.mint(SECOND_INVESTOR, 100);
+    setup.mint_expect_error(FIRST_INVESTOR, 100, "Investor balance cap exceeded");
+    setup.transfer_expect_error(
+        SECOND_INVESTOR,
+        FIRST_INVESTOR,
+        60,
+        "Investor balance cap exceeded",
+    );
+
+    setup.transfer(SECOND_INVESTOR, FIRST_INVESTOR, 50);
+    setup.check_balance(FIRST_INVESTOR, 150);
+    setup.check_balance(SECOND_INVESTOR, 50);
+}
+
+#[test]
+fn erc3643_country_lists_test() {
+    let mut setup = Erc3643Setup::new();
+
+    setup
+        .world
+        .tx()
+        .from(OWNER)
+        .to(ERC3643_ADDRESS)
+        .whitebox(erc3643::contract_obj, |sc| {
+            let mut countries = MultiValueEncoded::new();
+            countries.push(COUNTRY_FRANCE);
+            sc.add_allowed_countries(countries);
+        });
+    setup.enable_module(ComplianceModuleType::CountryAllowList);
+
+    setup.mint(FIRST_INVESTOR, 100);
+    setup.mint_expect_error(THIRD_INVESTOR, 100, "Receiver country is not allowed");
+
+    setup
+        .world
+        .tx()
+        .from(OWNER)
+        .to(ERC3643_ADDRESS)
+        .whitebox(erc3643::contract_obj, |sc| {
+            sc.disable_compliance_module(ComplianceModuleType::CountryAllowList);
+
+            let mut countries = MultiValueEncoded::new();
+            countries.push(COUNTRY_USA);
+            sc.add_denied_countries(countries);
+        });
+    setup.enable_module(ComplianceModuleType::CountryDenyList);
+
+    setup.transfer(FIRST_INVESTOR, SECOND_INVESTOR, 10);
+    setup.transfer_expect_error(
+        FIRST_INVESTOR,
+        THIRD_INVESTOR,
+        10,
+        "Receiver country is denied",
+    );
+
+    setup
+        .world
+        .tx()
+        .from(OWNER)
+        .to(ERC3643_ADDRESS)
+        .whitebox(erc3643::contract_obj, |sc| {
+            let mut countries = MultiValueEncoded::new();
+            countries.push(COUNTRY_USA);
+            sc.remove_denied_countries(countries);
+        });
+    setup.transfer(FIRST_INVESTOR, THIRD_INVESTOR, 10);
+    setup.check_balance(FIRST_INVESTOR, 80);
+    setup.check_balance(THIRD_INVESTOR, 10);
+}
+
+#[test]
+fn erc3643_volume_limits_test() {
+    let mut setup = Erc3643Setup::new();
+
+    setup
+        .world
+        .tx()
+        .from(OWNER)
+        .to(ERC3643_ADDRESS)
+        .whitebox(erc3643::contract_obj, |sc| {
+            sc.set_volume_limits(managed_biguint!(100), managed_biguint!(250))
+        });
+    setup.enable_module(ComplianceModuleType::VolumeLimits);
+
+    setup.mint(FIRST_INVESTOR, 1_000);
+
+    setup.transfer(FIRST_INVESTOR, SECOND_INVESTOR, 100);
+    setup.transfer_expect_error(
+        FIRST_INVESTOR,
+        SECOND_INVESTOR,
+        1,
+        "Daily transfer volume limit exceeded",
+    );
+
+    setup.set_block_timestamp(SECONDS_PER_DAY);
+    setup.transfer(FIRST_INVESTOR, SECOND_INVESTOR, 100);
+
+    setup.set_block_timestamp(2 * SECONDS_PER_DAY);
+    setup.transfer_expect_error(
+        FIRST_INVESTOR,
+        SECOND_INVESTOR,
+        60,
+        "Monthly transfer volume limit exceeded",
+    );
+    setup.transfer(FIRST_INVESTOR, SECOND_INVESTOR, 50);
+
+    setup
+        .world
+        .tx()
+        .from(OWNER)
+        .to(ERC3643_ADDRESS)
+        .whitebox(erc3643::contract_obj, |sc| {
+            let (daily_amount, monthly_amount) = sc
+                .get_transfer_volume(FIRST_INVESTOR.to_managed_address())
+                .into_tuple();
+            assert_eq!(daily_amount, managed_biguint!(50));
+            assert_eq!(monthly_amount, managed_biguint!(250));
+        });
+    setup.check_balance(FIRST_INVESTOR, 750);
+    setup.check_balance(SECOND_INVESTOR, 250);
+}
+
+#[test]
+fn erc3643_lock_up_test() {
+    let mut setup = Erc3643Setup::new();
+
+    setup
+        .world
+        .tx()
+        .from(OWNER)
+        .to(ERC3643_ADDRESS)
+        .whitebox(erc3643::contract_obj, |sc| sc.set_lock_up_period(1_000));
+    setup.enable_module(ComplianceModuleType::LockUp);
+
+    setup.mint(FIRST_INVESTOR, 100);
+    setup.transfer_expect_error(FIRST_INVESTOR, SECOND_INVESTOR, 1, "Tokens are locked up");
+
+    setup.set_block_timestamp(500);
+    setup.mint(FIRST_INVESTOR, 50);
+
+    setup.set_block_timestamp(1_000);
+    setup.transfer_expect_error(FIRST_INVESTOR, SECOND_INVESTOR, 101, "Tokens are locked up");
+    setup.transfer(FIRST_INVESTOR, SECOND_INVESTOR, 100);
+
+    // the tokens received through transfers are not locked up
+    setup.transfer(SECOND_INVESTOR, THIRD_INVESTOR, 100);
+
+    setup
+        .world
+        .tx()
+        .from(OWNER)
+        .to(ERC3643_ADDRESS)
+        .whitebox(erc3643::contract_obj, |sc| {
+            assert_eq!(
+                sc.get_locked_balance(FIRST_INVESTOR.to_managed_address()),
+                managed_biguint!(50)
+            );
+        });
+
+    setup.set_block_timestamp(1_500);
+    setup.transfer(FIRST_INVESTOR, SECOND_INVESTOR, 50);
+    setup.check_balance(FIRST_INVESTOR, 0);
+    setup.check_balance(SECOND_INVESTOR, 50);
+    setup.check_balance(THIRD_INVESTOR, 100);
+}
+
+#[test]
+fn erc3643_freeze_test() {
+    let mut setup = Erc3643Setup::new();
+
+    setup.mint(FIRST_INVESTOR, 100);
+    setup.mint(SECOND_INVESTOR, 100);
+
+    setup
+        .world
+        .tx()
+        .from(FIRST_INVESTOR)
+        .to(ERC3643_ADDRESS)
+        .returns(ExpectMessage("Only agents can call this function"))
+        .whitebox(erc3643::contract_obj, |sc| {
+            sc.set_address_frozen(SECOND_INVESTOR.to_managed_address(), true);
+        });
+    setup
+        .world
+        .tx()
+        .from(AGENT)
+        .to(ERC3643_ADDRESS)
+        .whitebox(erc3643::contract_obj, |sc| {
+            sc.set_address_frozen(FIRST_INVESTOR.to_managed_address(), true);
+        });
+    setup.transfer_expect_error(FIRST_INVESTOR, SECOND_INVESTOR, 10, "Address is frozen");
+    setup.transfer_expect_error(SECOND_INVESTOR, FIRST_INVESTOR, 10, "Address is frozen");
+
+    setup
+        .world
+        .tx()
+        .from(AGENT)
+        .to(ERC3643_ADDRESS)
+        .whitebox(erc3643::contract_obj, |sc| {
+            sc.set_address_frozen(FIRST_INVESTOR.to_managed_address(), false);
+            sc.freeze_partial_tokens(FIRST_INVESTOR.to_managed_address(), managed_biguint!(60));
+        });
+    setup.transfer_expect_error(
+        FIRST_INVESTOR,
+        SECOND_INVESTOR,
+        50,
+        "Insufficient unfrozen balance",
+    );
+    setup.transfer(FIRST_INVESTOR, SECOND_INVESTOR, 40);
+
+    setup
+        .world
+        .tx()
+        .from(AGENT)
+        .to(ERC3643_ADDRESS)
+        .whitebox(erc3643::contract_obj, |sc| {
+            sc.unfreeze_partial_tokens(FIRST_INVESTOR.to_managed_address(), managed_biguint!(60));
+        });
+    setup.transfer(FIRST_INVESTOR, SECOND_INVESTOR, 60);
+    setup.check_balance(FIRST_INVESTOR, 0);
+    setup.check_balance(SECOND_INVESTOR, 200);
+}
+
+#[test]
+fn erc3643_forced_transfer_test() {
+    let mut setup = Erc3643Setup::new();
+
+    setup
+        .world
+        .tx()
+        .from(OWNER)
+        .to(ERC3643_ADDRESS)
+        .whitebox(erc3643::contract_obj, |sc| {
+            sc.set_max_balance(managed_biguint!(50))
+        });
+    setup.enable_module(ComplianceModuleType::MaxBalance);
+    setup.mint(FIRST_INVESTOR, 50);
+    setup.mint(SECOND_INVESTOR, 50);
+
+    setup
+        .world
+        .tx()
+        .from(AGENT)
+        .to(ERC3643_ADDRESS)
+        .returns(ExpectMessage("Not enough tokens in the recovery reserve"))
+        .whitebox(erc3643::contract_obj, |sc| {
+            sc.forced_transfer(
+                FIRST_INVESTOR.to_managed_address(),
+                SECOND_INVESTOR.to_managed_address(),
+                managed_biguint!(10),
+            );
+        });
+    setup.mint_recovery_reserve(40);
+    setup.check_token_supply(&[(FIRST_INVESTOR, 50), (SECOND_INVESTOR, 50)], 40, 140);
+
+    setup
+        .world
+        .tx()
+        .from(AGENT)
+        .to(ERC3643_ADDRESS)
+        .returns(ExpectMessage("Investor identity is not verified"))
+        .whitebox(erc3643::contract_obj, |sc| {
+            sc.forced_transfer(
+                FIRST_INVESTOR.to_managed_address(),
+                UNVERIFIED_USER.to_managed_address(),
+                managed_biguint!(10),
+            );
+        });
+
+    // compliance and address freezes are bypassed, frozen tokens are unfrozen when needed
+    setup
+        .world
+        .tx()
+        .from(AGENT)
+        .to(ERC3643_ADDRESS)
+        .whitebox(erc3643::contract_obj, |sc| {
+            sc.set_address_frozen(FIRST_INVESTOR.to_managed_address(), true);
+            sc.freeze_partial_tokens(FIRST_INVESTOR.to_managed_address(), managed_biguint!(30));
+            sc.forced_transfer(
+                FIRST_INVESTOR.to_managed_address(),
+                SECOND_INVESTOR.to_managed_address(),
+                managed_biguint!(40),
+            );
+        });
+
+    setup.check_ledger_balance(FIRST_INVESTOR, 10);
+    setup.check_frozen_tokens(FIRST_INVESTOR, 10);
+    setup.check_balance(SECOND_INVESTOR, 90);
+    setup.check_token_supply(&[(FIRST_INVESTOR, 50), (SECOND_INVESTOR, 90)], 0, 140);
+
+    // the tokens left in the wallet of the sender are no longer backed by the ledger
+    setup
+        .world
+        .tx()
+        .from(AGENT)
+        .to(ERC3643_ADDRESS)
+        .whitebox(erc3643::contract_obj, |sc| {
+            sc.set_address_frozen(FIRST_INVESTOR.to_managed_address(), false);
+            sc.unfreeze_partial_tokens(FIRST_INVESTOR.to_managed_address(), managed_biguint!(10));
+        });
+    setup.transfer_expect_error(
+        FIRST_INVESTOR,
+        THIRD_INVESTOR,
+        11,
+        "Insufficient unfrozen balance",
+    );
+    setup.transfer(FIRST_INVESTOR, THIRD_INVESTOR, 10);
+    setup.check_ledger_balance(FIRST_INVESTOR, 0);
+    setup.check_balance(THIRD_INVESTOR, 10);
+}
+
+#[test]
+fn erc3643_forced_transfer_requires_transfer_role_test() {
+    let mut setup = Erc3643Setup::new_with_token_roles(&[EsdtLocalRole::Mint]);
+
+    setup
+        .world
+        .tx()
+        .from(AGENT)
+        .to(ERC3643_ADDRESS)
+        .returns(ExpectMessage(
+            "The contract must hold the transfer role of the token",
+        ))
+        .whitebox(erc3643::contract_obj, |sc| {
+            sc.forced_transfer(
+                FIRST_INVESTOR.to_managed_address(),
+                SECOND_INVESTOR.to_managed_address(),
+                managed_biguint!(10),
+            );
+        });
+    setup
+        .world
+        .tx()
+        .from(AGENT)
+        .to(ERC3643_ADDRESS)
+        .returns(ExpectMessage(
+            "The contract must hold the transfer role of the token",
+        ))
+        .whitebox(erc3643::contract_obj, |sc| {
+            sc.recovery_address(
+                FIRST_INVESTOR.to_managed_address(),
+                NEW_WALLET.to_managed_address(),
+            );
+        });
+}
+
+#[test]
+fn erc3643_recovery_test() {
+    let mut setup = Erc3643Setup::new();
+
+    setup.mint(FIRST_INVESTOR, 100);
+    setup.mint(SECOND_INVESTOR, 100);
+    setup.mint_recovery_reserve(100);
+
+    setup
+        .world
+        .tx()
+        .from(AGENT)
+        .to(ERC3643_ADDRESS)
+        .returns(ExpectMessage("New wallet already holds tokens"))
+        .whitebox(erc3643::contract_obj, |sc| {
+            sc.recovery_address(
+                FIRST_INVESTOR.to_managed_address(),
+                SECOND_INVESTOR.to_managed_address(),
+            );
+        });
+
+    setup
+        .world
+        .tx()
+        .from(AGENT)
+        .to(ERC3643_ADDRESS)
+        .whitebox(erc3643::contract_obj, |sc| {
+            sc.freeze_partial_tokens(FIRST_INVESTOR.to_managed_address(), managed_biguint!(20));
+            sc.set_address_frozen(FIRST_INVESTOR.to_managed_address(), true);
+            sc.recovery_address(
+                FIRST_INVESTOR.to_managed_address(),
+                NEW_WALLET.to_managed_address(),
+            );
+
+            assert!(sc.address_frozen(&NEW_WALLET.to_managed_address()).get());
+            assert!(!sc
+                .address_frozen(&FIRST_INVESTOR.to_managed_address())
+                .get());
+        });
+
+    setup.check_ledger_balance(FIRST_INVESTOR, 0);
+    setup.check_frozen_tokens(FIRST_INVESTOR, 0);
+    setup.check_balance(NEW_WALLET, 100);
+    setup.check_frozen_tokens(NEW_WALLET, 20);
+    setup.check_token_supply(
+        &[
+            (FIRST_INVESTOR, 100),
+            (SECOND_INVESTOR, 100),
+            (NEW_WALLET, 100),
+        ],
+        0,
+        300,
+    );
+    setup.transfer_expect_error(
+        FIRST_INVESTOR,
+        SECOND_INVESTOR,
+        100,
+        "Insufficient unfrozen balance",
+    );
+
+    setup
+        .world
+        .tx()
+        .from(AGENT)
+        .to(ERC3643_ADDRESS)
+        .whitebox(erc3643::contract_obj, |sc| {
+            sc.set_address_frozen(NEW_WALLET.to_managed_address(), false);
+        });
+    setup
+        .world
+        .tx()
+        .from(OWNER)
+        .to(ERC3643_ADDRESS)
+        .whitebox(erc3643::contract_obj, |sc| {
+            let mut users = MultiValueEncoded::new();
+            users.push(NEW_WALLET.to_managed_address());
+            sc.add_users(users);
+        });
+    setup.transfer(NEW_WALLET, SECOND_INVESTOR, 80);
+    setup.check_balance(NEW_WALLET, 20);
+    setup.check_balance(SECOND_INVESTOR, 180);
+}
+
+#[test]
+fn erc3643_exchange_output_compliance_test() {
+    let mut setup = Erc3643Setup::new();
+
+    let traders = [(FIRST_TRADER, COUNTRY_FRANCE), (SECOND_TRADER, COUNTRY_USA)];
+    for (trader, _) in traders {
+        setup
+            .world
+            .account(trader)
+            .nonce(1)
+            .esdt_balance(OTHER_TOKEN_ID, 100);
+    }
+    setup
+        .world
+        .account(PAIR_MOCK_ADDRESS)
+        .nonce(1)
+        .code(CODE_PATH_PAIR_MOCK)
+        .owner(OWNER)
+        .esdt_balance(TOKEN_ID, 1_000);
+    setup
+        .world
+        .tx()
+        .from(OWNER)
+        .to(PAIR_MOCK_ADDRESS)
+        .whitebox(pair_mock::contract_obj, |sc| {
+            sc.init(
+                OTHER_TOKEN_ID.to_token_identifier(),
+                TOKEN_ID.to_token_identifier(),
+            );
+        });
+
+    setup
+        .world
+        .tx()
+        .from(OWNER)
+        .to(ERC3643_ADDRESS)
+        .whitebox(erc3643::contract_obj, |sc| {
+            let mut endpoint_names = MultiValueEncoded::new();
+            endpoint_names.push("swapTokensFixedInput".into());
+            sc.add_exchange_endpoint(PAIR_MOCK_ADDRESS.to_managed_address(), endpoint_names);
+
+            let mut users = MultiValueEncoded::new();
+            for (trader, country) in traders {
+                users.push(trader.to_managed_address());
+                sc.register_identity(trader.to_managed_address(), country);
+            }
+            sc.add_users(users);
+            sc.add_denied_countries(MultiValueEncoded::from_iter([COUNTRY_USA]));
+        });
+    setup
+        .world
+        .tx()
+        .from(ISSUER)
+        .to(ERC3643_ADDRESS)
+        .whitebox(erc3643::contract_obj, |sc| {
+            for (trader, _) in traders {
+                sc.add_claim(trader.to_managed_address(), KYC_TOPIC, "kyc".into(), 0);
+            }
+        });
+    setup.enable_module(ComplianceModuleType::CountryDenyList);
+
+    // The tokens bought on the exchange are subject to the compliance modules
+    setup
+        .world
+        .tx()
+        .from(SECOND_TRADER)
+        .to(ERC3643_ADDRESS)
+        .esdt(TestEsdtTransfer(OTHER_TOKEN_ID, 0, 10))
+        .returns(ExpectMessage("Receiver country is denied"))
+        .whitebox(erc3643::contract_obj, |sc| {
+            sc.forward_execute_on_dest(
+                PAIR_MOCK_ADDRESS.to_managed_address(),
+                "swapTokensFixedInput".into(),
+                swap_args(),
+            );
+        });
+
+    setup
+        .world
+        .tx()
+        .from(FIRST_TRADER)
+        .to(ERC3643_ADDRESS)
+        .esdt(TestEsdtTransfer(OTHER_TOKEN_ID, 0, 10))
+        .whitebox(erc3643::contract_obj, |sc| {
+            sc.forward_execute_on_dest(
+                PAIR_MOCK_ADDRESS.to_managed_address(),
+                "swapTokensFixedInput".into(),
+                swap_args(),
+            );
+        });
+    setup.check_balance(FIRST_TRADER, 20);
+}
+
+#[test]
+fn erc3643_hook_priorities_test() {
+    let mut setup = Erc3643Setup::new();
+
+    setup
+        .world
+        .account(FIRST_HOOK_ADDRESS)
+        .code(CODE_PATH)
+        .owner(OWNER);
+    setup
+        .world
+        .account(SECOND_HOOK_ADDRESS)
+        .code(CODE_PATH)
+        .owner(OWNER);
+
+    setup
+        .world
+        .tx()
+        .from(OWNER)
+        .to(ERC3643_ADDRESS)
+        .whitebox(erc3643::contract_obj, |sc| {
+            sc.add_hook(
+                ErcHookType::BeforeTransfer,
+                FIRST_HOOK_ADDRESS.to_managed_address(),
+                "first".into(),
+                OptionalValue::Some(5),
+            );
+            sc.add_hook(
+                ErcHookType::BeforeTransfer,
+                SECOND_HOOK_ADDRESS.to_managed_address(),
+                "second".into(),
+                OptionalValue::None,
+            );
+            sc.add_hook(
+                ErcHookType::BeforeTransfer,
+                FIRST_HOOK_ADDRESS.to_managed_address(),
+                "third".into(),
+                OptionalValue::Some(5),
+            );
+            sc.add_hook(
+                ErcHookType::AfterTransfer,
+                SECOND_HOOK_ADDRESS.to_managed_address(),
+                "afterTransfer".into(),
+                OptionalValue::None,
+            );
+
+            let endpoint_names = sc
+                .hooks(ErcHookType::BeforeTransfer)
+                .get()
+                .iter()
+                .map(|hook| hook.endpoint_name.clone())
+                .collect::<Vec<_>>();
+            assert_eq!(
+                endpoint_names,
+                vec![
+                    ManagedBuffer::from("second"),
+                    ManagedBuffer::from("first"),
+                    ManagedBuffer::from("third"),
+                ]
+            );
+
+            sc.remove_hook(
+                ErcHookType::BeforeTransfer,
+                FIRST_HOOK_ADDRESS.to_managed_address(),
+                "first".into(),
+            );
+            let hooks = sc.hooks(ErcHookType::BeforeTransfer).get();
+            assert_eq!(hooks.len(), 2);
+            assert_eq!(hooks.get(1).endpoint_name, ManagedBuffer::from("third"));
+            assert_eq!(sc.hooks(ErcHookType::AfterTransfer).get().len(), 1);
+            assert!(sc
+                .hook_priority(
+                    ErcHookType::BeforeTransfer,
+                    &FIRST_HOOK_ADDRESS.to_managed_address(),
+                    &"first".into(),
+                )
+                .is_empty());
+            assert_eq!(
+                sc.hook_priority(
+                    ErcHookType::BeforeTransfer,
+                    &FIRST_HOOK_ADDRESS.to_managed_address(),
+                    &"third".into(),
+                )
+                .get(),
+                5
+            );
+        });
+
+    setup
+        .world
+        .tx()
+        .from(OWNER)
+        .to(ERC3643_ADDRESS)
+        .returns(ExpectMessage("Hook already added"))
+        .whitebox(erc3643::contract_obj, |sc| {
+            sc.add_hook(
+                ErcHookType::BeforeTransfer,
+                SECOND_HOOK_ADDRESS.to_managed_address(),
+                "second".into(),
+                OptionalValue::Some(1),
+            );
+        });
+}
+
+#[test]
+fn erc3643_after_transfer_hook_test() {
+    let mut setup = Erc3643Setup::new();
+
+    setup.deploy_hook_mock(10);
+    setup.add_hook(ErcHookType::BeforeTransfer, "takeFee");
+    setup.add_hook(ErcHookType::AfterTransfer, "afterTransfer");
+
+    setup.mint(FIRST_INVESTOR, 100);
+    setup.transfer(FIRST_INVESTOR, SECOND_INVESTOR, 50);
+
+    // the AfterTransfer hook receives the amounts left after the BeforeTransfer hook took its fee
+    setup.check_ledger_balance(FIRST_INVESTOR, 50);
+    setup.check_balance(SECOND_INVESTOR, 45);
+    setup
+        .world
+        .query()
+        .to(HOOK_MOCK_ADDRESS)
+        .whitebox(hook_mock::contract_obj, |sc| {
+            assert_eq!(
+                sc.received_amount(
+                    &FIRST_INVESTOR.to_managed_address(),
+                    &SECOND_INVESTOR.to_managed_address(),
+                )
+                .get(),
+                managed_biguint!(45)
+            );
+        });
+}
+
+#[test]
+fn erc3643_initialize_hooks_test() {
+    let mut setup = Erc3643Setup::new();
+
+    setup.deploy_hook_mock(0);
+    setup.add_hook(ErcHookType::BeforeInitialize, "beforeInitialize");
+    setup.add_hook(ErcHookType::AfterInitialize, "afterInitialize");
+
+    setup
+        .world
+        .tx()
+        .from(OWNER)
+        .to(ERC3643_ADDRESS)
+        .egld(ISSUE_COST)
+        .whitebox(erc3643::contract_obj, |sc| {
+            sc.token_state().clear();
+            sc.register_token("RealWorldAsset".into(), "RWA".into(), 18);
+        });
+    setup
+        .world
+        .query()
+        .to(HOOK_MOCK_ADDRESS)
+        .whitebox(hook_mock::contract_obj, |sc| {
+            assert_eq!(sc.token_ticker().get(), ManagedBuffer::from("RWA"));
+            assert!(sc.token_id().is_empty());
+        });
+
+    // the issue is completed in the callback
+    setup
+        .world
+        .tx()
+        .from(OWNER)
+        .to(ERC3643_ADDRESS)
+        .whitebox(erc3643::contract_obj, |sc| {
+            sc.register_token_callback(ManagedAsyncCallResult::Ok(TOKEN_ID.to_token_identifier()));
+
+            assert_eq!(sc.token().get_token_id(), TOKEN_ID.to_token_identifier());
+        });
+    setup
+        .world
+        .query()
+        .to(HOOK_MOCK_ADDRESS)
+        .whitebox(hook_mock::contract_obj, |sc| {
+            assert_eq!(sc.token_id().get(), TOKEN_ID.to_token_identifier());
+        });
+}
+
+#[test]
+fn erc3643_register_token_failed_test() {
+    let mut setup = Erc3643Setup::new();
+
+    setup.deploy_hook_mock(0);
+    setup.add_hook(ErcHookType::AfterInitialize, "afterInitialize");
+
+    // the issue cost is returned to the callback when the issue fails
+    setup
+        .world
+        .tx()
+        .from(OWNER)
+        .to(ERC3643_ADDRESS)
+        .egld(ISSUE_COST)
+        .whitebox(erc3643::contract_obj, |sc| {
+            sc.token_state().set(ManagedBuffer::from("pending"));
+            sc.register_token_callback(ManagedAsyncCallResult::Err(ManagedAsyncCallError {
+                err_code: 4,
+                err_msg: "issue failed".into(),
+            }));
+
+            assert!(sc.token().is_empty());
+        });
+
+    setup.world.check_account(OWNER).balance(ISSUE_COST);
+    setup.world.check_account(ERC3643_ADDRESS).balance(0);
+    setup
+        .world
+        .query()
+        .to(HOOK_MOCK_ADDRESS)
+        .whitebox(hook_mock::contract_obj, |sc| {
+            assert!(sc.token_id().is_empty());
+        });
+}
+
+fn swap_args<M: ManagedTypeApi>() -> MultiValueEncoded<M, ManagedBuffer<M>> {
+    let mut args = MultiValueEncoded::new();
+    args.push(TOKEN_ID.to_token_identifier().into_managed_buffer());
+    args.push(ManagedBuffer::new_from_bytes(&[1]));
+
+    args
+}
@@ -0,0 +1,318 @@
+use erc3643::{identity_registry::IdentityRegistryModule, Erc3643};
+use multiversx_sc::imports::MultiValueEncoded;
+use multiversx_sc::types::{TestAddress, TestSCAddress};
+use multiversx_sc_scenario::{imports::MxscPath, ExpectMessage, ScenarioTxWhitebox, ScenarioWorld};
+
+const OWNER: TestAddress = TestAddress::new("owner");
+const ISSUER: TestAddress = TestAddress::new("issuer");
+const FIRST_INVESTOR: TestAddress = TestAddress::new("first-investor");
+const UNVERIFIED_USER: TestAddress = TestAddress::new("unverified-user");
+const ERC3643_ADDRESS: TestSCAddress = TestSCAddress::new("erc3643");
+const CODE_PATH: MxscPath = MxscPath::new("output/erc3643.mxsc.json");
+const KYC_TOPIC: u64 = 1;
+const ACCREDITATION_TOPIC: u64 = 2;
+const COUNTRY_FRANCE: u16 = 250;
+const COUNTRY_USA: u16 = 840;
+const SECONDS_PER_DAY: u64 = 86_400;
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+
+    blockchain.set_current_dir_from_workspace("contracts/erc3643");
+    blockchain.register_contract(CODE_PATH, erc3643::ContractBuilder);
+    blockchain
+}
+
+struct Erc3643Setup {
+    world: ScenarioWorld,
+}
+
+impl Erc3643Setup {
+    fn new() -> Self {
+        let mut world = world();
+
+        world.account(OWNER).nonce(1);
+        world.account(ISSUER).nonce(1);
+        world.account(FIRST_INVESTOR).nonce(1);
+        world.account(UNVERIFIED_USER).nonce(1);
+        world
+            .account(ERC3643_ADDRESS)
+            .nonce(1)
+            .code(CODE_PATH)
+            .owner(OWNER);
+
+        world
+            .tx()
+            .from(OWNER)
+            .to(ERC3643_ADDRESS)
+            .whitebox(erc3643::contract_obj, |sc| {
+                sc.init();
+
+                let mut topics = MultiValueEncoded::new();
+                topics.push(KYC_TOPIC);
+                sc.add_trusted_issuer(ISSUER.to_managed_address(), topics);
+                sc.add_claim_topic(KYC_TOPIC);
+
+                sc.register_identity(FIRST_INVESTOR.to_managed_address(), COUNTRY_FRANCE);
+            });
+
+        Self { world }
+    }
+
+    fn add_claim(&mut self, investor: TestAddress, topic: u64, expiry: u64) {
+        self.world
+            .tx()
+            .from(ISSUER)
+            .to(ERC3643_ADDRESS)
+            .whitebox(erc3643::contract_obj, |sc| {
+                sc.add_claim(investor.to_managed_address(), topic, "kyc".into(), expiry);
+            });
+    }
+
+    fn add_claim_expect_error(
+        &mut self,
+        investor: TestAddress,
+        topic: u64,
+        expiry: u64,
+        err_message: &str,
+    ) {
+        self.world
+            .tx()
+            .from(ISSUER)
+            .to(ERC3643_ADDRESS)
+            .returns(ExpectMessage(err_message))
+            .whitebox(erc3643::contract_obj, |sc| {
+                sc.add_claim(investor.to_managed_address(), topic, "kyc".into(), expiry);
+            });
+    }
+
+    fn check_verified(&mut self, investor: TestAddress, expected: bool) {
+        self.world
+            .query()
+            .to(ERC3643_ADDRESS)
+            .whitebox(erc3643::contract_obj, |sc| {
+                assert_eq!(sc.is_verified(investor.to_managed_address()), expected);
+            });
+    }
+}
+
+#[test]
+fn identity_registration_test() {
+    let mut setup = Erc3643Setup::new();
+
+    setup
+        .world
+        .tx()
+        .from(OWNER)
+        .to(ERC3643_ADDRESS)
+        .returns(ExpectMessage("Identity already registered"))
+        .whitebox(erc3643::contract_obj, |sc| {
+            sc.register_identity(FIRST_INVESTOR.to_managed_address(), COUNTRY_USA);
+        });
+    setup
+        .world
+        .tx()
+        .from(OWNER)
+        .to(ERC3643_ADDRESS)
+        .returns(ExpectMessage("Identity not registered"))
+        .whitebox(erc3643::contract_obj, |sc| {
+            sc.update_country(UNVERIFIED_USER.to_managed_address(), COUNTRY_USA);
+        });
+
+    setup
+        .world
+        .tx()
+        .from(OWNER)
+        .to(ERC3643_ADDRESS)
+        .whitebox(erc3643::contract_obj, |sc| {
+            sc.update_country(FIRST_INVESTOR.to_managed_address(), COUNTRY_USA);
+        });
+    setup
+        .world
+        .query()
+        .to(ERC3643_ADDRESS)
+        .whitebox(erc3643::contract_obj, |sc| {
+            let identity = sc.identity(&FIRST_INVESTOR.to_managed_address()).get();
+            assert_eq!(identity.country, COUNTRY_USA);
+        });
+
+    // Claims can only be added for registered identities, and are deleted with them
+    setup.add_claim_expect_error(UNVERIFIED_USER, KYC_TOPIC, 0, "Identity not registered");
+    setup.add_claim(FIRST_INVESTOR, KYC_TOPIC, 0);
+    setup
+        .world
+        .tx()
+        .from(OWNER)
+        .to(ERC3643_ADDRESS)
+        .whitebox(erc3643::contract_obj, |sc| {
+            sc.delete_identity(FIRST_INVESTOR.to_managed_address());
+            assert!(sc.identity(&FIRST_INVESTOR.to_managed_address()).is_empty());
+            assert!(sc
+                .claim(&FIRST_INVESTOR.to_managed_address(), KYC_TOPIC)
+                .is_empty());
+            assert_eq!(sc.get_claims(FIRST_INVESTOR.to_managed_address()).len(), 0);
+        });
+    setup.check_verified(FIRST_INVESTOR, false);
+}
+
+#[test]
+fn claim_topics_test() {
+    let mut setup = Erc3643Setup::new();
+
+    setup.check_verified(FIRST_INVESTOR, false);
+    setup.add_claim(FIRST_INVESTOR, KYC_TOPIC, 0);
+    setup.check_verified(FIRST_INVESTOR, true);
+
+    // A new required topic needs a claim of its own
+    setup
+        .world
+        .tx()
+        .from(OWNER)
+        .to(ERC3643_ADDRESS)
+        .whitebox(erc3643::contract_obj, |sc| {
+            sc.add_claim_topic(ACCREDITATION_TOPIC);
+
+            let mut topics = MultiValueEncoded::new();
+            topics.push(ACCREDITATION_TOPIC);
+            sc.add_trusted_issuer(ISSUER.to_managed_address(), topics);
+        });
+    setup
+        .world
+        .tx()
+        .from(OWNER)
+        .to(ERC3643_ADDRESS)
+        .returns(ExpectMessage("Claim topic already required"))
+        .whitebox(erc3643::contract_obj, |sc| {
+            sc.add_claim_topic(ACCREDITATION_TOPIC);
+        });
+    setup.check_verified(FIRST_INVESTOR, false);
+
+    // Expired claims do not count
+    setup.world.current_block().block_timestamp(SECONDS_PER_DAY);
+    setup.add_claim_expect_error(
+        FIRST_INVESTOR,
+        ACCREDITATION_TOPIC,
+        SECONDS_PER_DAY,
+        "Claim already expired",
+    );
+    setup.add_claim(FIRST_INVESTOR, ACCREDITATION_TOPIC, 2 * SECONDS_PER_DAY);
+    setup.check_verified(FIRST_INVESTOR, true);
+    setup
+        .world
+        .current_block()
+        .block_timestamp(2 * SECONDS_PER_DAY);
+    setup.check_verified(FIRST_INVESTOR, false);
+
+    // Once the topic is no longer required, its claim does not matter
+    setup
+        .world
+        .tx()
+        .from(OWNER)
+        .to(ERC3643_ADDRESS)
+        .whitebox(erc3643::contract_obj, |sc| {
+            sc.remove_claim_topic(ACCREDITATION_TOPIC);
+        });
+    setup.check_verified(FIRST_INVESTOR, true);
+
+    // Revoked claims do not count
+    setup
+        .world
+        .tx()
+        .from(FIRST_INVESTOR)
+        .to(ERC3643_ADDRESS)
+        .returns(ExpectMessage(
+            "Only the issuer or the owner can revoke a claim",
+        ))
+        .whitebox(erc3643::contract_obj, |sc| {
+            sc.revoke_claim(FIRST_INVESTOR.to_managed_address(), KYC_TOPIC);
+        });
+    setup
+        .world
+        .tx()
+        .from(ISSUER)
+        .to(ERC3643_ADDRESS)
+        .whitebox(erc3643::contract_obj, |sc| {
+            sc.revoke_claim(FIRST_INVESTOR.to_managed_address(), KYC_TOPIC);
+        });
+    setup.check_verified(FIRST_INVESTOR, false);
+}
+
+#[test]
+fn trusted_issuers_test() {
+    let mut setup = Erc3643Setup::new();
+
+    // Issuers can only add claims for the topics they are trusted for
+    setup.add_claim_expect_error(
+        FIRST_INVESTOR,
+        ACCREDITATION_TOPIC,
+        0,
+        "Issuer is not trusted for this claim topic",
+    );
+    setup
+        .world
+        .tx()
+        .from(UNVERIFIED_USER)
+        .to(ERC3643_ADDRESS)
+        .returns(ExpectMessage("Issuer is not trusted for this claim topic"))
+        .whitebox(erc3643::contract_obj, |sc| {
+            sc.add_claim(
+                UNVERIFIED_USER.to_managed_address(),
+                KYC_TOPIC,
+                "kyc".into(),
+                0,
+            );
+        });
+
+    setup.add_claim(FIRST_INVESTOR, KYC_TOPIC, 0);
+    setup.check_verified(FIRST_INVESTOR, true);
+
+    // Removing the issuer invalidates its claims
+    setup
+        .world
+        .tx()
+        .from(OWNER)
+        .to(ERC3643_ADDRESS)
+        .whitebox(erc3643::contract_obj, |sc| {
+            sc.remove_trusted_issuer(ISSUER.to_managed_address());
+            assert_eq!(sc.trusted_issuers().len(), 0);
+        });
+    setup.check_verified(FIRST_INVESTOR, false);
+    setup.add_claim_expect_error(
+        FIRST_INVESTOR,
+        KYC_TOPIC,
+        0,
+        "Issuer is not trusted for this claim topic",
+    );
+    setup
+        .world
+        .tx()
+        .from(OWNER)
+        .to(ERC3643_ADDRESS)
+        .returns(ExpectMessage("Issuer is not trusted"))
+        .whitebox(erc3643::contract_obj, |sc| {
+            sc.remove_trusted_issuer(ISSUER.to_managed_address());
+        });
+}
+
+#[test]
+fn verified_identity_enforcement_test() {
+    let mut setup = Erc3643Setup::new();
+    setup.add_claim(FIRST_INVESTOR, KYC_TOPIC, 0);
+
+    setup
+        .world
+        .query()
+        .to(ERC3643_ADDRESS)
+        .whitebox(erc3643::contract_obj, |sc| {
+            sc.require_verified(&FIRST_INVESTOR.to_managed_address());
+        });
+    setup
+        .world
+        .tx()
+        .from(OWNER)
+        .to(ERC3643_ADDRESS)
+        .returns(ExpectMessage("Investor identity is not verified"))
+        .whitebox(erc3643::contract_obj, |sc| {
+            sc.require_verified(&UNVERIFIED_USER.to_managed_address());
+        });
+}
@@ -6,9 +6,9 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                           11
+// Endpoints:                           67
 // Async Callback:                       1
-// Total number of exported functions:  14
+// Total number of exported functions:  70
 
 #![no_std]
 
@@ -26,6 +26,62 @@ multiversx_sc_wasm_adapter::endpoints! {
         registerToken => register_token
         setTransferRole => set_transfer_role
         getTokenId => token
+        registerIdentity => register_identity
+        updateCountry => update_country
+        deleteIdentity => delete_identity
+        addTrustedIssuer => add_trusted_issuer
+        removeTrustedIssuer => remove_trusted_issuer
+        addClaimTopic => add_claim_topic
+        removeClaimTopic => remove_claim_topic
+        addClaim => add_claim
+        revokeClaim => revoke_claim
+        isVerified => is_verified
+        getClaims => get_claims
+        getIdentity => identity
+        getTrustedIssuers => trusted_issuers
+        getTrustedIssuerTopics => trusted_issuer_topics
+        getRequiredClaimTopics => required_claim_topics
+        enableComplianceModule => enable_compliance_module
+        disableComplianceModule => disable_compliance_module
+        setMaxHolders => set_max_holders
+        setMaxBalance => set_max_balance
+        addAllowedCountries => add_allowed_countries
+        removeAllowedCountries => remove_allowed_countries
+        addDeniedCountries => add_denied_countries
+        removeDeniedCountries => remove_denied_countries
+        setVolumeLimits => set_volume_limits
+        setLockUpPeriod => set_lock_up_period
+        getLockedBalance => get_locked_balance
+        getTransferVolume => get_transfer_volume
+        getEnabledComplianceModules => enabled_compliance_modules
+        getBalance => balance
+        getHolderCount => holder_count
+        getMaxHolders => max_holders
+        getMaxBalance => max_balance
+        getAllowedCountries => allowed_countries
+        getDeniedCountries => denied_countries
+        getDailyVolumeLimit => daily_volume_limit
+        getMonthlyVolumeLimit => monthly_volume_limit
+        getLockUpPeriod => lock_up_period
+        addAgent => add_agent
+        removeAgent => remove_agent
+        mintRecoveryReserve => mint_recovery_reserve
+        setAddressFrozen => set_address_frozen
+        freezePartialTokens => freeze_partial_tokens
+        unfreezePartialTokens => unfreeze_partial_tokens
+        forcedTransfer => forced_transfer
+        recoveryAddress => recovery_address
+        getRecoveryReserve => get_recovery_reserve
+        getAgents => agents
+        isFrozen => address_frozen
+        getFrozenTokens => frozen_tokens
+        forwardTransfer => forward_transfer
+        mint => mint
+        addExchangeEndpoint => add_exchange_endpoint
+        removeExchangeEndpoint => remove_exchange_endpoint
+        forwardExecuteOnDest => forward_execute_on_dest
+        getHooks => hooks
+        getHookPriority => hook_priority
         addHook => add_hook
         removeHook => remove_hook
         pause => pause_endpoint
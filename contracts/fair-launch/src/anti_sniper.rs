@@ -0,0 +1,88 @@
+use multiversx_sc::derive_imports::*;
+use multiversx_sc::imports::*;
+
+/// The protection window lasts `protection_duration` seconds from the start of the launch.
+/// If `whitelist_only` is set, only whitelisted users can buy during the window.
+#[type_abi]
+#[derive(TopEncode, TopDecode)]
+pub struct AntiSniperConfig {
+    pub protection_duration: u64,
+    pub whitelist_only: bool,
+}
+
+#[multiversx_sc::module]
+pub trait AntiSniperModule: crate::common::CommonModule {
+    /// During the protection window, smart contracts cannot buy
+    /// and each address can buy at most once per block
+    #[only_owner]
+    #[endpoint(setAntiSniperConfig)]
+    fn set_anti_sniper_config(&self, protection_duration: u64, whitelist_only: bool) {
+        self.anti_sniper_config().set(AntiSniperConfig {
+            protection_duration,
+            whitelist_only,
+        });
+    }
+
+    /// Purchases of blacklisted users are refunded
+    #[only_owner]
+    #[endpoint(addUsersToBlacklist)]
+    fn add_users_to_blacklist(&self, users: MultiValueEncoded<ManagedAddress>) {
+        let blacklist = self.user_blacklist();
+        for user in users {
+            blacklist.add(&user);
+        }
+    }
+
+    #[only_owner]
+    #[endpoint(removeUsersFromBlacklist)]
+    fn remove_users_from_blacklist(&self, users: MultiValueEncoded<ManagedAddress>) {
+        let blacklist = self.user_blacklist();
+        for user in users {
+            blacklist.remove(&user);
+        }
+    }
+
+    fn is_blacklisted(&self, user: &ManagedAddress) -> bool {
+        self.user_blacklist().contains(user)
+    }
+
+    fn check_anti_sniper_protection(&self, caller: &ManagedAddress, launch_start: u64) {
+        let config_mapper = self.anti_sniper_config();
+        if config_mapper.is_empty() {
+            return;
+        }
+
+        let config = config_mapper.get();
+        let current_timestamp = self.blockchain().get_block_timestamp();
+        if current_timestamp >= launch_start + config.protection_duration {
+            return;
+        }
+
+        require!(
+            !self.blockchain().is_smart_contract(caller),
+            "Smart contracts cannot buy during the protection window"
+        );
+        require!(
+            !config.whitelist_only || self.user_whitelist().contains(caller),
+            "Only whitelisted users can buy during the protection window"
+        );
+
+        let current_block = self.blockchain().get_block_nonce();
+        let last_buy_block_mapper = self.last_buy_block(caller);
+        require!(
+            last_buy_block_mapper.is_empty() || last_buy_block_mapper.get() < current_block,
+            "Only one buy per block is allowed during the protection window"
+        );
+        last_buy_block_mapper.set(current_block);
+    }
+
+    #[view(getAntiSniperConfig)]
+    #[storage_mapper("antiSniperConfig")]
+    fn anti_sniper_config(&self) -> SingleValueMapper<AntiSniperConfig>;
+
+    #[storage_mapper("userBlacklist")]
+    fn user_blacklist(&self) -> WhitelistMapper<Self::Api, ManagedAddress>;
+
+    #[storage_mapper("lastBuyBlock")]
+    fn last_buy_block(&self, user_addr: &ManagedAddress) -> SingleValueMapper<u64>;
+}
@@ -87,18 +87,6 @@ pub trait CommonModule {
         (payment_amount * fees_percentage + MAX_FEE_PERCENTAGE - 1u32) / MAX_FEE_PERCENTAGE
     }
 
-    fn burn_all_tokens(&self, tokens: &PaymentsVec<Self::Api>) {
-        for token in tokens {
-            if token.amount > 0 {
-                self.send().esdt_local_burn(
-                    &token.token_identifier,
-                    token.token_nonce,
-                    &token.amount,
-                );
-            }
-        }
-    }
-
     #[view(getTokenFees)]
     #[storage_mapper("tokenFees")]
     fn token_fees(&self, token_id: &TokenIdentifier) -> SingleValueMapper<Percentage>;
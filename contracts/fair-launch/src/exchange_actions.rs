@@ -16,16 +16,18 @@ pub struct EndpointInfo<M: ManagedTypeApi> {
 #[multiversx_sc::module]
 pub trait ExchangeActionsModule:
     crate::common::CommonModule
+    + crate::fee_distribution::FeeDistributionModule
     + crate::initial_launch::InitialLaunchModule
+    + crate::anti_sniper::AntiSniperModule
     + crate::token_info::TokenInfoModule
     + multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
     + multiversx_sc_modules::pause::PauseModule
 {
     /// Arguments: endpoint_name,
     /// input_fee_percentage: between 0 and 10_000,
-    /// burn_input: bool, burn input tokens taken as fee,
+    /// burn_input: bool, burn input tokens taken as fee, or split them as set by setFeeSplit,
     /// output_fee_percentage: between 0 and 10_000,
-    /// burn_output: bool, burn output taken as fee
+    /// burn_output: bool, burn output taken as fee, or split it as set by setFeeSplit
     #[only_owner]
     #[endpoint(addExchangeEndpoint)]
     fn add_exchange_endpoint(
@@ -127,7 +129,7 @@ pub trait ExchangeActionsModule:
                 self.take_fees(caller.clone(), payments.clone(), input_fees_percentage);
 
             if endpoint_info.burn_input {
-                self.burn_all_tokens(&take_fees_result.fees);
+                self.distribute_fees(&take_fees_result.fees);
             }
 
             self.tx()
@@ -156,7 +158,7 @@ pub trait ExchangeActionsModule:
                 self.take_fees(caller, back_transfers.esdt_payments, output_fees_percentage);
 
             if endpoint_info.burn_output {
-                self.burn_all_tokens(&take_fees_from_results.fees);
+                self.distribute_fees(&take_fees_from_results.fees);
             }
 
             self.tx()
@@ -43,36 +43,31 @@ where
     From: TxFrom<Env>,
     Gas: TxGas<Env>,
 {
+    /// Launch duration and buy cooldown are in seconds 
+    /// Fee schedule: (seconds since launch start, buy fee, sell fee) points, sorted by time 
     /// Percentages have to be between 0 and 10_000 
-    /// Start percentage >= End percentage 
     pub fn init<
         Arg0: ProxyArg<u64>,
         Arg1: ProxyArg<BigUint<Env::Api>>,
         Arg2: ProxyArg<BigUint<Env::Api>>,
-        Arg3: ProxyArg<u32>,
-        Arg4: ProxyArg<u32>,
-        Arg5: ProxyArg<u32>,
-        Arg6: ProxyArg<u32>,
+        Arg3: ProxyArg<u64>,
+        Arg4: ProxyArg<MultiValueEncoded<Env::Api, MultiValue3<u64, u32, u32>>>,
     >(
         self,
-        initial_launch_duration_blocks: Arg0,
+        initial_launch_duration: Arg0,
         account_buy_limit: Arg1,
         tx_buy_limit: Arg2,
-        buy_fee_percentage_start: Arg3,
-        buy_fee_percentage_end: Arg4,
-        sell_fee_percentage_start: Arg5,
-        sell_fee_percentage_end: Arg6,
+        buy_cooldown: Arg3,
+        fee_schedule: Arg4,
     ) -> TxTypedDeploy<Env, From, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
             .raw_deploy()
-            .argument(&initial_launch_duration_blocks)
+            .argument(&initial_launch_duration)
             .argument(&account_buy_limit)
             .argument(&tx_buy_limit)
-            .argument(&buy_fee_percentage_start)
-            .argument(&buy_fee_percentage_end)
-            .argument(&sell_fee_percentage_start)
-            .argument(&sell_fee_percentage_end)
+            .argument(&buy_cooldown)
+            .argument(&fee_schedule)
             .original_result()
     }
 }
@@ -86,6 +81,8 @@ where
     To: TxTo<Env>,
     Gas: TxGas<Env>,
 {
+    /// Contracts deployed with the launch window in block nonces cannot be upgraded, 
+    /// as the blocks cannot be converted to timestamps reliably. They have to be deployed again. 
     pub fn upgrade(
         self,
     ) -> TxTypedUpgrade<Env, From, To, NotPayable, Gas, ()> {
@@ -105,6 +102,60 @@ where
     To: TxTo<Env>,
     Gas: TxGas<Env>,
 {
+    /// During the protection window, smart contracts cannot buy 
+    /// and each address can buy at most once per block 
+    pub fn set_anti_sniper_config<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<bool>,
+    >(
+        self,
+        protection_duration: Arg0,
+        whitelist_only: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setAntiSniperConfig")
+            .argument(&protection_duration)
+            .argument(&whitelist_only)
+            .original_result()
+    }
+
+    /// Purchases of blacklisted users are refunded 
+    pub fn add_users_to_blacklist<
+        Arg0: ProxyArg<MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>>,
+    >(
+        self,
+        users: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("addUsersToBlacklist")
+            .argument(&users)
+            .original_result()
+    }
+
+    pub fn remove_users_from_blacklist<
+        Arg0: ProxyArg<MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>>,
+    >(
+        self,
+        users: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("removeUsersFromBlacklist")
+            .argument(&users)
+            .original_result()
+    }
+
+    pub fn anti_sniper_config(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, AntiSniperConfig> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAntiSniperConfig")
+            .original_result()
+    }
+
     pub fn token_fees<
         Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
     >(
@@ -120,9 +171,9 @@ where
 
     /// Arguments: endpoint_name, 
     /// input_fee_percentage: between 0 and 10_000, 
-    /// burn_input: bool, burn input tokens taken as fee, 
+    /// burn_input: bool, burn input tokens taken as fee, or split them as set by setFeeSplit, 
     /// output_fee_percentage: between 0 and 10_000, 
-    /// burn_output: bool, burn output taken as fee 
+    /// burn_output: bool, burn output taken as fee, or split it as set by setFeeSplit 
     pub fn add_exchange_endpoint<
         Arg0: ProxyArg<ManagedAddress<Env::Api>>,
         Arg1: ProxyArg<MultiValueEncoded<Env::Api, MultiValue5<ManagedBuffer<Env::Api>, u32, bool, u32, bool>>>,
@@ -174,6 +225,115 @@ where
             .original_result()
     }
 
+    pub fn set_treasury_address<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        treasury_address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setTreasuryAddress")
+            .argument(&treasury_address)
+            .original_result()
+    }
+
+    /// The fees are added as liquidity to this pair, which must be on the same shard 
+    /// and hold all the tokens with a liquidity fee split 
+    pub fn set_liquidity_pair<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<u32>,
+    >(
+        self,
+        pair_address: Arg0,
+        max_slippage: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setLiquidityPair")
+            .argument(&pair_address)
+            .argument(&max_slippage)
+            .original_result()
+    }
+
+    /// Fees of tokens without a split are burned 
+    pub fn set_fee_split<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<u32>,
+        Arg2: ProxyArg<u32>,
+    >(
+        self,
+        token_id: Arg0,
+        treasury_percentage: Arg1,
+        liquidity_percentage: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setFeeSplit")
+            .argument(&token_id)
+            .argument(&treasury_percentage)
+            .argument(&liquidity_percentage)
+            .original_result()
+    }
+
+    pub fn remove_fee_split<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("removeFeeSplit")
+            .argument(&token_id)
+            .original_result()
+    }
+
+    pub fn treasury_address(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTreasuryAddress")
+            .original_result()
+    }
+
+    pub fn liquidity_pair(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, LiquidityPair<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getLiquidityPair")
+            .original_result()
+    }
+
+    pub fn fee_split<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, FeeSplit> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getFeeSplit")
+            .argument(&token_id)
+            .original_result()
+    }
+
+    /// Total fees of the token that were burned, sent to the treasury and added as liquidity 
+    pub fn get_distributed_fees<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, DistributedFees<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getDistributedFees")
+            .argument(&token_id)
+            .original_result()
+    }
+
     pub fn buy_token<
         Arg0: ProxyArg<ManagedAddress<Env::Api>>,
         Arg1: ProxyArg<BigUint<Env::Api>>,
@@ -207,6 +367,15 @@ where
             .original_result()
     }
 
+    pub fn fee_schedule(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedVec<Env::Api, FeeBreakpoint>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getFeeSchedule")
+            .original_result()
+    }
+
     pub fn issue_token<
         Arg0: ProxyArg<EsdtTokenType>,
         Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
@@ -326,3 +495,56 @@ where
             .original_result()
     }
 }
+
+/// The protection window lasts `protection_duration` seconds from the start of the launch.
+/// If `whitelist_only` is set, only whitelisted users can buy during the window.
+#[type_abi]
+#[derive(TopEncode, TopDecode)]
+pub struct AntiSniperConfig {
+    pub protection_duration: u64,
+    pub whitelist_only: bool,
+}
+
+/// The amounts swapped and added as liquidity may be at most `max_slippage` below the pair price,
+/// between 0 and 10_000
+#[type_abi]
+#[derive(TopEncode, TopDecode)]
+pub struct LiquidityPair<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub address: ManagedAddress<Api>,
+    pub first_token_id: TokenIdentifier<Api>,
+    pub second_token_id: TokenIdentifier<Api>,
+    pub max_slippage: u32,
+}
+
+/// Parts of the fees, between 0 and 10_000, that are sent to the treasury and added as liquidity.
+/// The rest is burned. The LP tokens received for the added liquidity go to the treasury.
+#[type_abi]
+#[derive(TopEncode, TopDecode)]
+pub struct FeeSplit {
+    pub treasury_percentage: u32,
+    pub liquidity_percentage: u32,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode)]
+pub struct DistributedFees<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub burned: BigUint<Api>,
+    pub sent_to_treasury: BigUint<Api>,
+    pub added_to_liquidity: BigUint<Api>,
+}
+
+/// Point of the fee curve, `time_offset` seconds after the start of the launch.
+/// The fees are interpolated linearly between consecutive points.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone)]
+pub struct FeeBreakpoint {
+    pub time_offset: u64,
+    pub buy_fee_percentage: u32,
+    pub sell_fee_percentage: u32,
+}
@@ -0,0 +1,321 @@
+use crate::common::{PaymentsVec, Percentage, MAX_FEE_PERCENTAGE};
+use crate::pair_mock_proxy;
+
+use multiversx_sc::derive_imports::*;
+use multiversx_sc::imports::*;
+
+/// Parts of the fees, between 0 and 10_000, that are sent to the treasury and added as liquidity.
+/// The rest is burned. The LP tokens received for the added liquidity go to the treasury.
+#[type_abi]
+#[derive(TopEncode, TopDecode)]
+pub struct FeeSplit {
+    pub treasury_percentage: Percentage,
+    pub liquidity_percentage: Percentage,
+}
+
+/// The amounts swapped and added as liquidity may be at most `max_slippage` below the pair price,
+/// between 0 and 10_000
+#[type_abi]
+#[derive(TopEncode, TopDecode)]
+pub struct LiquidityPair<M: ManagedTypeApi> {
+    pub address: ManagedAddress<M>,
+    pub first_token_id: TokenIdentifier<M>,
+    pub second_token_id: TokenIdentifier<M>,
+    pub max_slippage: Percentage,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq)]
+pub enum FeeDestination {
+    Burn,
+    Treasury,
+    Liquidity,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode)]
+pub struct DistributedFees<M: ManagedTypeApi> {
+    pub burned: BigUint<M>,
+    pub sent_to_treasury: BigUint<M>,
+    pub added_to_liquidity: BigUint<M>,
+}
+
+#[multiversx_sc::module]
+pub trait FeeDistributionModule: crate::common::CommonModule {
+    #[only_owner]
+    #[endpoint(setTreasuryAddress)]
+    fn set_treasury_address(&self, treasury_address: ManagedAddress) {
+        self.treasury_address().set(treasury_address);
+    }
+
+    /// The fees are added as liquidity to this pair, which must be on the same shard
+    /// and hold all the tokens with a liquidity fee split
+    #[only_owner]
+    #[endpoint(setLiquidityPair)]
+    fn set_liquidity_pair(&self, pair_address: ManagedAddress, max_slippage: Percentage) {
+        require!(
+            self.blockchain().is_smart_contract(&pair_address),
+            "Invalid pair address"
+        );
+        require!(max_slippage < MAX_FEE_PERCENTAGE, "Invalid slippage");
+
+        let own_address = self.blockchain().get_sc_address();
+        require!(
+            self.blockchain().get_shard_of_address(&pair_address)
+                == self.blockchain().get_shard_of_address(&own_address),
+            "The pair must be on the same shard"
+        );
+
+        let first_token_id = self
+            .tx()
+            .to(&pair_address)
+            .typed(pair_mock_proxy::PairMockProxy)
+            .first_token_id()
+            .returns(ReturnsResult)
+            .sync_call_readonly();
+        let second_token_id = self
+            .tx()
+            .to(&pair_address)
+            .typed(pair_mock_proxy::PairMockProxy)
+            .second_token_id()
+            .returns(ReturnsResult)
+            .sync_call_readonly();
+
+        for token_id in self.liquidity_fee_tokens().iter() {
+            require!(
+                token_id == first_token_id || token_id == second_token_id,
+                "Fee split token not in the liquidity pair"
+            );
+        }
+
+        self.liquidity_pair().set(LiquidityPair {
+            address: pair_address,
+            first_token_id,
+            second_token_id,
+            max_slippage,
+        });
+    }
+
+    /// Fees of tokens without a split are burned
+    #[only_owner]
+    #[endpoint(setFeeSplit)]
+    fn set_fee_split(
+        &self,
+        token_id: TokenIdentifier,
+        treasury_percentage: Percentage,
+        liquidity_percentage: Percentage,
+    ) {
+        require!(
+            treasury_percentage + liquidity_percentage <= MAX_FEE_PERCENTAGE,
+            "Invalid fee split"
+        );
+        require!(
+            treasury_percentage + liquidity_percentage == 0 || !self.treasury_address().is_empty(),
+            "Treasury address not set"
+        );
+        if liquidity_percentage > 0 {
+            let liquidity_pair_mapper = self.liquidity_pair();
+            require!(!liquidity_pair_mapper.is_empty(), "Liquidity pair not set");
+
+            let liquidity_pair = liquidity_pair_mapper.get();
+            require!(
+                token_id == liquidity_pair.first_token_id
+                    || token_id == liquidity_pair.second_token_id,
+                "Token not in the liquidity pair"
+            );
+            self.liquidity_fee_tokens().insert(token_id.clone());
+        } else {
+            self.liquidity_fee_tokens().swap_remove(&token_id);
+        }
+
+        self.fee_split(&token_id).set(FeeSplit {
+            treasury_percentage,
+            liquidity_percentage,
+        });
+    }
+
+    #[only_owner]
+    #[endpoint(removeFeeSplit)]
+    fn remove_fee_split(&self, token_id: TokenIdentifier) {
+        self.fee_split(&token_id).clear();
+        self.liquidity_fee_tokens().swap_remove(&token_id);
+    }
+
+    fn distribute_fees(&self, fees: &PaymentsVec<Self::Api>) {
+        for fee in fees {
+            if fee.amount == 0 {
+                continue;
+            }
+
+            let fee_split_mapper = self.fee_split(&fee.token_identifier);
+            if fee_split_mapper.is_empty() {
+                self.burn_tokens(&fee);
+                continue;
+            }
+
+            let fee_split = fee_split_mapper.get();
+            let treasury_amount = &fee.amount * fee_split.treasury_percentage / MAX_FEE_PERCENTAGE;
+            let mut liquidity_amount =
+                &fee.amount * fee_split.liquidity_percentage / MAX_FEE_PERCENTAGE;
+            // too small to be split between the two tokens of the pair
+            if liquidity_amount < 2u32 {
+                liquidity_amount = BigUint::zero();
+            }
+            let burn_amount = &fee.amount - &treasury_amount - &liquidity_amount;
+
+            if treasury_amount > 0 {
+                self.tx()
+                    .to(self.treasury_address().get())
+                    .single_esdt(&fee.token_identifier, fee.token_nonce, &treasury_amount)
+                    .transfer();
+                self.distributed_fees(&fee.token_identifier, FeeDestination::Treasury)
+                    .update(|total| *total += &treasury_amount);
+            }
+
+            if liquidity_amount > 0 {
+                self.add_fees_as_liquidity(EsdtTokenPayment::new(
+                    fee.token_identifier.clone(),
+                    fee.token_nonce,
+                    liquidity_amount.clone(),
+                ));
+                self.distributed_fees(&fee.token_identifier, FeeDestination::Liquidity)
+                    .update(|total| *total += &liquidity_amount);
+            }
+
+            if burn_amount > 0 {
+                self.burn_tokens(&EsdtTokenPayment::new(
+                    fee.token_identifier.clone(),
+                    fee.token_nonce,
+                    burn_amount,
+                ));
+            }
+        }
+    }
+
+    /// Half of the tokens is swapped for the other token of the pair and both halves are added as liquidity.
+    /// The LP tokens and the unused amounts are sent to the treasury.
+    fn add_fees_as_liquidity(&self, tokens: EsdtTokenPayment) {
+        let liquidity_pair = self.liquidity_pair().get();
+        let is_first_token = tokens.token_identifier == liquidity_pair.first_token_id;
+        let other_token_id = if is_first_token {
+            liquidity_pair.second_token_id
+        } else {
+            liquidity_pair.first_token_id
+        };
+
+        let swap_amount = &tokens.amount / 2u32;
+        let swap_amount_equivalent = self
+            .tx()
+            .to(&liquidity_pair.address)
+            .typed(pair_mock_proxy::PairMockProxy)
+            .get_equivalent(&tokens.token_identifier, &swap_amount)
+            .returns(ReturnsResult)
+            .sync_call_readonly();
+        let swap_amount_out_min =
+            self.apply_max_slippage(&swap_amount_equivalent, liquidity_pair.max_slippage);
+        let swapped_tokens = self
+            .tx()
+            .to(&liquidity_pair.address)
+            .typed(pair_mock_proxy::PairMockProxy)
+            .swap_tokens_fixed_input(other_token_id, swap_amount_out_min)
+            .single_esdt(&tokens.token_identifier, tokens.token_nonce, &swap_amount)
+            .returns(ReturnsBackTransfers)
+            .sync_call()
+            .esdt_payments
+            .get(0)
+            .clone();
+        let kept_tokens = EsdtTokenPayment::new(
+            tokens.token_identifier,
+            tokens.token_nonce,
+            &tokens.amount - &swap_amount,
+        );
+
+        let mut liquidity_payments = PaymentsVec::new();
+        if is_first_token {
+            liquidity_payments.push(kept_tokens);
+            liquidity_payments.push(swapped_tokens);
+        } else {
+            liquidity_payments.push(swapped_tokens);
+            liquidity_payments.push(kept_tokens);
+        }
+
+        let first_amount_min = self.apply_max_slippage(
+            &liquidity_payments.get(0).amount,
+            liquidity_pair.max_slippage,
+        );
+        let second_amount_min = self.apply_max_slippage(
+            &liquidity_payments.get(1).amount,
+            liquidity_pair.max_slippage,
+        );
+        let received_tokens = self
+            .tx()
+            .to(&liquidity_pair.address)
+            .typed(pair_mock_proxy::PairMockProxy)
+            .add_liquidity(first_amount_min, second_amount_min)
+            .payment(liquidity_payments)
+            .returns(ReturnsBackTransfers)
+            .sync_call()
+            .esdt_payments;
+        self.tx()
+            .to(self.treasury_address().get())
+            .payment(received_tokens)
+            .transfer();
+    }
+
+    fn apply_max_slippage(&self, amount: &BigUint, max_slippage: Percentage) -> BigUint {
+        amount * (MAX_FEE_PERCENTAGE - max_slippage) / MAX_FEE_PERCENTAGE
+    }
+
+    /// Tokens the contract cannot burn are kept
+    fn burn_tokens(&self, tokens: &EsdtTokenPayment) {
+        let token_roles = self
+            .blockchain()
+            .get_esdt_local_roles(&tokens.token_identifier);
+        if token_roles.has_role(&EsdtLocalRole::Burn) {
+            self.send().esdt_local_burn(
+                &tokens.token_identifier,
+                tokens.token_nonce,
+                &tokens.amount,
+            );
+            self.distributed_fees(&tokens.token_identifier, FeeDestination::Burn)
+                .update(|total| *total += &tokens.amount);
+        }
+    }
+
+    #[view(getTreasuryAddress)]
+    #[storage_mapper("treasuryAddress")]
+    fn treasury_address(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[view(getLiquidityPair)]
+    #[storage_mapper("liquidityPair")]
+    fn liquidity_pair(&self) -> SingleValueMapper<LiquidityPair<Self::Api>>;
+
+    #[view(getFeeSplit)]
+    #[storage_mapper("feeSplit")]
+    fn fee_split(&self, token_id: &TokenIdentifier) -> SingleValueMapper<FeeSplit>;
+
+    /// Tokens with a fee split that adds liquidity, which must be in the liquidity pair
+    #[storage_mapper("liquidityFeeTokens")]
+    fn liquidity_fee_tokens(&self) -> UnorderedSetMapper<TokenIdentifier>;
+
+    /// Total fees of the token that were burned, sent to the treasury and added as liquidity
+    #[view(getDistributedFees)]
+    fn get_distributed_fees(&self, token_id: TokenIdentifier) -> DistributedFees<Self::Api> {
+        DistributedFees {
+            burned: self.distributed_fees(&token_id, FeeDestination::Burn).get(),
+            sent_to_treasury: self
+                .distributed_fees(&token_id, FeeDestination::Treasury)
+                .get(),
+            added_to_liquidity: self
+                .distributed_fees(&token_id, FeeDestination::Liquidity)
+                .get(),
+        }
+    }
+
+    #[storage_mapper("distributedFees")]
+    fn distributed_fees(
+        &self,
+        token_id: &TokenIdentifier,
+        destination: FeeDestination,
+    ) -> SingleValueMapper<BigUint>;
+}
@@ -1,12 +1,15 @@
 use crate::pair_mock_proxy;
-use crate::{common::Percentage, exchange_actions::EndpointInfo};
+use crate::{
+    common::{Percentage, MAX_FEE_PERCENTAGE},
+    exchange_actions::EndpointInfo,
+};
 
 use multiversx_sc::derive_imports::*;
 use multiversx_sc::imports::*;
 
 #[type_abi]
 #[derive(TopEncode, TopDecode)]
-pub struct InitialLaunchBlocks {
+pub struct InitialLaunchTimestamps {
     pub start: u64,
     pub end: u64,
 }
@@ -16,15 +19,25 @@ pub struct InitialLaunchBlocks {
 pub struct InitialLaunchInfo<M: ManagedTypeApi> {
     pub account_buy_limit: BigUint<M>,
     pub tx_buy_limit: BigUint<M>,
-    pub buy_fee_percentage_start: Percentage,
-    pub buy_fee_percentage_end: Percentage,
-    pub sell_fee_percentage_start: Percentage,
-    pub sell_fee_percentage_end: Percentage,
+    /// Minimum number of seconds between two buys of the same account
+    pub buy_cooldown: u64,
+}
+
+/// Point of the fee curve, `time_offset` seconds after the start of the launch.
+/// The fees are interpolated linearly between consecutive points.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone)]
+pub struct FeeBreakpoint {
+    pub time_offset: u64,
+    pub buy_fee_percentage: Percentage,
+    pub sell_fee_percentage: Percentage,
 }
 
 #[multiversx_sc::module]
 pub trait InitialLaunchModule:
-    crate::common::CommonModule
+    crate::anti_sniper::AntiSniperModule
+    + crate::common::CommonModule
+    + crate::fee_distribution::FeeDistributionModule
     + crate::token_info::TokenInfoModule
     + multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
     + multiversx_sc_modules::pause::PauseModule
@@ -45,11 +58,19 @@ pub trait InitialLaunchModule:
 
         let caller = self.blockchain().get_caller();
         let payment = self.call_value().single_esdt();
+        if self.is_blacklisted(&caller) {
+            self.tx().to(&caller).payment(payment.clone()).transfer();
+
+            return payment.clone();
+        }
+
+        let launch_start = self.initial_launch_timestamps().get().start;
+        self.check_anti_sniper_protection(&caller, launch_start);
+
         let launch_info = self.initial_launch_info().get();
-        let fee_percentage = self.get_fee_percentage(
-            launch_info.buy_fee_percentage_start,
-            launch_info.buy_fee_percentage_end,
-        );
+        self.require_buy_cooldown_passed(&caller, launch_info.buy_cooldown);
+
+        let (fee_percentage, _) = self.get_fee_percentages();
         let take_fee_result = self.take_fees(
             caller,
             ManagedVec::from_single_item(payment.clone()),
@@ -89,9 +110,10 @@ pub trait InitialLaunchModule:
                 );
             });
 
-        let fees = take_fee_result.fees.get(0);
-        self.burn_tokens(&fees);
+        self.distribute_fees(&take_fee_result.fees);
 
+        self.last_buy_timestamp(&take_fee_result.original_caller)
+            .set(self.blockchain().get_block_timestamp());
         self.tx()
             .to(&take_fee_result.original_caller)
             .payment(received_tokens.clone())
@@ -117,11 +139,7 @@ pub trait InitialLaunchModule:
 
         let caller = self.blockchain().get_caller();
         let payment = self.call_value().single_esdt();
-        let launch_info = self.initial_launch_info().get();
-        let fee_percentage = self.get_fee_percentage(
-            launch_info.sell_fee_percentage_start,
-            launch_info.sell_fee_percentage_end,
-        );
+        let (_, fee_percentage) = self.get_fee_percentages();
         let take_fee_result = self.take_fees(
             caller,
             ManagedVec::from_single_item(payment.clone()),
@@ -146,8 +164,7 @@ pub trait InitialLaunchModule:
             .sync_call();
         let received_tokens = all_transfers.esdt_payments.get(0);
 
-        let fees = take_fee_result.fees.get(0);
-        self.burn_tokens(&fees);
+        self.distribute_fees(&take_fee_result.fees);
 
         self.tx()
             .to(&take_fee_result.original_caller)
@@ -157,61 +174,119 @@ pub trait InitialLaunchModule:
         received_tokens.clone()
     }
 
-    fn get_fee_percentage(
+    /// Stores the fee curve, given as (time offset, buy fee, sell fee) points with increasing time offsets
+    fn set_fee_schedule(
         &self,
-        fee_percentage_start: Percentage,
-        fee_percentage_end: Percentage,
-    ) -> Percentage {
-        let initial_launch_blocks = self.initial_launch_blocks().get();
-        let current_block = self.blockchain().get_block_nonce();
+        launch_duration: u64,
+        fee_schedule: MultiValueEncoded<MultiValue3<u64, Percentage, Percentage>>,
+    ) {
+        require!(!fee_schedule.is_empty(), "Empty fee schedule");
+
+        let mut breakpoints = ManagedVec::<Self::Api, FeeBreakpoint>::new();
+        for breakpoint in fee_schedule {
+            let (time_offset, buy_fee_percentage, sell_fee_percentage) = breakpoint.into_tuple();
+            require!(
+                buy_fee_percentage <= MAX_FEE_PERCENTAGE
+                    && sell_fee_percentage <= MAX_FEE_PERCENTAGE,
+                "Invalid percentage"
+            );
+            require!(
+                time_offset <= launch_duration,
+                "Fee breakpoint after the end of the launch"
+            );
+            if let Some(previous) = breakpoints.iter().next_back() {
+                require!(
+                    time_offset > previous.time_offset,
+                    "Fee breakpoints must be sorted by time"
+                );
+            }
+
+            breakpoints.push(FeeBreakpoint {
+                time_offset,
+                buy_fee_percentage,
+                sell_fee_percentage,
+            });
+        }
+
+        self.fee_schedule().set(breakpoints);
+    }
+
+    /// Current buy and sell fees, interpolated between the surrounding points of the fee curve
+    fn get_fee_percentages(&self) -> (Percentage, Percentage) {
+        let launch_timestamps = self.initial_launch_timestamps().get();
+        let current_timestamp = self.blockchain().get_block_timestamp();
         require!(
-            current_block <= initial_launch_blocks.end,
-            "Invalid buy/sell block"
+            current_timestamp <= launch_timestamps.end,
+            "Invalid buy/sell timestamp"
         );
 
-        let blocks_passed_in_penalty_phase = current_block - initial_launch_blocks.start;
-        let blocks_diff = initial_launch_blocks.end - initial_launch_blocks.start;
-        let percentage_diff = fee_percentage_start - fee_percentage_end;
+        let elapsed = current_timestamp - launch_timestamps.start;
+        let fee_schedule = self.fee_schedule().get();
+        let mut previous = fee_schedule.get(0).clone();
+        if elapsed <= previous.time_offset {
+            return (previous.buy_fee_percentage, previous.sell_fee_percentage);
+        }
 
-        let penalty_percentage_decrease =
-            percentage_diff as u64 * blocks_passed_in_penalty_phase / (blocks_diff - 1);
+        for next in fee_schedule.iter().skip(1) {
+            if elapsed <= next.time_offset {
+                let offset_in_segment = elapsed - previous.time_offset;
+                let segment_length = next.time_offset - previous.time_offset;
+                let interpolate = |from: Percentage, to: Percentage| {
+                    let diff = to as i64 - from as i64;
+                    (from as i64 + diff * offset_in_segment as i64 / segment_length as i64)
+                        as Percentage
+                };
 
-        fee_percentage_start - penalty_percentage_decrease as u32
-    }
+                return (
+                    interpolate(previous.buy_fee_percentage, next.buy_fee_percentage),
+                    interpolate(previous.sell_fee_percentage, next.sell_fee_percentage),
+                );
+            }
 
-    fn burn_tokens(&self, tokens: &EsdtTokenPayment) {
-        let token_roles = self
-            .blockchain()
-            .get_esdt_local_roles(&tokens.token_identifier);
-        if token_roles.has_role(&EsdtLocalRole::Burn) {
-            self.send().esdt_local_burn(
-                &tokens.token_identifier,
-                tokens.token_nonce,
-                &tokens.amount,
-            );
+            previous = next.clone();
         }
+
+        (previous.buy_fee_percentage, previous.sell_fee_percentage)
+    }
+
+    /// The last buy timestamp is only recorded once a buy has gone through
+    fn require_buy_cooldown_passed(&self, caller: &ManagedAddress, buy_cooldown: u64) {
+        let current_timestamp = self.blockchain().get_block_timestamp();
+        let last_buy_mapper = self.last_buy_timestamp(caller);
+        require!(
+            last_buy_mapper.is_empty() || last_buy_mapper.get() + buy_cooldown <= current_timestamp,
+            "Buy cooldown not passed"
+        );
     }
 
     fn require_not_initial_launch(&self) {
-        let current_block = self.blockchain().get_block_nonce();
-        let initial_launch_blocks = self.initial_launch_blocks().get();
+        let current_timestamp = self.blockchain().get_block_timestamp();
+        let launch_timestamps = self.initial_launch_timestamps().get();
         require!(
-            current_block > initial_launch_blocks.end,
+            current_timestamp > launch_timestamps.end,
             "Cannot call this endpoint during initial launch"
         );
     }
 
     fn require_initial_launch(&self) {
-        let current_block = self.blockchain().get_block_nonce();
-        let initial_launch_blocks = self.initial_launch_blocks().get();
+        let current_timestamp = self.blockchain().get_block_timestamp();
+        let launch_timestamps = self.initial_launch_timestamps().get();
         require!(
-            current_block <= initial_launch_blocks.end,
+            current_timestamp <= launch_timestamps.end,
             "Cannot call this endpoint, initial launch period passed"
         );
     }
 
+    /// Launch window of the contracts deployed before it was measured in timestamps, only checked on upgrade
     #[storage_mapper("initialLaunchBlocks")]
-    fn initial_launch_blocks(&self) -> SingleValueMapper<InitialLaunchBlocks>;
+    fn legacy_initial_launch_blocks(&self) -> SingleValueMapper<ManagedBuffer>;
+
+    #[storage_mapper("initialLaunchTimestamps")]
+    fn initial_launch_timestamps(&self) -> SingleValueMapper<InitialLaunchTimestamps>;
+
+    #[view(getFeeSchedule)]
+    #[storage_mapper("feeSchedule")]
+    fn fee_schedule(&self) -> SingleValueMapper<ManagedVec<FeeBreakpoint>>;
 
     #[storage_mapper("initialLaunchInfo")]
     fn initial_launch_info(&self) -> SingleValueMapper<InitialLaunchInfo<Self::Api>>;
@@ -219,6 +294,9 @@ pub trait InitialLaunchModule:
     #[storage_mapper("totalBought")]
     fn total_bought(&self, user_addr: &ManagedAddress) -> SingleValueMapper<BigUint>;
 
+    #[storage_mapper("lastBuyTimestamp")]
+    fn last_buy_timestamp(&self, user_addr: &ManagedAddress) -> SingleValueMapper<u64>;
+
     #[storage_mapper("knownContracts")]
     fn known_contracts(
         &self,
@@ -1,15 +1,15 @@
 #![no_std]
 
 use common::Percentage;
-use initial_launch::InitialLaunchBlocks;
-
-use crate::{common::MAX_FEE_PERCENTAGE, initial_launch::InitialLaunchInfo};
+use initial_launch::{InitialLaunchInfo, InitialLaunchTimestamps};
 
 use multiversx_sc::imports::*;
 
+pub mod anti_sniper;
 pub mod common;
 pub mod exchange_actions;
 pub mod fair_launch_proxy;
+pub mod fee_distribution;
 pub mod initial_launch;
 pub mod pair_mock_proxy;
 pub mod token_info;
@@ -17,63 +17,58 @@ pub mod transfer;
 
 #[multiversx_sc::contract]
 pub trait FairLaunch:
-    common::CommonModule
+    anti_sniper::AntiSniperModule
+    + common::CommonModule
     + exchange_actions::ExchangeActionsModule
+    + fee_distribution::FeeDistributionModule
     + initial_launch::InitialLaunchModule
     + token_info::TokenInfoModule
     + transfer::TransferModule
     + multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
     + multiversx_sc_modules::pause::PauseModule
 {
+    /// Launch duration and buy cooldown are in seconds
+    /// Fee schedule: (seconds since launch start, buy fee, sell fee) points, sorted by time
     /// Percentages have to be between 0 and 10_000
-    /// Start percentage >= End percentage
     #[init]
     fn init(
         &self,
-        initial_launch_duration_blocks: u64,
+        initial_launch_duration: u64,
         account_buy_limit: BigUint,
         tx_buy_limit: BigUint,
-        buy_fee_percentage_start: Percentage,
-        buy_fee_percentage_end: Percentage,
-        sell_fee_percentage_start: Percentage,
-        sell_fee_percentage_end: Percentage,
+        buy_cooldown: u64,
+        fee_schedule: MultiValueEncoded<MultiValue3<u64, Percentage, Percentage>>,
     ) {
-        require!(
-            initial_launch_duration_blocks > 0,
-            "Invalid launch duration blocks"
-        );
+        require!(initial_launch_duration > 0, "Invalid launch duration");
         require!(tx_buy_limit <= account_buy_limit, "Invalid limits");
-        require!(
-            buy_fee_percentage_start >= buy_fee_percentage_end
-                && buy_fee_percentage_start <= MAX_FEE_PERCENTAGE,
-            "Invalid percentage"
-        );
-        require!(
-            sell_fee_percentage_start >= sell_fee_percentage_end
-                && sell_fee_percentage_start <= MAX_FEE_PERCENTAGE,
-            "Invalid percentage"
-        );
 
-        let start_block = self.blockchain().get_block_nonce();
-        let end_block = start_block + initial_launch_duration_blocks;
-        self.initial_launch_blocks().set(InitialLaunchBlocks {
-            start: start_block,
-            end: end_block,
-        });
+        self.set_fee_schedule(initial_launch_duration, fee_schedule);
+
+        let start_timestamp = self.blockchain().get_block_timestamp();
+        let end_timestamp = start_timestamp + initial_launch_duration;
+        self.initial_launch_timestamps()
+            .set(InitialLaunchTimestamps {
+                start: start_timestamp,
+                end: end_timestamp,
+            });
 
         let launch_info = InitialLaunchInfo {
             account_buy_limit,
             tx_buy_limit,
-            buy_fee_percentage_start,
-            buy_fee_percentage_end,
-            sell_fee_percentage_start,
-            sell_fee_percentage_end,
+            buy_cooldown,
         };
         self.initial_launch_info().set(launch_info);
 
         self.set_paused(true);
     }
 
+    /// Contracts deployed with the launch window in block nonces cannot be upgraded,
+    /// as the blocks cannot be converted to timestamps reliably. They have to be deployed again.
     #[upgrade]
-    fn upgrade(&self) {}
+    fn upgrade(&self) {
+        require!(
+            self.legacy_initial_launch_blocks().is_empty(),
+            "Launch window stored in blocks, deploy a new contract instead"
+        );
+    }
 }
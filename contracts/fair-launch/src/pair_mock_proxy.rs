@@ -75,12 +75,86 @@ where
     >(
         self,
         _token_out: Arg0,
-        _amount_out_min: Arg1,
+        amount_out_min: Arg1,
     ) -> TxTypedCall<Env, From, To, (), Gas, EsdtTokenPayment<Env::Api>> {
         self.wrapped_tx
             .raw_call("swapTokensFixedInput")
             .argument(&_token_out)
-            .argument(&_amount_out_min)
+            .argument(&amount_out_min)
+            .original_result()
+    }
+
+    pub fn set_lp_token_identifier<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token_identifier: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setLpTokenIdentifier")
+            .argument(&token_identifier)
+            .original_result()
+    }
+
+    /// Both tokens are required, in the pair order, and are added at the 1:2 swap price. 
+    /// Returns the LP tokens and the refunds of the unused amounts. 
+    pub fn add_liquidity<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        first_token_amount_min: Arg0,
+        second_token_amount_min: Arg1,
+    ) -> TxTypedCall<Env, From, To, (), Gas, MultiValue3<EsdtTokenPayment<Env::Api>, EsdtTokenPayment<Env::Api>, EsdtTokenPayment<Env::Api>>> {
+        self.wrapped_tx
+            .raw_call("addLiquidity")
+            .argument(&first_token_amount_min)
+            .argument(&second_token_amount_min)
+            .original_result()
+    }
+
+    /// Amount of the other token of the pair worth `amount_in`, at the 1:2 swap price 
+    pub fn get_equivalent<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        token_in: Arg0,
+        amount_in: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getEquivalent")
+            .argument(&token_in)
+            .argument(&amount_in)
+            .original_result()
+    }
+
+    pub fn first_token_id(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, TokenIdentifier<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getFirstTokenId")
+            .original_result()
+    }
+
+    pub fn second_token_id(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, TokenIdentifier<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getSecondTokenId")
+            .original_result()
+    }
+
+    pub fn lp_token_identifier(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, TokenIdentifier<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getLpTokenIdentifier")
             .original_result()
     }
 }
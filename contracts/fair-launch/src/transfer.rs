@@ -6,6 +6,8 @@ use multiversx_sc::imports::*;
 pub trait TransferModule:
     crate::exchange_actions::ExchangeActionsModule
     + crate::initial_launch::InitialLaunchModule
+    + crate::anti_sniper::AntiSniperModule
+    + crate::fee_distribution::FeeDistributionModule
     + crate::common::CommonModule
     + crate::token_info::TokenInfoModule
     + multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
@@ -1,5 +1,8 @@
 use crowdfunding_esdt::crowdfunding_esdt_proxy;
-use fair_launch::{common::CommonModule, fair_launch_proxy, initial_launch::InitialLaunchModule};
+use fair_launch::{
+    common::CommonModule, fair_launch_proxy, fee_distribution::FeeDistributionModule,
+    initial_launch::InitialLaunchModule,
+};
 use multiversx_sc::{
     imports::MultiValue5,
     types::{ManagedAddress, ManagedBuffer, MultiValueEncoded, TestEsdtTransfer},
@@ -8,10 +11,12 @@ use multiversx_sc_scenario::{
     api::StaticApi, managed_biguint, managed_buffer, ExpectMessage, ScenarioTxRun,
     ScenarioTxWhitebox,
 };
+use pair_mock::PairMock;
 use tests_common::{
-    FairLaunchSetup, BUY_FEE_PERCENTAGE_END, BUY_FEE_PERCENTAGE_START, CODE_PATH_CROWDFUNDING,
-    CROWDFUNDING_ADDRESS, FAIR_LAUNCH_ADDRESS, FIRST_ADDRESS, OTHER_TOKEN_ID, OWNER,
-    PAIR_MOCK_ADDRESS, SECOND_ADDRESS, TOKEN_ID,
+    FairLaunchSetup, BUY_COOLDOWN, BUY_FEE_PERCENTAGE_START, CODE_PATH_CROWDFUNDING,
+    CODE_PATH_FAIR_LAUNCH, CODE_PATH_PAIR_MOCK, CROWDFUNDING_ADDRESS, FAIR_LAUNCH_ADDRESS,
+    FIRST_ADDRESS, LP_TOKEN_ID, OTHER_PAIR_MOCK_ADDRESS, OTHER_TOKEN_ID, OWNER, PAIR_MOCK_ADDRESS,
+    SECOND_ADDRESS, SELL_FEE_PERCENTAGE_START, THIRD_ADDRESS, TOKEN_ID,
 };
 
 mod tests_common;
@@ -21,23 +26,121 @@ fn init_test() {
     let _ = FairLaunchSetup::new(None, 0);
 }
 
+#[test]
+fn upgrade_test() {
+    let mut fl_setup = FairLaunchSetup::new(None, 0);
+    fl_setup
+        .world
+        .tx()
+        .from(OWNER)
+        .to(FAIR_LAUNCH_ADDRESS)
+        .typed(fair_launch_proxy::FairLaunchProxy)
+        .upgrade()
+        .code(CODE_PATH_FAIR_LAUNCH)
+        .run();
+
+    // launch window of a contract deployed before the switch to timestamps, blocks 1 to 100
+    let legacy_launch_blocks = [1u64.to_be_bytes(), 100u64.to_be_bytes()].concat();
+    fl_setup
+        .world
+        .tx()
+        .from(OWNER)
+        .to(FAIR_LAUNCH_ADDRESS)
+        .whitebox(fair_launch::contract_obj, |sc| {
+            sc.legacy_initial_launch_blocks()
+                .set(ManagedBuffer::from(legacy_launch_blocks.as_slice()));
+        });
+    fl_setup
+        .world
+        .tx()
+        .from(OWNER)
+        .to(FAIR_LAUNCH_ADDRESS)
+        .typed(fair_launch_proxy::FairLaunchProxy)
+        .upgrade()
+        .code(CODE_PATH_FAIR_LAUNCH)
+        .with_result(ExpectMessage(
+            "Launch window stored in blocks, deploy a new contract instead",
+        ))
+        .run();
+}
+
 #[test]
 fn percentage_test() {
     let mut fl_setup = FairLaunchSetup::new(None, 0);
-    fl_setup.world.current_block().block_nonce(10);
+    fl_setup.world.current_block().block_timestamp(10);
     fl_setup
         .world
         .tx()
         .from(OWNER)
         .to(FAIR_LAUNCH_ADDRESS)
         .whitebox(fair_launch::contract_obj, |sc| {
-            let percentage =
-                sc.get_fee_percentage(BUY_FEE_PERCENTAGE_START, BUY_FEE_PERCENTAGE_END);
-            let expected_percentage = BUY_FEE_PERCENTAGE_START - 808; // (BUY_FEE_PERCENTAGE_END - BUY_FEE_PERCENTAGE_START) * 10 blocks / (100 blocks - 1) ~= 808
-            assert_eq!(percentage, expected_percentage);
+            let (buy_percentage, sell_percentage) = sc.get_fee_percentages();
+            // start - (start - end) * 10 seconds / 100 seconds
+            assert_eq!(buy_percentage, BUY_FEE_PERCENTAGE_START - 800);
+            assert_eq!(sell_percentage, SELL_FEE_PERCENTAGE_START - 500);
         })
 }
 
+#[test]
+fn multi_point_fee_schedule_test() {
+    let mut fl_setup = FairLaunchSetup::new(None, 0);
+    fl_setup
+        .world
+        .tx()
+        .from(OWNER)
+        .to(FAIR_LAUNCH_ADDRESS)
+        .whitebox(fair_launch::contract_obj, |sc| {
+            let mut fee_schedule = MultiValueEncoded::new();
+            fee_schedule.push((0u64, 9_000u32, 9_000u32).into());
+            fee_schedule.push((20u64, 5_000u32, 8_000u32).into());
+            fee_schedule.push((100u64, 1_000u32, 1_000u32).into());
+            sc.set_fee_schedule(100, fee_schedule);
+        });
+
+    fl_setup.world.current_block().block_timestamp(10);
+    fl_setup
+        .world
+        .tx()
+        .from(OWNER)
+        .to(FAIR_LAUNCH_ADDRESS)
+        .whitebox(fair_launch::contract_obj, |sc| {
+            assert_eq!(sc.get_fee_percentages(), (7_000, 8_500));
+        });
+
+    fl_setup.world.current_block().block_timestamp(60);
+    fl_setup
+        .world
+        .tx()
+        .from(OWNER)
+        .to(FAIR_LAUNCH_ADDRESS)
+        .whitebox(fair_launch::contract_obj, |sc| {
+            assert_eq!(sc.get_fee_percentages(), (3_000, 4_500));
+        });
+
+    fl_setup.world.current_block().block_timestamp(100);
+    fl_setup
+        .world
+        .tx()
+        .from(OWNER)
+        .to(FAIR_LAUNCH_ADDRESS)
+        .whitebox(fair_launch::contract_obj, |sc| {
+            assert_eq!(sc.get_fee_percentages(), (1_000, 1_000));
+        });
+
+    fl_setup
+        .world
+        .tx()
+        .from(OWNER)
+        .to(FAIR_LAUNCH_ADDRESS)
+        .returns(ExpectMessage("Fee breakpoints must be sorted by time"))
+        .whitebox(fair_launch::contract_obj, |sc| {
+            let mut fee_schedule = MultiValueEncoded::new();
+            fee_schedule.push((50u64, 9_000u32, 9_000u32).into());
+            fee_schedule.push((20u64, 5_000u32, 8_000u32).into());
+            sc.set_fee_schedule(100, fee_schedule);
+        });
+}
+
 #[test]
 fn calculate_fee_test() {
     let mut fl_setup = FairLaunchSetup::new(None, 0);
@@ -295,10 +398,546 @@ fn buy_token_test() {
         .esdt_balance(OTHER_TOKEN_ID, 100);
 }
 
+#[test]
+fn buy_cooldown_test() {
+    let mut fl_setup = FairLaunchSetup::new(Some(TOKEN_ID), 200);
+    fl_setup
+        .world
+        .account(FIRST_ADDRESS)
+        .esdt_balance(OTHER_TOKEN_ID, 3000);
+    fl_setup.world.current_block().block_timestamp(1);
+
+    // 89.2% of the initial value is kept as fee
+    fl_setup
+        .world
+        .tx()
+        .from(FIRST_ADDRESS)
+        .to(FAIR_LAUNCH_ADDRESS)
+        .typed(fair_launch_proxy::FairLaunchProxy)
+        .buy_token(PAIR_MOCK_ADDRESS, 1u32)
+        .esdt(TestEsdtTransfer(OTHER_TOKEN_ID, 0, 1000))
+        .run();
+
+    fl_setup
+        .world
+        .tx()
+        .from(FIRST_ADDRESS)
+        .to(FAIR_LAUNCH_ADDRESS)
+        .typed(fair_launch_proxy::FairLaunchProxy)
+        .buy_token(PAIR_MOCK_ADDRESS, 1u32)
+        .esdt(TestEsdtTransfer(OTHER_TOKEN_ID, 0, 1000))
+        .with_result(ExpectMessage("Buy cooldown not passed"))
+        .run();
+
+    // 85.2% of the initial value is kept as fee
+    fl_setup
+        .world
+        .current_block()
+        .block_timestamp(1 + BUY_COOLDOWN);
+    fl_setup
+        .world
+        .tx()
+        .from(FIRST_ADDRESS)
+        .to(FAIR_LAUNCH_ADDRESS)
+        .typed(fair_launch_proxy::FairLaunchProxy)
+        .buy_token(PAIR_MOCK_ADDRESS, 1u32)
+        .esdt(TestEsdtTransfer(OTHER_TOKEN_ID, 0, 1000))
+        .run();
+
+    fl_setup
+        .world
+        .check_account(FIRST_ADDRESS)
+        .esdt_balance(TOKEN_ID, 54 + 74);
+    fl_setup
+        .world
+        .check_account(PAIR_MOCK_ADDRESS)
+        .esdt_balance(OTHER_TOKEN_ID, 108 + 148);
+}
+
+#[test]
+fn anti_sniper_protection_test() {
+    let mut fl_setup = FairLaunchSetup::new(Some(TOKEN_ID), 300);
+    fl_setup
+        .world
+        .account(FIRST_ADDRESS)
+        .esdt_balance(OTHER_TOKEN_ID, 3000);
+    fl_setup
+        .world
+        .account(CROWDFUNDING_ADDRESS)
+        .code(CODE_PATH_CROWDFUNDING)
+        .owner(OWNER)
+        .esdt_balance(OTHER_TOKEN_ID, 1000);
+
+    fl_setup
+        .world
+        .tx()
+        .from(OWNER)
+        .to(FAIR_LAUNCH_ADDRESS)
+        .typed(fair_launch_proxy::FairLaunchProxy)
+        .set_anti_sniper_config(10u64, false)
+        .run();
+
+    fl_setup
+        .world
+        .current_block()
+        .block_nonce(1)
+        .block_timestamp(1);
+    fl_setup
+        .world
+        .tx()
+        .from(CROWDFUNDING_ADDRESS)
+        .to(FAIR_LAUNCH_ADDRESS)
+        .typed(fair_launch_proxy::FairLaunchProxy)
+        .buy_token(PAIR_MOCK_ADDRESS, 1u32)
+        .esdt(TestEsdtTransfer(OTHER_TOKEN_ID, 0, 1000))
+        .with_result(ExpectMessage(
+            "Smart contracts cannot buy during the protection window",
+        ))
+        .run();
+
+    // 89.2% of the initial value is kept as fee
+    fl_setup
+        .world
+        .tx()
+        .from(FIRST_ADDRESS)
+        .to(FAIR_LAUNCH_ADDRESS)
+        .typed(fair_launch_proxy::FairLaunchProxy)
+        .buy_token(PAIR_MOCK_ADDRESS, 1u32)
+        .esdt(TestEsdtTransfer(OTHER_TOKEN_ID, 0, 1000))
+        .run();
+
+    fl_setup
+        .world
+        .tx()
+        .from(FIRST_ADDRESS)
+        .to(FAIR_LAUNCH_ADDRESS)
+        .typed(fair_launch_proxy::FairLaunchProxy)
+        .buy_token(PAIR_MOCK_ADDRESS, 1u32)
+        .esdt(TestEsdtTransfer(OTHER_TOKEN_ID, 0, 1000))
+        .with_result(ExpectMessage(
+            "Only one buy per block is allowed during the protection window",
+        ))
+        .run();
+
+    // 85.2% of the initial value is kept as fee
+    fl_setup
+        .world
+        .current_block()
+        .block_nonce(2)
+        .block_timestamp(1 + BUY_COOLDOWN);
+    fl_setup
+        .world
+        .tx()
+        .from(FIRST_ADDRESS)
+        .to(FAIR_LAUNCH_ADDRESS)
+        .typed(fair_launch_proxy::FairLaunchProxy)
+        .buy_token(PAIR_MOCK_ADDRESS, 1u32)
+        .esdt(TestEsdtTransfer(OTHER_TOKEN_ID, 0, 1000))
+        .run();
+
+    fl_setup
+        .world
+        .check_account(FIRST_ADDRESS)
+        .esdt_balance(TOKEN_ID, 54 + 74);
+
+    // the protection window has passed, smart contracts can buy again
+    fl_setup
+        .world
+        .current_block()
+        .block_nonce(3)
+        .block_timestamp(10);
+    fl_setup
+        .world
+        .tx()
+        .from(CROWDFUNDING_ADDRESS)
+        .to(FAIR_LAUNCH_ADDRESS)
+        .typed(fair_launch_proxy::FairLaunchProxy)
+        .buy_token(PAIR_MOCK_ADDRESS, 1u32)
+        .esdt(TestEsdtTransfer(OTHER_TOKEN_ID, 0, 1000))
+        .run();
+}
+
+#[test]
+fn anti_sniper_whitelist_only_test() {
+    let mut fl_setup = FairLaunchSetup::new(Some(TOKEN_ID), 200);
+    fl_setup
+        .world
+        .account(FIRST_ADDRESS)
+        .esdt_balance(OTHER_TOKEN_ID, 1000);
+    fl_setup
+        .world
+        .account(THIRD_ADDRESS)
+        .esdt_balance(OTHER_TOKEN_ID, 1000);
+
+    fl_setup
+        .world
+        .tx()
+        .from(OWNER)
+        .to(FAIR_LAUNCH_ADDRESS)
+        .typed(fair_launch_proxy::FairLaunchProxy)
+        .set_anti_sniper_config(10u64, true)
+        .run();
+
+    let mut users = MultiValueEncoded::new();
+    users.push(ManagedAddress::from_address(&THIRD_ADDRESS.to_address()));
+    fl_setup
+        .world
+        .tx()
+        .from(OWNER)
+        .to(FAIR_LAUNCH_ADDRESS)
+        .typed(fair_launch_proxy::FairLaunchProxy)
+        .add_users_to_whitelist(users)
+        .run();
+
+    fl_setup
+        .world
+        .current_block()
+        .block_nonce(1)
+        .block_timestamp(1);
+    fl_setup
+        .world
+        .tx()
+        .from(FIRST_ADDRESS)
+        .to(FAIR_LAUNCH_ADDRESS)
+        .typed(fair_launch_proxy::FairLaunchProxy)
+        .buy_token(PAIR_MOCK_ADDRESS, 1u32)
+        .esdt(TestEsdtTransfer(OTHER_TOKEN_ID, 0, 1000))
+        .with_result(ExpectMessage(
+            "Only whitelisted users can buy during the protection window",
+        ))
+        .run();
+
+    // whitelisted users pay no fees
+    fl_setup
+        .world
+        .tx()
+        .from(THIRD_ADDRESS)
+        .to(FAIR_LAUNCH_ADDRESS)
+        .typed(fair_launch_proxy::FairLaunchProxy)
+        .buy_token(PAIR_MOCK_ADDRESS, 1u32)
+        .esdt(TestEsdtTransfer(OTHER_TOKEN_ID, 0, 200))
+        .run();
+
+    fl_setup
+        .world
+        .check_account(THIRD_ADDRESS)
+        .esdt_balance(TOKEN_ID, 100);
+}
+
+#[test]
+fn blacklisted_buy_refunded_test() {
+    let mut fl_setup = FairLaunchSetup::new(Some(TOKEN_ID), 50);
+    fl_setup
+        .world
+        .account(FIRST_ADDRESS)
+        .esdt_balance(OTHER_TOKEN_ID, 1000);
+
+    let mut users = MultiValueEncoded::new();
+    users.push(ManagedAddress::from_address(&FIRST_ADDRESS.to_address()));
+    fl_setup
+        .world
+        .tx()
+        .from(OWNER)
+        .to(FAIR_LAUNCH_ADDRESS)
+        .typed(fair_launch_proxy::FairLaunchProxy)
+        .add_users_to_blacklist(users)
+        .run();
+
+    fl_setup
+        .world
+        .tx()
+        .from(FIRST_ADDRESS)
+        .to(FAIR_LAUNCH_ADDRESS)
+        .typed(fair_launch_proxy::FairLaunchProxy)
+        .buy_token(PAIR_MOCK_ADDRESS, 1u32)
+        .esdt(TestEsdtTransfer(OTHER_TOKEN_ID, 0, 1000))
+        .run();
+
+    fl_setup
+        .world
+        .check_account(FIRST_ADDRESS)
+        .esdt_balance(OTHER_TOKEN_ID, 1000)
+        .esdt_balance(TOKEN_ID, 0);
+    fl_setup
+        .world
+        .check_account(PAIR_MOCK_ADDRESS)
+        .esdt_balance(TOKEN_ID, 50);
+
+    // a refunded buy does not start the buy cooldown
+    let mut users = MultiValueEncoded::new();
+    users.push(ManagedAddress::from_address(&FIRST_ADDRESS.to_address()));
+    fl_setup
+        .world
+        .tx()
+        .from(OWNER)
+        .to(FAIR_LAUNCH_ADDRESS)
+        .typed(fair_launch_proxy::FairLaunchProxy)
+        .remove_users_from_blacklist(users)
+        .run();
+
+    // 90% of the initial value is kept as fee
+    fl_setup
+        .world
+        .tx()
+        .from(FIRST_ADDRESS)
+        .to(FAIR_LAUNCH_ADDRESS)
+        .typed(fair_launch_proxy::FairLaunchProxy)
+        .buy_token(PAIR_MOCK_ADDRESS, 1u32)
+        .esdt(TestEsdtTransfer(OTHER_TOKEN_ID, 0, 1000))
+        .run();
+
+    fl_setup
+        .world
+        .check_account(FIRST_ADDRESS)
+        .esdt_balance(TOKEN_ID, 50);
+}
+
+#[test]
+fn fee_split_test() {
+    let mut fl_setup = FairLaunchSetup::new(Some(TOKEN_ID), 200);
+    fl_setup
+        .world
+        .account(FIRST_ADDRESS)
+        .esdt_balance(OTHER_TOKEN_ID, 1000);
+
+    fl_setup
+        .world
+        .tx()
+        .from(OWNER)
+        .to(FAIR_LAUNCH_ADDRESS)
+        .typed(fair_launch_proxy::FairLaunchProxy)
+        .set_fee_split(OTHER_TOKEN_ID, 5_000u32, 3_000u32)
+        .with_result(ExpectMessage("Treasury address not set"))
+        .run();
+
+    fl_setup
+        .world
+        .tx()
+        .from(OWNER)
+        .to(FAIR_LAUNCH_ADDRESS)
+        .typed(fair_launch_proxy::FairLaunchProxy)
+        .set_treasury_address(SECOND_ADDRESS)
+        .run();
+    fl_setup
+        .world
+        .tx()
+        .from(OWNER)
+        .to(FAIR_LAUNCH_ADDRESS)
+        .typed(fair_launch_proxy::FairLaunchProxy)
+        .set_liquidity_pair(PAIR_MOCK_ADDRESS, 0u32)
+        .run();
+
+    fl_setup
+        .world
+        .tx()
+        .from(OWNER)
+        .to(FAIR_LAUNCH_ADDRESS)
+        .typed(fair_launch_proxy::FairLaunchProxy)
+        .set_fee_split(LP_TOKEN_ID, 0u32, 3_000u32)
+        .with_result(ExpectMessage("Token not in the liquidity pair"))
+        .run();
+
+    fl_setup
+        .world
+        .tx()
+        .from(OWNER)
+        .to(FAIR_LAUNCH_ADDRESS)
+        .typed(fair_launch_proxy::FairLaunchProxy)
+        .set_fee_split(OTHER_TOKEN_ID, 6_000u32, 5_000u32)
+        .with_result(ExpectMessage("Invalid fee split"))
+        .run();
+
+    // 50% of the fees go to the treasury, 30% are added as liquidity and the rest is burned
+    fl_setup
+        .world
+        .tx()
+        .from(OWNER)
+        .to(FAIR_LAUNCH_ADDRESS)
+        .typed(fair_launch_proxy::FairLaunchProxy)
+        .set_fee_split(OTHER_TOKEN_ID, 5_000u32, 3_000u32)
+        .run();
+
+    // the pair refunds the unit lost when rounding to its price, which needs some slippage
+    fl_setup
+        .world
+        .tx()
+        .from(FIRST_ADDRESS)
+        .to(FAIR_LAUNCH_ADDRESS)
+        .typed(fair_launch_proxy::FairLaunchProxy)
+        .buy_token(PAIR_MOCK_ADDRESS, 1u32)
+        .esdt(TestEsdtTransfer(OTHER_TOKEN_ID, 0, 1000))
+        .with_result(ExpectMessage("Insufficient liquidity added"))
+        .run();
+
+    fl_setup
+        .world
+        .tx()
+        .from(OWNER)
+        .to(FAIR_LAUNCH_ADDRESS)
+        .typed(fair_launch_proxy::FairLaunchProxy)
+        .set_liquidity_pair(PAIR_MOCK_ADDRESS, 10_000u32)
+        .with_result(ExpectMessage("Invalid slippage"))
+        .run();
+    fl_setup
+        .world
+        .tx()
+        .from(OWNER)
+        .to(FAIR_LAUNCH_ADDRESS)
+        .typed(fair_launch_proxy::FairLaunchProxy)
+        .set_liquidity_pair(PAIR_MOCK_ADDRESS, 100u32)
+        .run();
+
+    // 90% of the initial value is kept as fee
+    fl_setup
+        .world
+        .tx()
+        .from(FIRST_ADDRESS)
+        .to(FAIR_LAUNCH_ADDRESS)
+        .typed(fair_launch_proxy::FairLaunchProxy)
+        .buy_token(PAIR_MOCK_ADDRESS, 1u32)
+        .esdt(TestEsdtTransfer(OTHER_TOKEN_ID, 0, 1000))
+        .run();
+
+    fl_setup
+        .world
+        .check_account(FIRST_ADDRESS)
+        .esdt_balance(TOKEN_ID, 50);
+
+    // half of the liquidity part is swapped, 67 MYTOKEN and 134 OTHER are added as liquidity,
+    // the treasury receives the LP tokens and the unused OTHER token
+    fl_setup
+        .world
+        .check_account(SECOND_ADDRESS)
+        .esdt_balance(OTHER_TOKEN_ID, 450 + 1)
+        .esdt_balance(LP_TOKEN_ID, 67);
+    fl_setup
+        .world
+        .check_account(PAIR_MOCK_ADDRESS)
+        .esdt_balance(OTHER_TOKEN_ID, 100 + 135 + 134)
+        .esdt_balance(TOKEN_ID, 200 - 50);
+
+    // the contract has no burn role, so the burned part is kept
+    fl_setup
+        .world
+        .check_account(FAIR_LAUNCH_ADDRESS)
+        .esdt_balance(OTHER_TOKEN_ID, 180);
+
+    fl_setup
+        .world
+        .tx()
+        .from(OWNER)
+        .to(FAIR_LAUNCH_ADDRESS)
+        .whitebox(fair_launch::contract_obj, |sc| {
+            let distributed_fees = sc.get_distributed_fees(OTHER_TOKEN_ID.to_token_identifier());
+            assert_eq!(distributed_fees.burned, managed_biguint!(0));
+            assert_eq!(distributed_fees.sent_to_treasury, managed_biguint!(450));
+            assert_eq!(distributed_fees.added_to_liquidity, managed_biguint!(270));
+        });
+}
+
+#[test]
+fn fee_split_liquidity_pair_change_test() {
+    let mut fl_setup = FairLaunchSetup::new(Some(TOKEN_ID), 200);
+    fl_setup
+        .world
+        .account(OTHER_PAIR_MOCK_ADDRESS)
+        .code(CODE_PATH_PAIR_MOCK)
+        .owner(OWNER);
+    fl_setup
+        .world
+        .tx()
+        .from(OWNER)
+        .to(OTHER_PAIR_MOCK_ADDRESS)
+        .whitebox(pair_mock::contract_obj, |sc| {
+            sc.init(
+                TOKEN_ID.to_token_identifier(),
+                LP_TOKEN_ID.to_token_identifier(),
+            );
+        });
+
+    fl_setup
+        .world
+        .tx()
+        .from(OWNER)
+        .to(FAIR_LAUNCH_ADDRESS)
+        .typed(fair_launch_proxy::FairLaunchProxy)
+        .set_treasury_address(SECOND_ADDRESS)
+        .run();
+    fl_setup
+        .world
+        .tx()
+        .from(OWNER)
+        .to(FAIR_LAUNCH_ADDRESS)
+        .typed(fair_launch_proxy::FairLaunchProxy)
+        .set_liquidity_pair(PAIR_MOCK_ADDRESS, 100u32)
+        .run();
+    fl_setup
+        .world
+        .tx()
+        .from(OWNER)
+        .to(FAIR_LAUNCH_ADDRESS)
+        .typed(fair_launch_proxy::FairLaunchProxy)
+        .set_fee_split(OTHER_TOKEN_ID, 5_000u32, 3_000u32)
+        .run();
+
+    // the fees of OTHER could no longer be added as liquidity
+    fl_setup
+        .world
+        .tx()
+        .from(OWNER)
+        .to(FAIR_LAUNCH_ADDRESS)
+        .typed(fair_launch_proxy::FairLaunchProxy)
+        .set_liquidity_pair(OTHER_PAIR_MOCK_ADDRESS, 100u32)
+        .with_result(ExpectMessage("Fee split token not in the liquidity pair"))
+        .run();
+
+    fl_setup
+        .world
+        .tx()
+        .from(OWNER)
+        .to(FAIR_LAUNCH_ADDRESS)
+        .typed(fair_launch_proxy::FairLaunchProxy)
+        .set_fee_split(OTHER_TOKEN_ID, 5_000u32, 0u32)
+        .run();
+    fl_setup
+        .world
+        .tx()
+        .from(OWNER)
+        .to(FAIR_LAUNCH_ADDRESS)
+        .typed(fair_launch_proxy::FairLaunchProxy)
+        .set_liquidity_pair(OTHER_PAIR_MOCK_ADDRESS, 100u32)
+        .run();
+
+    fl_setup
+        .world
+        .tx()
+        .from(OWNER)
+        .to(FAIR_LAUNCH_ADDRESS)
+        .typed(fair_launch_proxy::FairLaunchProxy)
+        .set_fee_split(TOKEN_ID, 0u32, 3_000u32)
+        .run();
+    fl_setup
+        .world
+        .tx()
+        .from(OWNER)
+        .to(FAIR_LAUNCH_ADDRESS)
+        .typed(fair_launch_proxy::FairLaunchProxy)
+        .remove_fee_split(TOKEN_ID)
+        .run();
+    fl_setup
+        .world
+        .tx()
+        .from(OWNER)
+        .to(FAIR_LAUNCH_ADDRESS)
+        .typed(fair_launch_proxy::FairLaunchProxy)
+        .set_liquidity_pair(PAIR_MOCK_ADDRESS, 100u32)
+        .run();
+}
+
 #[test]
 fn sell_token_test() {
     let mut fl_setup = FairLaunchSetup::new(Some(OTHER_TOKEN_ID), 5000);
-    fl_setup.world.current_block().block_nonce(50);
+    fl_setup.world.current_block().block_timestamp(50);
     fl_setup
         .world
         .account(FIRST_ADDRESS)
@@ -315,19 +954,18 @@ fn sell_token_test() {
         .esdt(TestEsdtTransfer(TOKEN_ID, 0, 1000))
         .run();
 
-    // values slightly differ due to biguint division errors
     fl_setup
         .world
         .check_account(FIRST_ADDRESS)
-        .esdt_balance(OTHER_TOKEN_ID, 504);
+        .esdt_balance(OTHER_TOKEN_ID, 500);
     fl_setup
         .world
         .check_account(FAIR_LAUNCH_ADDRESS)
-        .esdt_balance(TOKEN_ID, 748);
+        .esdt_balance(TOKEN_ID, 750);
     fl_setup
         .world
         .check_account(PAIR_MOCK_ADDRESS)
-        .esdt_balance(TOKEN_ID, 252);
+        .esdt_balance(TOKEN_ID, 250);
 }
 
 #[test]
@@ -338,7 +976,7 @@ fn try_buy_after_deadline() {
         .account(FIRST_ADDRESS)
         .esdt_balance(OTHER_TOKEN_ID, 1000);
 
-    fl_setup.world.current_block().block_nonce(120);
+    fl_setup.world.current_block().block_timestamp(120);
 
     // 90% of the initial value is kept as fee
     fl_setup
@@ -358,7 +996,7 @@ fn try_buy_after_deadline() {
 #[test]
 fn forward_swap_sync_test() {
     let mut fl_setup = FairLaunchSetup::new(Some(TOKEN_ID), 500_000);
-    fl_setup.world.current_block().block_nonce(101);
+    fl_setup.world.current_block().block_timestamp(101);
     fl_setup
         .world
         .account(FIRST_ADDRESS)
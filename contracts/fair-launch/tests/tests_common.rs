@@ -7,7 +7,10 @@ use pair_mock::PairMock;
 
 pub const TOKEN_ID: TestTokenIdentifier = TestTokenIdentifier::new("MYTOKEN-123456");
 pub const OTHER_TOKEN_ID: TestTokenIdentifier = TestTokenIdentifier::new("OTHER-123456");
-pub const LAUNCH_DURATION_BLOCKS: u64 = 100;
+pub const LP_TOKEN_ID: TestTokenIdentifier = TestTokenIdentifier::new("LPTOKEN-123456");
+pub const LP_TOKEN_SUPPLY: u64 = 1_000_000;
+pub const LAUNCH_DURATION: u64 = 100;
+pub const BUY_COOLDOWN: u64 = 5;
 pub const ACCOUNT_BUY_LIMIT: u64 = 2_000;
 pub const TX_BUY_LIMIT: u64 = 1_000;
 pub const BUY_FEE_PERCENTAGE_START: Percentage = 9_000;
@@ -21,7 +24,9 @@ pub const CODE_PATH_CROWDFUNDING: MxscPath =
 pub const OWNER: TestAddress = TestAddress::new("owner");
 pub const FIRST_ADDRESS: TestAddress = TestAddress::new("first-address");
 pub const SECOND_ADDRESS: TestAddress = TestAddress::new("second-address");
+pub const THIRD_ADDRESS: TestAddress = TestAddress::new("third-address");
 pub const PAIR_MOCK_ADDRESS: TestSCAddress = TestSCAddress::new("pair-mock");
+pub const OTHER_PAIR_MOCK_ADDRESS: TestSCAddress = TestSCAddress::new("other-pair-mock");
 pub const FAIR_LAUNCH_ADDRESS: TestSCAddress = TestSCAddress::new("fair-launch");
 pub const CROWDFUNDING_ADDRESS: TestSCAddress = TestSCAddress::new("crowdfunding-esdt");
 
@@ -52,11 +57,13 @@ impl FairLaunchSetup {
                 .account(PAIR_MOCK_ADDRESS)
                 .code(CODE_PATH_PAIR_MOCK)
                 .owner(OWNER)
+                .esdt_balance(LP_TOKEN_ID, LP_TOKEN_SUPPLY)
                 .esdt_balance(t, balance),
             None => world
                 .account(PAIR_MOCK_ADDRESS)
                 .code(CODE_PATH_PAIR_MOCK)
-                .owner(OWNER),
+                .owner(OWNER)
+                .esdt_balance(LP_TOKEN_ID, LP_TOKEN_SUPPLY),
         };
 
         world
@@ -68,20 +75,29 @@ impl FairLaunchSetup {
                     TOKEN_ID.to_token_identifier(),
                     OTHER_TOKEN_ID.to_token_identifier(),
                 );
+                sc.set_lp_token_identifier(LP_TOKEN_ID.to_token_identifier());
             });
 
+        let mut fee_schedule = MultiValueEncoded::new();
+        fee_schedule.push((0u64, BUY_FEE_PERCENTAGE_START, SELL_FEE_PERCENTAGE_START).into());
+        fee_schedule.push(
+            (
+                LAUNCH_DURATION,
+                BUY_FEE_PERCENTAGE_END,
+                SELL_FEE_PERCENTAGE_END,
+            )
+                .into(),
+        );
         world
             .tx()
             .from(OWNER)
             .typed(fair_launch_proxy::FairLaunchProxy)
             .init(
-                LAUNCH_DURATION_BLOCKS,
+                LAUNCH_DURATION,
                 ACCOUNT_BUY_LIMIT,
                 TX_BUY_LIMIT,
-                BUY_FEE_PERCENTAGE_START,
-                BUY_FEE_PERCENTAGE_END,
-                SELL_FEE_PERCENTAGE_START,
-                SELL_FEE_PERCENTAGE_END,
+                BUY_COOLDOWN,
+                fee_schedule,
             )
             .new_address(FAIR_LAUNCH_ADDRESS)
             .code(CODE_PATH_FAIR_LAUNCH)
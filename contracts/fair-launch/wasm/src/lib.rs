@@ -6,9 +6,9 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                           15
+// Endpoints:                           28
 // Async Callback:                       1
-// Total number of exported functions:  18
+// Total number of exported functions:  31
 
 #![no_std]
 
@@ -20,12 +20,25 @@ multiversx_sc_wasm_adapter::endpoints! {
     (
         init => init
         upgrade => upgrade
+        setAntiSniperConfig => set_anti_sniper_config
+        addUsersToBlacklist => add_users_to_blacklist
+        removeUsersFromBlacklist => remove_users_from_blacklist
+        getAntiSniperConfig => anti_sniper_config
         getTokenFees => token_fees
         addExchangeEndpoint => add_exchange_endpoint
         removeExchangeEndpoint => remove_exchange_endpoint
         forwardExecuteOnDest => forward_execute_on_dest
+        setTreasuryAddress => set_treasury_address
+        setLiquidityPair => set_liquidity_pair
+        setFeeSplit => set_fee_split
+        removeFeeSplit => remove_fee_split
+        getTreasuryAddress => treasury_address
+        getLiquidityPair => liquidity_pair
+        getFeeSplit => fee_split
+        getDistributedFees => get_distributed_fees
         buyToken => buy_token
         sellToken => sell_token
+        getFeeSchedule => fee_schedule
         issueToken => issue_token
         setTransferRole => set_transfer_role
         setTokenFees => set_token_fees
@@ -1,22 +1,38 @@
 use crate::{
-    raw_call::common::{FunctionName, GasLimit, PaymentsVec, RawArgs, RawCall},
+    high_level_calls::SyncCallOutput,
+    raw_call::common::{FunctionName, GasLimit, PaymentsVec, RawArgs, RawCall, RawResults},
     unique_payments::UniquePayments,
 };
 
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
+pub type Bps = u32;
+
+pub const MAX_BPS: Bps = 10_000;
+
+/// `PercentageOfBalance` and `AllOfToken` read from the running balance of the bundle,
+/// i.e. the caller's payments plus everything received from previous steps, minus what was already sent.
 #[type_abi]
-#[derive(TopEncode, TopDecode)]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode)]
 pub enum PaymentType<M: ManagedTypeApi> {
     None,
-    Egld { amount: BigUint<M> },
-    FixedPayments { esdt_payments: PaymentsVec<M> },
+    Egld {
+        amount: BigUint<M>,
+    },
+    FixedPayments {
+        esdt_payments: PaymentsVec<M>,
+    },
     ReceivedPaymentsFromSc,
+    PercentageOfBalance {
+        token: EgldOrEsdtTokenIdentifier<M>,
+        bps: Bps,
+    },
+    AllOfToken(EgldOrEsdtTokenIdentifier<M>),
 }
 
 #[type_abi]
-#[derive(TopEncode, TopDecode)]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq)]
 pub enum CallType {
     SimpleTransfer,
     Sync,
@@ -30,6 +46,45 @@ pub struct FunctionNameArgsPair<M: ManagedTypeApi> {
     pub args: RawArgs<M>,
 }
 
+/// Checks the previous step's result at `result_index` against `value`.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem)]
+pub struct ExpectedResult<M: ManagedTypeApi> {
+    pub result_index: usize,
+    pub value: ManagedBuffer<M>,
+}
+
+/// Replaces the argument at `arg_index` with the previous step's result at `result_index`.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem)]
+pub struct PipedResult {
+    pub result_index: usize,
+    pub arg_index: usize,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode)]
+pub struct StepOptions<M: ManagedTypeApi> {
+    /// The step is skipped unless the bundle holds at least these amounts.
+    pub min_balances: ManagedVec<M, EgldOrEsdtTokenPayment<M>>,
+    /// The step is skipped unless the previous step returned these values.
+    pub expected_results: ManagedVec<M, ExpectedResult<M>>,
+    pub piped_results: ManagedVec<M, PipedResult>,
+    /// The whole bundle is reverted unless the step sends back at least these amounts. Sync calls only.
+    pub min_outputs: ManagedVec<M, EgldOrEsdtTokenPayment<M>>,
+}
+
+impl<M: ManagedTypeApi> Default for StepOptions<M> {
+    fn default() -> Self {
+        Self {
+            min_balances: ManagedVec::new(),
+            expected_results: ManagedVec::new(),
+            piped_results: ManagedVec::new(),
+            min_outputs: ManagedVec::new(),
+        }
+    }
+}
+
 pub type SingleCallArg<M> = MultiValue5<
     ManagedAddress<M>,
     PaymentType<M>,
@@ -38,6 +93,15 @@ pub type SingleCallArg<M> = MultiValue5<
     Option<FunctionNameArgsPair<M>>,
 >;
 
+pub type SingleCallArgWithOptions<M> = MultiValue6<
+    ManagedAddress<M>,
+    PaymentType<M>,
+    CallType,
+    GasLimit,
+    Option<FunctionNameArgsPair<M>>,
+    StepOptions<M>,
+>;
+
 #[multiversx_sc::module]
 pub trait CallDispatcherModule:
     crate::raw_call::simple_transfer::SimpleTransferModule
@@ -51,28 +115,54 @@ pub trait CallDispatcherModule:
     ///
     /// Args:
     /// - dest_address - The destination address for the transfer/action. Must be a SC if any execution is wanted.
-    /// - payment_type - You can choose between no payments, EGLD, or multiple ESDT tokens. You may also choose to use the tokens received from the SC starting from the 2nd argument onwards.
+    /// - payment_type - You can choose between no payments, EGLD, or multiple ESDT tokens. You may also choose to use the tokens received from the SC starting from the 2nd argument onwards,
+    ///   or a percentage (in BPS) or all of the bundle's current balance of a token.
     /// - call_type - The type of call wanted. Currently supports simple transfers, sync calls or async calls.
     /// - gas_limit - Used for execution. Ignored for simple transfers. Must be at least 1M if execution is wanted. Not bundled with execution args to save some encoding space.
     /// - function_name + args - Option type arg (NOT optional!). Pass if you want execution. Gas limit must be at least 1M.
     #[payable("*")]
     #[endpoint(multiCall)]
     fn multi_call(&self, args: MultiValueEncoded<SingleCallArg<Self::Api>>) {
+        let args_with_options = args.into_iter().map(|arg| {
+            let (dest_address, payment_type, call_type, gas_limit, opt_exec_arg) = arg.into_tuple();
+
+            (
+                dest_address,
+                payment_type,
+                call_type,
+                gas_limit,
+                opt_exec_arg,
+                StepOptions::default(),
+            )
+                .into()
+        });
+
+        self.perform_calls_for_caller(args_with_options);
+    }
+
+    /// Same as `multiCall`, but every argument also has step options:
+    /// conditions under which the step is skipped, previous step results to use as arguments
+    /// and minimum outputs under which the whole bundle is reverted.
+    /// A skipped step does not change what the next step sees as the "previous step".
+    #[payable("*")]
+    #[endpoint(multiCallWithOptions)]
+    fn multi_call_with_options(
+        &self,
+        args: MultiValueEncoded<SingleCallArgWithOptions<Self::Api>>,
+    ) {
+        self.perform_calls_for_caller(args.into_iter());
+    }
+
+    fn perform_calls_for_caller<I: Iterator<Item = SingleCallArgWithOptions<Self::Api>>>(
+        &self,
+        args: I,
+    ) {
         self.require_not_paused();
 
         let mut total_egld = self.call_value().egld_direct_non_strict().clone_value();
         let mut all_esdt =
             UniquePayments::new_from_payments(self.call_value().all_esdt_transfers().clone_value());
-        let mut opt_last_back_transfers = None;
-
-        for arg in args {
-            self.perform_single_call_from_arg(
-                arg,
-                &mut total_egld,
-                &mut all_esdt,
-                &mut opt_last_back_transfers,
-            );
-        }
+        self.perform_calls(args, &mut total_egld, &mut all_esdt);
 
         let caller = self.blockchain().get_caller();
         self.send().direct_non_zero_egld(&caller, &total_egld);
@@ -83,39 +173,68 @@ pub trait CallDispatcherModule:
         }
     }
 
+    fn perform_calls<I: Iterator<Item = SingleCallArgWithOptions<Self::Api>>>(
+        &self,
+        args: I,
+        total_egld: &mut BigUint,
+        all_esdt: &mut UniquePayments<Self::Api>,
+    ) {
+        let mut opt_last_back_transfers = None;
+        let mut last_results = RawResults::new();
+
+        for arg in args {
+            self.perform_single_call_from_arg(
+                arg,
+                total_egld,
+                all_esdt,
+                &mut opt_last_back_transfers,
+                &mut last_results,
+            );
+        }
+    }
+
     fn perform_single_call_from_arg(
         &self,
-        arg: SingleCallArg<Self::Api>,
+        arg: SingleCallArgWithOptions<Self::Api>,
         total_egld: &mut BigUint,
         all_esdt: &mut UniquePayments<Self::Api>,
         opt_last_back_transfers: &mut Option<BackTransfers<Self::Api>>,
+        last_results: &mut RawResults<Self::Api>,
     ) {
-        let (dest_address, payment_type, call_type, gas_limit, opt_exec_arg) = arg.into_tuple();
+        let (dest_address, payment_type, call_type, gas_limit, opt_exec_arg, step_options) =
+            arg.into_tuple();
         require!(!dest_address.is_zero(), "May not send to zero address");
 
+        if !self.are_step_conditions_met(&step_options, total_egld, all_esdt, last_results) {
+            return;
+        }
+
+        require!(
+            step_options.piped_results.is_empty() || opt_exec_arg.is_some(),
+            "May only pipe results into exec args"
+        );
+
         // The only reason we keep gas limit separate is to save some encoding space. Don't need the whole u64 range for it.
         let opt_raw_call_args = opt_exec_arg.map(|exec_arg| RawCall {
             gas_limit,
             function_name: exec_arg.function_name,
-            args: exec_arg.args,
+            args: self.pipe_previous_results(
+                exec_arg.args,
+                &step_options.piped_results,
+                last_results,
+            ),
         });
 
-        match payment_type {
+        let opt_sync_call_output = match payment_type {
             PaymentType::None => {
-                let opt_back_transfers =
-                    self.perform_no_transfer(dest_address, call_type, opt_raw_call_args);
-                *opt_last_back_transfers =
-                    self.handle_back_transfers_if_any(total_egld, all_esdt, opt_back_transfers);
+                self.perform_no_transfer(dest_address, call_type, opt_raw_call_args)
             }
             PaymentType::Egld { amount } => {
                 require!(*total_egld >= amount, "Invalid EGLD amount");
 
                 *total_egld -= &amount;
 
-                let opt_back_transfers =
-                    self.perform_egld_transfer(dest_address, call_type, amount, opt_raw_call_args);
-                *opt_last_back_transfers =
-                    self.handle_back_transfers_if_any(total_egld, all_esdt, opt_back_transfers);
+                self.perform_egld_transfer(dest_address, call_type, amount, opt_raw_call_args)
             }
             PaymentType::FixedPayments { esdt_payments } => {
                 for transfer in &esdt_payments {
@@ -125,65 +244,259 @@ pub trait CallDispatcherModule:
                     require!(deduct_result.is_ok(), "Invalid ESDT amount");
                 }
 
-                let opt_back_transfers = self.perform_multi_esdt_transfer(
+                self.perform_multi_esdt_transfer(
                     dest_address,
                     call_type,
                     esdt_payments,
                     opt_raw_call_args,
-                );
-                *opt_last_back_transfers =
-                    self.handle_back_transfers_if_any(total_egld, all_esdt, opt_back_transfers);
+                )
             }
             PaymentType::ReceivedPaymentsFromSc => {
                 let last_back_transfers = match opt_last_back_transfers {
-                    Some(back_transfers) => {
-                        require!(
-                            !back_transfers.esdt_payments.is_empty(),
-                            "Only EGLD received or no ESDT received. May only use this feature with ESDT"
-                        );
-
-                        BackTransfers {
-                            total_egld_amount: back_transfers.total_egld_amount.clone(),
-                            esdt_payments: back_transfers.esdt_payments.clone(),
-                        }
-                    }
+                    Some(back_transfers) => BackTransfers {
+                        total_egld_amount: back_transfers.total_egld_amount.clone(),
+                        esdt_payments: back_transfers.esdt_payments.clone(),
+                    },
                     None => sc_panic!("No payments received from SC"),
                 };
 
-                for transfer in &last_back_transfers.esdt_payments {
+                let egld_amount = last_back_transfers.total_egld_amount;
+                let esdt_payments = last_back_transfers.esdt_payments;
+                require!(
+                    egld_amount > 0 || !esdt_payments.is_empty(),
+                    "No payments received from SC"
+                );
+                require!(
+                    *total_egld >= egld_amount,
+                    "May not use these back transfers"
+                );
+                for transfer in &esdt_payments {
                     let deduct_result = all_esdt.deduct_payment(&transfer);
                     require!(deduct_result.is_ok(), "May not use these back transfers");
                 }
 
-                let opt_back_transfers = self.perform_multi_esdt_transfer(
+                *total_egld -= &egld_amount;
+
+                if esdt_payments.is_empty() {
+                    self.perform_egld_transfer(
+                        dest_address,
+                        call_type,
+                        egld_amount,
+                        opt_raw_call_args,
+                    )
+                } else if egld_amount == 0 {
+                    self.perform_multi_esdt_transfer(
+                        dest_address,
+                        call_type,
+                        esdt_payments,
+                        opt_raw_call_args,
+                    )
+                } else {
+                    self.perform_mixed_transfer(
+                        dest_address,
+                        call_type,
+                        egld_amount,
+                        esdt_payments,
+                        opt_raw_call_args,
+                    )
+                }
+            }
+            PaymentType::PercentageOfBalance { token, bps } => {
+                require!(bps > 0 && bps <= MAX_BPS, "Invalid BPS");
+
+                let balance = self.get_running_balance(&token, total_egld, all_esdt);
+                let amount = balance * bps / MAX_BPS;
+
+                self.perform_transfer_from_running_balance(
                     dest_address,
                     call_type,
-                    last_back_transfers.esdt_payments,
+                    token,
+                    amount,
+                    total_egld,
+                    all_esdt,
                     opt_raw_call_args,
-                );
+                )
+            }
+            PaymentType::AllOfToken(token) => {
+                let amount = self.get_running_balance(&token, total_egld, all_esdt);
+
+                self.perform_transfer_from_running_balance(
+                    dest_address,
+                    call_type,
+                    token,
+                    amount,
+                    total_egld,
+                    all_esdt,
+                    opt_raw_call_args,
+                )
+            }
+        };
+
+        self.require_min_outputs(&step_options.min_outputs, &opt_sync_call_output);
+
+        match opt_sync_call_output {
+            Some((back_transfers, results)) => {
                 *opt_last_back_transfers =
-                    self.handle_back_transfers_if_any(total_egld, all_esdt, opt_back_transfers);
+                    Some(self.handle_back_transfers(total_egld, all_esdt, back_transfers));
+                *last_results = results;
             }
+            None => {
+                *opt_last_back_transfers = None;
+                *last_results = RawResults::new();
+            }
+        }
+    }
+
+    fn get_running_balance(
+        &self,
+        token: &EgldOrEsdtTokenIdentifier,
+        total_egld: &BigUint,
+        all_esdt: &UniquePayments<Self::Api>,
+    ) -> BigUint {
+        if token.is_egld() {
+            total_egld.clone()
+        } else {
+            all_esdt.get_amount(&token.clone().unwrap_esdt(), 0)
         }
     }
 
+    #[allow(clippy::too_many_arguments)]
     #[must_use]
-    fn handle_back_transfers_if_any(
+    fn perform_transfer_from_running_balance(
         &self,
+        dest_address: ManagedAddress,
+        call_type: CallType,
+        token: EgldOrEsdtTokenIdentifier,
+        amount: BigUint,
         total_egld: &mut BigUint,
         all_esdt: &mut UniquePayments<Self::Api>,
-        opt_back_transfers: Option<BackTransfers<Self::Api>>,
-    ) -> Option<BackTransfers<Self::Api>> {
-        opt_back_transfers.map(|back_transfers| {
-            let returned_transfers = BackTransfers {
-                total_egld_amount: back_transfers.total_egld_amount.clone(),
-                esdt_payments: back_transfers.esdt_payments.clone(),
+        opt_raw_call_args: Option<RawCall<Self::Api>>,
+    ) -> Option<SyncCallOutput<Self::Api>> {
+        require!(amount > 0, "No balance for token");
+
+        if token.is_egld() {
+            *total_egld -= &amount;
+
+            return self.perform_egld_transfer(dest_address, call_type, amount, opt_raw_call_args);
+        }
+
+        let payment = EsdtTokenPayment::new(token.unwrap_esdt(), 0, amount);
+        let deduct_result = all_esdt.deduct_payment(&payment);
+        require!(deduct_result.is_ok(), "Invalid ESDT amount");
+
+        self.perform_multi_esdt_transfer(
+            dest_address,
+            call_type,
+            ManagedVec::from_single_item(payment),
+            opt_raw_call_args,
+        )
+    }
+
+    fn are_step_conditions_met(
+        &self,
+        step_options: &StepOptions<Self::Api>,
+        total_egld: &BigUint,
+        all_esdt: &UniquePayments<Self::Api>,
+        last_results: &RawResults<Self::Api>,
+    ) -> bool {
+        for min_balance in &step_options.min_balances {
+            let current_balance = if min_balance.token_identifier.is_egld() {
+                total_egld.clone()
+            } else {
+                all_esdt.get_amount(
+                    &min_balance.token_identifier.clone().unwrap_esdt(),
+                    min_balance.token_nonce,
+                )
             };
+            if current_balance < min_balance.amount {
+                return false;
+            }
+        }
+
+        for expected_result in &step_options.expected_results {
+            if expected_result.result_index >= last_results.len() {
+                return false;
+            }
+            if *last_results.get(expected_result.result_index) != expected_result.value {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    fn pipe_previous_results(
+        &self,
+        mut args: RawArgs<Self::Api>,
+        piped_results: &ManagedVec<PipedResult>,
+        last_results: &RawResults<Self::Api>,
+    ) -> RawArgs<Self::Api> {
+        for piped_result in piped_results {
+            require!(
+                piped_result.result_index < last_results.len(),
+                "Invalid piped result index"
+            );
+
+            let result = (*last_results.get(piped_result.result_index)).clone();
+            let set_result = args.set(piped_result.arg_index, result);
+            require!(set_result.is_ok(), "Invalid piped arg index");
+        }
+
+        args
+    }
+
+    fn require_min_outputs(
+        &self,
+        min_outputs: &ManagedVec<EgldOrEsdtTokenPayment>,
+        opt_sync_call_output: &Option<SyncCallOutput<Self::Api>>,
+    ) {
+        if min_outputs.is_empty() {
+            return;
+        }
+
+        let back_transfers = match opt_sync_call_output {
+            Some((back_transfers, _)) => back_transfers,
+            None => sc_panic!("May only check outputs of sync calls"),
+        };
+
+        for min_output in min_outputs {
+            let received_amount = if min_output.token_identifier.is_egld() {
+                back_transfers.total_egld_amount.clone()
+            } else {
+                let mut received_amount = BigUint::zero();
+                for payment in &back_transfers.esdt_payments {
+                    if min_output.token_identifier == payment.token_identifier
+                        && min_output.token_nonce == payment.token_nonce
+                    {
+                        received_amount += &payment.amount;
+                    }
+                }
+
+                received_amount
+            };
+
+            require!(
+                received_amount >= min_output.amount,
+                "Minimum output not reached"
+            );
+        }
+    }
+
+    #[must_use]
+    fn handle_back_transfers(
+        &self,
+        total_egld: &mut BigUint,
+        all_esdt: &mut UniquePayments<Self::Api>,
+        back_transfers: BackTransfers<Self::Api>,
+    ) -> BackTransfers<Self::Api> {
+        let returned_transfers = BackTransfers {
+            total_egld_amount: back_transfers.total_egld_amount.clone(),
+            esdt_payments: back_transfers.esdt_payments.clone(),
+        };
 
-            self.add_payments_received_from_sc(total_egld, all_esdt, back_transfers);
+        self.add_payments_received_from_sc(total_egld, all_esdt, back_transfers);
 
-            returned_transfers
-        })
+        returned_transfers
     }
 
     fn add_payments_received_from_sc(
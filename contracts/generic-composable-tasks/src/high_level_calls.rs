@@ -1,10 +1,12 @@
 use crate::{
     call_dispatcher::CallType,
-    raw_call::common::{PaymentsVec, RawCall},
+    raw_call::common::{PaymentsVec, RawCall, RawResults},
 };
 
 multiversx_sc::imports!();
 
+pub type SyncCallOutput<M> = (BackTransfers<M>, RawResults<M>);
+
 #[multiversx_sc::module]
 pub trait HighLevelCallsModule:
     crate::raw_call::simple_transfer::SimpleTransferModule
@@ -18,17 +20,17 @@ pub trait HighLevelCallsModule:
         dest_address: ManagedAddress,
         call_type: CallType,
         opt_raw_call_args: Option<RawCall<Self::Api>>,
-    ) -> Option<BackTransfers<Self::Api>> {
+    ) -> Option<SyncCallOutput<Self::Api>> {
         match call_type {
             CallType::SimpleTransfer => {
                 sc_panic!("May not perform simple transfer with no actual transfers")
             }
             CallType::Sync => {
                 let raw_call_args = self.unwrap_raw_call_args_or_panic(opt_raw_call_args);
-                let back_transfers =
+                let sync_call_output =
                     self.perform_raw_sync_call_egld(dest_address, raw_call_args, BigUint::zero());
 
-                Some(back_transfers)
+                Some(sync_call_output)
             }
             CallType::Async => {
                 let raw_call_args = self.unwrap_raw_call_args_or_panic(opt_raw_call_args);
@@ -46,7 +48,7 @@ pub trait HighLevelCallsModule:
         call_type: CallType,
         egld_value: BigUint,
         opt_raw_call_args: Option<RawCall<Self::Api>>,
-    ) -> Option<BackTransfers<Self::Api>> {
+    ) -> Option<SyncCallOutput<Self::Api>> {
         match call_type {
             CallType::SimpleTransfer => {
                 self.perform_simple_transfer_egld(&dest_address, &egld_value);
@@ -55,10 +57,10 @@ pub trait HighLevelCallsModule:
             }
             CallType::Sync => {
                 let raw_call_args = self.unwrap_raw_call_args_or_panic(opt_raw_call_args);
-                let back_transfers =
+                let sync_call_output =
                     self.perform_raw_sync_call_egld(dest_address, raw_call_args, egld_value);
 
-                Some(back_transfers)
+                Some(sync_call_output)
             }
             CallType::Async => {
                 let raw_call_args = self.unwrap_raw_call_args_or_panic(opt_raw_call_args);
@@ -76,7 +78,7 @@ pub trait HighLevelCallsModule:
         call_type: CallType,
         esdt_payments: PaymentsVec<Self::Api>,
         opt_raw_call_args: Option<RawCall<Self::Api>>,
-    ) -> Option<BackTransfers<Self::Api>> {
+    ) -> Option<SyncCallOutput<Self::Api>> {
         match call_type {
             CallType::SimpleTransfer => {
                 self.perform_simple_transfer_esdt(&dest_address, &esdt_payments);
@@ -85,10 +87,10 @@ pub trait HighLevelCallsModule:
             }
             CallType::Sync => {
                 let raw_call_args = self.unwrap_raw_call_args_or_panic(opt_raw_call_args);
-                let back_transfers =
+                let sync_call_output =
                     self.perform_raw_sync_call_esdt(dest_address, raw_call_args, esdt_payments);
 
-                Some(back_transfers)
+                Some(sync_call_output)
             }
             CallType::Async => {
                 let raw_call_args = self.unwrap_raw_call_args_or_panic(opt_raw_call_args);
@@ -99,6 +101,46 @@ pub trait HighLevelCallsModule:
         }
     }
 
+    #[must_use]
+    fn perform_mixed_transfer(
+        &self,
+        dest_address: ManagedAddress,
+        call_type: CallType,
+        egld_value: BigUint,
+        esdt_payments: PaymentsVec<Self::Api>,
+        opt_raw_call_args: Option<RawCall<Self::Api>>,
+    ) -> Option<SyncCallOutput<Self::Api>> {
+        match call_type {
+            CallType::SimpleTransfer => {
+                self.perform_simple_transfer_mixed(&dest_address, &egld_value, &esdt_payments);
+
+                None
+            }
+            CallType::Sync => {
+                let raw_call_args = self.unwrap_raw_call_args_or_panic(opt_raw_call_args);
+                let sync_call_output = self.perform_raw_sync_call_mixed(
+                    dest_address,
+                    raw_call_args,
+                    egld_value,
+                    esdt_payments,
+                );
+
+                Some(sync_call_output)
+            }
+            CallType::Async => {
+                let raw_call_args = self.unwrap_raw_call_args_or_panic(opt_raw_call_args);
+                self.perform_raw_async_call_mixed(
+                    dest_address,
+                    raw_call_args,
+                    egld_value,
+                    esdt_payments,
+                );
+
+                None
+            }
+        }
+    }
+
     #[inline]
     #[must_use]
     fn unwrap_raw_call_args_or_panic(
@@ -5,6 +5,7 @@ multiversx_sc::imports!();
 pub mod call_dispatcher;
 pub mod high_level_calls;
 pub mod raw_call;
+pub mod task_templates;
 pub mod unique_payments;
 
 #[multiversx_sc::contract]
@@ -15,6 +16,7 @@ pub trait GenericComposableTasks:
     + raw_call::common::CommonModule
     + high_level_calls::HighLevelCallsModule
     + call_dispatcher::CallDispatcherModule
+    + task_templates::TaskTemplatesModule
     + multiversx_sc_modules::pause::PauseModule
 {
     #[init]
@@ -56,6 +56,37 @@ pub trait AsyncCallModule: super::common::CommonModule {
             .register_promise();
     }
 
+    #[allow(deprecated)]
+    fn perform_raw_async_call_mixed(
+        &self,
+        sc_address: ManagedAddress,
+        raw_call_data: RawCall<Self::Api>,
+        egld_value: BigUint,
+        esdt_payments: PaymentsVec<Self::Api>,
+    ) {
+        self.require_dest_not_self(&sc_address);
+        self.require_sc_address(&sc_address);
+        self.require_not_empty_payments(&esdt_payments);
+
+        let original_caller = self.blockchain().get_caller();
+        let gas_for_callback = self.get_gas_for_callback(raw_call_data.gas_limit);
+
+        let contract_call = self.build_raw_call_with_args(sc_address, raw_call_data);
+        let payments = self.build_mixed_payments(egld_value.clone(), esdt_payments.clone());
+        self.tx()
+            .to(contract_call.to)
+            .gas(contract_call.explicit_gas_limit)
+            .raw_data(contract_call.function_call)
+            .payment(payments)
+            .callback(self.callbacks().raw_async_callback(
+                original_caller,
+                egld_value,
+                esdt_payments,
+            ))
+            .gas_for_callback(gas_for_callback)
+            .register_promise();
+    }
+
     #[inline]
     fn get_gas_for_callback(&self, full_gas_limit: GasLimit) -> GasLimit {
         full_gas_limit / 8
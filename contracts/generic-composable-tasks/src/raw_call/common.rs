@@ -4,6 +4,7 @@ multiversx_sc::derive_imports!();
 pub type GasLimit = u64;
 pub type FunctionName<M> = ManagedBuffer<M>;
 pub type RawArgs<M> = ManagedVec<M, ManagedBuffer<M>>;
+pub type RawResults<M> = ManagedVec<M, ManagedBuffer<M>>;
 pub type PaymentsVec<M> = ManagedVec<M, EsdtTokenPayment<M>>;
 
 pub const MIN_GAS_LIMIT: GasLimit = 1_000_000;
@@ -40,6 +41,20 @@ pub trait CommonModule {
         contract_call
     }
 
+    fn build_mixed_payments(
+        &self,
+        egld_value: BigUint,
+        esdt_payments: PaymentsVec<Self::Api>,
+    ) -> MultiEgldOrEsdtPayment<Self::Api> {
+        let mut payments = MultiEgldOrEsdtPayment::new();
+        payments.push(EgldOrEsdtTokenPayment::egld_payment(egld_value));
+        for payment in &esdt_payments {
+            payments.push(EgldOrEsdtTokenPayment::from(payment.clone()));
+        }
+
+        payments
+    }
+
     #[inline]
     fn clear_back_transfers(&self) {
         let _ = self.blockchain().get_back_transfers();
@@ -22,4 +22,14 @@ pub trait SimpleTransferModule: super::common::CommonModule {
 
         self.send().direct_multi(to, esdt_payments);
     }
+
+    fn perform_simple_transfer_mixed(
+        &self,
+        to: &ManagedAddress,
+        egld_value: &BigUint,
+        esdt_payments: &PaymentsVec<Self::Api>,
+    ) {
+        self.perform_simple_transfer_egld(to, egld_value);
+        self.perform_simple_transfer_esdt(to, esdt_payments);
+    }
 }
@@ -1,4 +1,4 @@
-use super::common::{PaymentsVec, RawCall};
+use super::common::{PaymentsVec, RawCall, RawResults};
 
 multiversx_sc::imports!();
 
@@ -10,17 +10,17 @@ pub trait SyncCallModule: super::common::CommonModule {
         sc_address: ManagedAddress,
         raw_call_data: RawCall<Self::Api>,
         egld_value: BigUint,
-    ) -> BackTransfers<Self::Api> {
+    ) -> (BackTransfers<Self::Api>, RawResults<Self::Api>) {
         self.require_dest_not_self(&sc_address);
         self.require_sc_address(&sc_address);
 
         let contract_call = self.build_raw_call_with_args(sc_address, raw_call_data);
         let contract_call_with_egld = contract_call.with_egld_transfer(egld_value);
-        let (_, back_transfers): (IgnoreValue, _) =
+        let (results, back_transfers): (MultiValueEncoded<ManagedBuffer>, _) =
             contract_call_with_egld.execute_on_dest_context_with_back_transfers();
         self.clear_back_transfers();
 
-        back_transfers
+        (back_transfers, results.into_vec_of_buffers())
     }
 
     #[must_use]
@@ -29,17 +29,46 @@ pub trait SyncCallModule: super::common::CommonModule {
         sc_address: ManagedAddress,
         raw_call_data: RawCall<Self::Api>,
         esdt_payments: PaymentsVec<Self::Api>,
-    ) -> BackTransfers<Self::Api> {
+    ) -> (BackTransfers<Self::Api>, RawResults<Self::Api>) {
         self.require_dest_not_self(&sc_address);
         self.require_sc_address(&sc_address);
         self.require_not_empty_payments(&esdt_payments);
 
         let contract_call = self.build_raw_call_with_args(sc_address, raw_call_data);
         let contract_call_with_esdt = contract_call.with_multi_token_transfer(esdt_payments);
-        let (_, back_transfers): (IgnoreValue, _) =
+        let (results, back_transfers): (MultiValueEncoded<ManagedBuffer>, _) =
             contract_call_with_esdt.execute_on_dest_context_with_back_transfers();
         self.clear_back_transfers();
 
-        back_transfers
+        (back_transfers, results.into_vec_of_buffers())
+    }
+
+    #[allow(deprecated)]
+    #[must_use]
+    fn perform_raw_sync_call_mixed(
+        &self,
+        sc_address: ManagedAddress,
+        raw_call_data: RawCall<Self::Api>,
+        egld_value: BigUint,
+        esdt_payments: PaymentsVec<Self::Api>,
+    ) -> (BackTransfers<Self::Api>, RawResults<Self::Api>) {
+        self.require_dest_not_self(&sc_address);
+        self.require_sc_address(&sc_address);
+        self.require_not_empty_payments(&esdt_payments);
+
+        let contract_call = self.build_raw_call_with_args(sc_address, raw_call_data);
+        let payments = self.build_mixed_payments(egld_value, esdt_payments);
+        let (results, back_transfers) = self
+            .tx()
+            .to(contract_call.to)
+            .gas(contract_call.explicit_gas_limit)
+            .raw_data(contract_call.function_call)
+            .payment(payments)
+            .returns(ReturnsRawResult)
+            .returns(ReturnsBackTransfers)
+            .sync_call();
+        self.clear_back_transfers();
+
+        (back_transfers, results)
     }
 }
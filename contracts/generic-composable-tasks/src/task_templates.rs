@@ -0,0 +1,382 @@
+use crate::{
+    call_dispatcher::{
+        CallType, FunctionNameArgsPair, PaymentType, SingleCallArgWithOptions, StepOptions,
+    },
+    raw_call::common::{GasLimit, PaymentsVec},
+    unique_payments::UniquePayments,
+};
+
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+pub type Timestamp = u64;
+
+/// Stored form of a `multiCallWithOptions` argument.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode)]
+pub struct TaskStep<M: ManagedTypeApi> {
+    pub dest_address: ManagedAddress<M>,
+    pub payment_type: PaymentType<M>,
+    pub call_type: CallType,
+    pub gas_limit: GasLimit,
+    pub opt_exec_arg: Option<FunctionNameArgsPair<M>>,
+    pub step_options: StepOptions<M>,
+}
+
+/// The execution param with the same index replaces the argument at `arg_index` of step `step_index`.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem)]
+pub struct ParamSlot {
+    pub step_index: usize,
+    pub arg_index: usize,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode)]
+pub struct ExecutorPermission<M: ManagedTypeApi> {
+    pub expiry: Timestamp,
+    pub allowed_destinations: ManagedVec<M, ManagedAddress<M>>,
+    /// Decreased with every run. Tokens not in this list may not be spent.
+    pub remaining_spend: ManagedVec<M, EgldOrEsdtTokenPayment<M>>,
+}
+
+#[multiversx_sc::module]
+pub trait TaskTemplatesModule:
+    crate::call_dispatcher::CallDispatcherModule
+    + crate::raw_call::simple_transfer::SimpleTransferModule
+    + crate::raw_call::sync_call::SyncCallModule
+    + crate::raw_call::async_call::AsyncCallModule
+    + crate::raw_call::common::CommonModule
+    + crate::high_level_calls::HighLevelCallsModule
+    + multiversx_sc_modules::pause::PauseModule
+{
+    /// Saves a named task for the caller. Steps use the same format as `multiCallWithOptions`, async calls excepted.
+    /// `param_slots` mark the arguments that are provided at execution time.
+    #[endpoint(registerTaskTemplate)]
+    fn register_task_template(
+        &self,
+        name: ManagedBuffer,
+        param_slots: ManagedVec<ParamSlot>,
+        steps: MultiValueEncoded<SingleCallArgWithOptions<Self::Api>>,
+    ) {
+        require!(!steps.is_empty(), "No steps provided");
+
+        let caller = self.blockchain().get_caller();
+        require!(
+            self.template_names(&caller).insert(name.clone()),
+            "Template already exists"
+        );
+
+        let mut steps_mapper = self.template_steps(&caller, &name);
+        for step in steps {
+            let (dest_address, payment_type, call_type, gas_limit, opt_exec_arg, step_options) =
+                step.into_tuple();
+            require!(
+                call_type != CallType::Async,
+                "Async calls not allowed in templates"
+            );
+
+            let _ = steps_mapper.push(&TaskStep {
+                dest_address,
+                payment_type,
+                call_type,
+                gas_limit,
+                opt_exec_arg,
+                step_options,
+            });
+        }
+
+        for param_slot in &param_slots {
+            require!(
+                param_slot.step_index < steps_mapper.len(),
+                "Invalid param slot"
+            );
+
+            let step = steps_mapper.get(param_slot.step_index + 1);
+            match step.opt_exec_arg {
+                Some(exec_arg) => require!(
+                    param_slot.arg_index < exec_arg.args.len(),
+                    "Invalid param slot"
+                ),
+                None => sc_panic!("Invalid param slot"),
+            }
+        }
+
+        self.template_param_slots(&caller, &name).set(param_slots);
+    }
+
+    #[endpoint(removeTaskTemplate)]
+    fn remove_task_template(&self, name: ManagedBuffer) {
+        let caller = self.blockchain().get_caller();
+        require!(
+            self.template_names(&caller).swap_remove(&name),
+            "Unknown template"
+        );
+
+        self.template_steps(&caller, &name).clear();
+        self.template_param_slots(&caller, &name).clear();
+    }
+
+    /// Allows `executor` to run the caller's templates until `expiry`, only towards `allowed_destinations`
+    /// and spending at most `max_spend` from the caller's task funds. Replaces any previous permission.
+    #[endpoint(authorizeExecutor)]
+    fn authorize_executor(
+        &self,
+        executor: ManagedAddress,
+        expiry: Timestamp,
+        allowed_destinations: ManagedVec<ManagedAddress>,
+        max_spend: ManagedVec<EgldOrEsdtTokenPayment>,
+    ) {
+        let current_timestamp = self.blockchain().get_block_timestamp();
+        require!(expiry > current_timestamp, "Invalid expiry");
+
+        let caller = self.blockchain().get_caller();
+        require!(caller != executor, "May not authorize self");
+
+        self.executor_permission(&caller, &executor)
+            .set(ExecutorPermission {
+                expiry,
+                allowed_destinations,
+                remaining_spend: max_spend,
+            });
+    }
+
+    #[endpoint(revokeExecutor)]
+    fn revoke_executor(&self, executor: ManagedAddress) {
+        let caller = self.blockchain().get_caller();
+        self.executor_permission(&caller, &executor).clear();
+    }
+
+    /// Funds used when the caller's templates are executed.
+    #[payable("*")]
+    #[endpoint(depositTaskFunds)]
+    fn deposit_task_funds(&self) {
+        let caller = self.blockchain().get_caller();
+        let egld_value = self.call_value().egld_direct_non_strict().clone_value();
+        if egld_value > 0 {
+            self.task_funds_egld(&caller)
+                .update(|egld_funds| *egld_funds += egld_value);
+        }
+
+        let esdt_payments = self.call_value().all_esdt_transfers().clone_value();
+        if !esdt_payments.is_empty() {
+            self.task_funds_esdt(&caller).update(|esdt_funds| {
+                let mut unique_payments =
+                    UniquePayments::new_from_unique_payments(esdt_funds.clone());
+                for payment in esdt_payments {
+                    unique_payments.add_payment(payment);
+                }
+
+                *esdt_funds = unique_payments.into_payments();
+            });
+        }
+    }
+
+    #[endpoint(withdrawTaskFunds)]
+    fn withdraw_task_funds(&self) {
+        let caller = self.blockchain().get_caller();
+        let egld_funds = self.task_funds_egld(&caller).take();
+        self.send().direct_non_zero_egld(&caller, &egld_funds);
+
+        let esdt_payments = self.task_funds_esdt(&caller).take();
+        if !esdt_payments.is_empty() {
+            self.send().direct_multi(&caller, &esdt_payments);
+        }
+    }
+
+    /// Runs a template of `owner` with their task funds. What is left afterwards goes back to the task funds.
+    /// The owner may always run their templates, anyone else needs an unexpired executor permission.
+    #[endpoint(executeTaskTemplate)]
+    fn execute_task_template(
+        &self,
+        owner: ManagedAddress,
+        name: ManagedBuffer,
+        params: MultiValueEncoded<ManagedBuffer>,
+    ) {
+        self.require_not_paused();
+        require!(
+            self.template_names(&owner).contains(&name),
+            "Unknown template"
+        );
+
+        let param_slots = self.template_param_slots(&owner, &name).get();
+        let params = params.into_vec_of_buffers();
+        require!(
+            params.len() == param_slots.len(),
+            "Invalid number of params"
+        );
+
+        let steps_mapper = self.template_steps(&owner, &name);
+        let caller = self.blockchain().get_caller();
+        let opt_permission = if caller != owner {
+            let permission_mapper = self.executor_permission(&owner, &caller);
+            require!(!permission_mapper.is_empty(), "Not authorized");
+
+            let permission = permission_mapper.get();
+            let current_timestamp = self.blockchain().get_block_timestamp();
+            require!(current_timestamp <= permission.expiry, "Permission expired");
+
+            for step in steps_mapper.iter() {
+                require!(
+                    permission.allowed_destinations.contains(&step.dest_address),
+                    "Destination not allowed"
+                );
+            }
+
+            Some(permission)
+        } else {
+            None
+        };
+
+        let egld_funds_mapper = self.task_funds_egld(&owner);
+        let esdt_funds_mapper = self.task_funds_esdt(&owner);
+        let egld_before = egld_funds_mapper.get();
+        let esdt_before = UniquePayments::new_from_unique_payments(esdt_funds_mapper.get());
+
+        let mut total_egld = egld_before.clone();
+        let mut all_esdt = esdt_before.clone();
+        let steps = steps_mapper.iter().enumerate().map(|(step_index, step)| {
+            self.build_call_arg_from_step(step_index, step, &param_slots, &params)
+        });
+        self.perform_calls(steps, &mut total_egld, &mut all_esdt);
+
+        if let Some(mut permission) = opt_permission {
+            self.deduct_spent_amounts(
+                &mut permission.remaining_spend,
+                &egld_before,
+                &total_egld,
+                &esdt_before,
+                &all_esdt,
+            );
+            self.executor_permission(&owner, &caller).set(permission);
+        }
+
+        egld_funds_mapper.set(total_egld);
+        esdt_funds_mapper.set(all_esdt.into_payments());
+    }
+
+    fn build_call_arg_from_step(
+        &self,
+        step_index: usize,
+        step: TaskStep<Self::Api>,
+        param_slots: &ManagedVec<ParamSlot>,
+        params: &ManagedVec<ManagedBuffer>,
+    ) -> SingleCallArgWithOptions<Self::Api> {
+        let mut opt_exec_arg = step.opt_exec_arg;
+        for (param_index, param_slot) in param_slots.iter().enumerate() {
+            if param_slot.step_index != step_index {
+                continue;
+            }
+
+            if let Some(exec_arg) = opt_exec_arg.as_mut() {
+                let param = (*params.get(param_index)).clone();
+                let _ = exec_arg.args.set(param_slot.arg_index, param);
+            }
+        }
+
+        (
+            step.dest_address,
+            step.payment_type,
+            step.call_type,
+            step.gas_limit,
+            opt_exec_arg,
+            step.step_options,
+        )
+            .into()
+    }
+
+    fn deduct_spent_amounts(
+        &self,
+        remaining_spend: &mut ManagedVec<EgldOrEsdtTokenPayment>,
+        egld_before: &BigUint,
+        egld_after: &BigUint,
+        esdt_before: &UniquePayments<Self::Api>,
+        esdt_after: &UniquePayments<Self::Api>,
+    ) {
+        if egld_before > egld_after {
+            let spent_amount = egld_before - egld_after;
+            self.deduct_spent_amount(
+                remaining_spend,
+                &EgldOrEsdtTokenIdentifier::egld(),
+                0,
+                &spent_amount,
+            );
+        }
+
+        for payment in esdt_before.clone().into_payments().iter() {
+            let amount_after =
+                esdt_after.get_amount(&payment.token_identifier, payment.token_nonce);
+            if payment.amount <= amount_after {
+                continue;
+            }
+
+            let spent_amount = &payment.amount - &amount_after;
+            self.deduct_spent_amount(
+                remaining_spend,
+                &EgldOrEsdtTokenIdentifier::esdt(payment.token_identifier.clone()),
+                payment.token_nonce,
+                &spent_amount,
+            );
+        }
+    }
+
+    fn deduct_spent_amount(
+        &self,
+        remaining_spend: &mut ManagedVec<EgldOrEsdtTokenPayment>,
+        token: &EgldOrEsdtTokenIdentifier,
+        token_nonce: u64,
+        spent_amount: &BigUint,
+    ) {
+        for i in 0..remaining_spend.len() {
+            let mut allowance = (*remaining_spend.get(i)).clone();
+            if &allowance.token_identifier != token || allowance.token_nonce != token_nonce {
+                continue;
+            }
+
+            require!(&allowance.amount >= spent_amount, "Spending limit exceeded");
+
+            allowance.amount -= spent_amount;
+            let _ = remaining_spend.set(i, allowance);
+
+            return;
+        }
+
+        sc_panic!("Spending limit exceeded");
+    }
+
+    #[view(getTemplateNames)]
+    #[storage_mapper("templateNames")]
+    fn template_names(&self, owner: &ManagedAddress) -> UnorderedSetMapper<ManagedBuffer>;
+
+    #[view(getTemplateSteps)]
+    #[storage_mapper("templateSteps")]
+    fn template_steps(
+        &self,
+        owner: &ManagedAddress,
+        name: &ManagedBuffer,
+    ) -> VecMapper<TaskStep<Self::Api>>;
+
+    #[view(getTemplateParamSlots)]
+    #[storage_mapper("templateParamSlots")]
+    fn template_param_slots(
+        &self,
+        owner: &ManagedAddress,
+        name: &ManagedBuffer,
+    ) -> SingleValueMapper<ManagedVec<ParamSlot>>;
+
+    #[view(getExecutorPermission)]
+    #[storage_mapper("executorPermission")]
+    fn executor_permission(
+        &self,
+        owner: &ManagedAddress,
+        executor: &ManagedAddress,
+    ) -> SingleValueMapper<ExecutorPermission<Self::Api>>;
+
+    #[view(getTaskFundsEgld)]
+    #[storage_mapper("taskFundsEgld")]
+    fn task_funds_egld(&self, owner: &ManagedAddress) -> SingleValueMapper<BigUint>;
+
+    #[view(getTaskFundsEsdt)]
+    #[storage_mapper("taskFundsEsdt")]
+    fn task_funds_esdt(&self, owner: &ManagedAddress) -> SingleValueMapper<PaymentsVec<Self::Api>>;
+}
@@ -89,6 +89,16 @@ impl<M: ManagedTypeApi> UniquePayments<M> {
         Result::Err(())
     }
 
+    pub fn get_amount(&self, token_id: &TokenIdentifier<M>, token_nonce: u64) -> BigUint<M> {
+        for payment in self.payments.iter() {
+            if &payment.token_identifier == token_id && payment.token_nonce == token_nonce {
+                return payment.amount.clone();
+            }
+        }
+
+        BigUint::zero()
+    }
+
     #[inline]
     pub fn into_payments(self) -> PaymentsVec<M> {
         self.payments
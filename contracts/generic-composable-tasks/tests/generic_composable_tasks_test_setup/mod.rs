@@ -1,7 +1,10 @@
 #![allow(deprecated)]
 
 use generic_composable_tasks::{
-    call_dispatcher::{CallType, FunctionNameArgsPair, PaymentType, SingleCallArg},
+    call_dispatcher::{
+        CallType, FunctionNameArgsPair, PaymentType, SingleCallArg, SingleCallArgWithOptions,
+        StepOptions,
+    },
     raw_call::common::PaymentsVec,
     GenericComposableTasks,
 };
@@ -171,6 +174,16 @@ pub fn build_simple_transfer_payments_received_from_sc_data<M: ManagedTypeApi>(
         .into()
 }
 
+pub fn build_simple_transfer_from_balance_data<M: ManagedTypeApi>(
+    dest_address: &Address,
+    payment_type: PaymentType<M>,
+) -> SingleCallArg<M> {
+    let mut arg = build_simple_transfer_payments_received_from_sc_data(dest_address);
+    arg.0 .1 = payment_type;
+
+    arg
+}
+
 pub fn build_sync_call_egld_transfer_data<M: ManagedTypeApi>(
     dest_address: &Address,
     egld_amount: u64,
@@ -197,6 +210,17 @@ pub fn build_sync_call_egld_transfer_data<M: ManagedTypeApi>(
         .into()
 }
 
+pub fn build_sync_call_no_payment_data<M: ManagedTypeApi>(
+    dest_address: &Address,
+    function_name: &[u8],
+    args: Vec<Vec<u8>>,
+) -> SingleCallArg<M> {
+    let mut arg = build_sync_call_egld_transfer_data(dest_address, 0, function_name, args);
+    arg.0 .1 = PaymentType::None;
+
+    arg
+}
+
 pub fn build_sync_call_esdt_transfer_data<M: ManagedTypeApi>(
     dest_address: &Address,
     esdt_transfers: Vec<TxTokenTransfer>,
@@ -254,3 +278,20 @@ pub fn build_async_call_esdt_transfer_data<M: ManagedTypeApi>(
 
     arg
 }
+
+pub fn with_step_options<M: ManagedTypeApi>(
+    arg: SingleCallArg<M>,
+    step_options: StepOptions<M>,
+) -> SingleCallArgWithOptions<M> {
+    let (dest_address, payment_type, call_type, gas_limit, opt_exec_arg) = arg.into_tuple();
+
+    (
+        dest_address,
+        payment_type,
+        call_type,
+        gas_limit,
+        opt_exec_arg,
+        step_options,
+    )
+        .into()
+}
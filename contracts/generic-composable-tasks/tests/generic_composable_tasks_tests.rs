@@ -1,13 +1,27 @@
-use generic_composable_tasks::call_dispatcher::CallDispatcherModule;
+use adder::Adder;
+use generic_composable_tasks::{
+    call_dispatcher::{
+        CallDispatcherModule, ExpectedResult, PaymentType, PipedResult, StepOptions,
+    },
+    task_templates::{ParamSlot, TaskTemplatesModule},
+    GenericComposableTasks,
+};
 use generic_composable_tasks_test_setup::{
     build_async_call_egld_transfer_data, build_async_call_esdt_transfer_data,
     build_egld_simple_transfer_data, build_esdt_simple_transfer_data,
-    build_simple_transfer_payments_received_from_sc_data, build_sync_call_egld_transfer_data,
-    build_sync_call_esdt_transfer_data, GenericCompTasksSetup, RAND_ESDT_TOKEN_ID,
+    build_simple_transfer_from_balance_data, build_simple_transfer_payments_received_from_sc_data,
+    build_sync_call_egld_transfer_data, build_sync_call_esdt_transfer_data,
+    build_sync_call_no_payment_data, with_step_options, GenericCompTasksSetup, RAND_ESDT_TOKEN_ID,
     UNWRAP_EGLD_ENDPOINT_NAME, USER_BALANCE, WEGLD_TOKEN_ID, WRAP_EGLD_ENDPOINT_NAME,
 };
-use multiversx_sc::types::MultiValueEncoded;
-use multiversx_sc_scenario::{imports::TxTokenTransfer, managed_token_id, rust_biguint, DebugApi};
+use multiversx_sc::{
+    codec::top_encode_to_vec_u8_or_panic,
+    types::{EgldOrEsdtTokenIdentifier, EgldOrEsdtTokenPayment, ManagedVec, MultiValueEncoded},
+};
+use multiversx_sc_scenario::{
+    imports::TxTokenTransfer, managed_address, managed_biguint, managed_buffer, managed_token_id,
+    rust_biguint, DebugApi,
+};
 use multiversx_wegld_swap_sc::EgldEsdtSwap;
 
 pub mod generic_composable_tasks_test_setup;
@@ -477,3 +491,756 @@ fn sync_call_then_use_received_payments_test() {
         &rust_biguint!(transfer_amount),
     );
 }
+
+#[test]
+fn sync_call_pipe_results_test() {
+    let mut setup = GenericCompTasksSetup::new(
+        generic_composable_tasks::contract_obj,
+        multiversx_wegld_swap_sc::contract_obj,
+    );
+
+    let initial_sum = 5u64;
+    let transfer_amount = 100;
+    let wegld_sc_address = setup.wegld_swap_wrapper.address_ref().clone();
+
+    let adder_wrapper = setup.b_mock.create_sc_account(
+        &rust_biguint!(0),
+        Some(&setup.owner_address),
+        adder::contract_obj,
+        "adder",
+    );
+    setup
+        .b_mock
+        .execute_tx(
+            &setup.owner_address,
+            &adder_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.init(managed_biguint!(initial_sum));
+            },
+        )
+        .assert_ok();
+    let adder_sc_address = adder_wrapper.address_ref().clone();
+
+    setup
+        .b_mock
+        .execute_tx(
+            &setup.user_address,
+            &setup.tasks_wrapper,
+            &rust_biguint!(transfer_amount),
+            |sc| {
+                let wrap_arg = with_step_options(
+                    build_sync_call_egld_transfer_data::<DebugApi>(
+                        &wegld_sc_address,
+                        transfer_amount,
+                        WRAP_EGLD_ENDPOINT_NAME,
+                        Vec::new(),
+                    ),
+                    StepOptions::default(),
+                );
+                let locked_balance_arg = with_step_options(
+                    build_sync_call_no_payment_data::<DebugApi>(
+                        &wegld_sc_address,
+                        b"getLockedEgldBalance",
+                        Vec::new(),
+                    ),
+                    StepOptions::default(),
+                );
+
+                // the placeholder argument is replaced by the locked balance
+                let mut step_options = StepOptions::default();
+                step_options.piped_results.push(PipedResult {
+                    result_index: 0,
+                    arg_index: 0,
+                });
+                let add_arg = with_step_options(
+                    build_sync_call_no_payment_data::<DebugApi>(
+                        &adder_sc_address,
+                        b"add",
+                        vec![Vec::new()],
+                    ),
+                    step_options,
+                );
+
+                let mut all_args = MultiValueEncoded::new();
+                all_args.push(wrap_arg);
+                all_args.push(locked_balance_arg);
+                all_args.push(add_arg);
+
+                sc.multi_call_with_options(all_args);
+            },
+        )
+        .assert_ok();
+
+    setup
+        .b_mock
+        .execute_query(&adder_wrapper, |sc| {
+            assert_eq!(
+                sc.sum().get(),
+                managed_biguint!(initial_sum + transfer_amount)
+            );
+        })
+        .assert_ok();
+    setup.b_mock.check_esdt_balance(
+        &setup.user_address,
+        WEGLD_TOKEN_ID,
+        &rust_biguint!(transfer_amount),
+    );
+}
+
+#[test]
+fn conditional_steps_test() {
+    let mut setup = GenericCompTasksSetup::new(
+        generic_composable_tasks::contract_obj,
+        multiversx_wegld_swap_sc::contract_obj,
+    );
+
+    let transfer_amount = 100;
+    let dest_sc_address = setup.wegld_swap_wrapper.address_ref().clone();
+    let other_user_address = setup.other_user_address.clone();
+
+    setup
+        .b_mock
+        .execute_tx(
+            &setup.user_address,
+            &setup.tasks_wrapper,
+            &rust_biguint!(transfer_amount),
+            |sc| {
+                let wrap_arg = with_step_options(
+                    build_sync_call_egld_transfer_data::<DebugApi>(
+                        &dest_sc_address,
+                        transfer_amount,
+                        WRAP_EGLD_ENDPOINT_NAME,
+                        Vec::new(),
+                    ),
+                    StepOptions::default(),
+                );
+
+                // skipped, the wrap call did not return this value
+                let mut result_step_options = StepOptions::default();
+                result_step_options.expected_results.push(ExpectedResult {
+                    result_index: 0,
+                    value: managed_buffer!(b"unexpected"),
+                });
+                let skipped_transfer_arg = with_step_options(
+                    build_simple_transfer_payments_received_from_sc_data::<DebugApi>(
+                        &other_user_address,
+                    ),
+                    result_step_options,
+                );
+
+                // skipped, the bundle does not hold this many WEGLD
+                let mut balance_step_options = StepOptions::default();
+                balance_step_options
+                    .min_balances
+                    .push(EgldOrEsdtTokenPayment::new(
+                        EgldOrEsdtTokenIdentifier::esdt(managed_token_id!(WEGLD_TOKEN_ID)),
+                        0,
+                        managed_biguint!(transfer_amount + 1),
+                    ));
+                let other_skipped_transfer_arg = with_step_options(
+                    build_simple_transfer_payments_received_from_sc_data::<DebugApi>(
+                        &other_user_address,
+                    ),
+                    balance_step_options,
+                );
+
+                let mut all_args = MultiValueEncoded::new();
+                all_args.push(wrap_arg);
+                all_args.push(skipped_transfer_arg);
+                all_args.push(other_skipped_transfer_arg);
+
+                sc.multi_call_with_options(all_args);
+            },
+        )
+        .assert_ok();
+
+    // the caller gets the unused WEGLD back
+    setup.b_mock.check_esdt_balance(
+        &setup.user_address,
+        WEGLD_TOKEN_ID,
+        &rust_biguint!(transfer_amount),
+    );
+    setup
+        .b_mock
+        .check_esdt_balance(&other_user_address, WEGLD_TOKEN_ID, &rust_biguint!(0));
+}
+
+#[test]
+fn min_output_test() {
+    let mut setup = GenericCompTasksSetup::new(
+        generic_composable_tasks::contract_obj,
+        multiversx_wegld_swap_sc::contract_obj,
+    );
+
+    let transfer_amount = 100;
+    let dest_sc_address = setup.wegld_swap_wrapper.address_ref().clone();
+
+    let build_wrap_arg = |min_output: u64| {
+        let mut step_options = StepOptions::default();
+        step_options.min_outputs.push(EgldOrEsdtTokenPayment::new(
+            EgldOrEsdtTokenIdentifier::esdt(managed_token_id!(WEGLD_TOKEN_ID)),
+            0,
+            managed_biguint!(min_output),
+        ));
+
+        with_step_options(
+            build_sync_call_egld_transfer_data::<DebugApi>(
+                &dest_sc_address,
+                transfer_amount,
+                WRAP_EGLD_ENDPOINT_NAME,
+                Vec::new(),
+            ),
+            step_options,
+        )
+    };
+
+    setup
+        .b_mock
+        .execute_tx(
+            &setup.user_address,
+            &setup.tasks_wrapper,
+            &rust_biguint!(transfer_amount),
+            |sc| {
+                let mut all_args = MultiValueEncoded::new();
+                all_args.push(build_wrap_arg(transfer_amount + 1));
+
+                sc.multi_call_with_options(all_args);
+            },
+        )
+        .assert_user_error("Minimum output not reached");
+
+    setup
+        .b_mock
+        .check_egld_balance(&setup.user_address, &rust_biguint!(USER_BALANCE));
+
+    setup
+        .b_mock
+        .execute_tx(
+            &setup.user_address,
+            &setup.tasks_wrapper,
+            &rust_biguint!(transfer_amount),
+            |sc| {
+                let mut all_args = MultiValueEncoded::new();
+                all_args.push(build_wrap_arg(transfer_amount));
+
+                sc.multi_call_with_options(all_args);
+            },
+        )
+        .assert_ok();
+
+    setup.b_mock.check_esdt_balance(
+        &setup.user_address,
+        WEGLD_TOKEN_ID,
+        &rust_biguint!(transfer_amount),
+    );
+}
+
+#[test]
+fn percentage_and_all_of_token_payments_test() {
+    let mut setup = GenericCompTasksSetup::new(
+        generic_composable_tasks::contract_obj,
+        multiversx_wegld_swap_sc::contract_obj,
+    );
+
+    let transfer_amount = 100;
+    let dest_sc_address = setup.wegld_swap_wrapper.address_ref().clone();
+    let other_user_address = setup.other_user_address.clone();
+
+    setup
+        .b_mock
+        .execute_tx(
+            &setup.user_address,
+            &setup.tasks_wrapper,
+            &rust_biguint!(transfer_amount),
+            |sc| {
+                let wrap_arg = build_sync_call_egld_transfer_data::<DebugApi>(
+                    &dest_sc_address,
+                    transfer_amount,
+                    WRAP_EGLD_ENDPOINT_NAME,
+                    Vec::new(),
+                );
+                // 25% of the wrapped amount
+                let percentage_arg = build_simple_transfer_from_balance_data::<DebugApi>(
+                    &other_user_address,
+                    PaymentType::PercentageOfBalance {
+                        token: EgldOrEsdtTokenIdentifier::esdt(managed_token_id!(WEGLD_TOKEN_ID)),
+                        bps: 2_500,
+                    },
+                );
+                // the remaining 75%
+                let all_arg = build_simple_transfer_from_balance_data::<DebugApi>(
+                    &other_user_address,
+                    PaymentType::AllOfToken(EgldOrEsdtTokenIdentifier::esdt(managed_token_id!(
+                        WEGLD_TOKEN_ID
+                    ))),
+                );
+
+                let mut all_args = MultiValueEncoded::new();
+                all_args.push(wrap_arg);
+                all_args.push(percentage_arg);
+                all_args.push(all_arg);
+
+                sc.multi_call(all_args);
+            },
+        )
+        .assert_ok();
+
+    setup
+        .b_mock
+        .check_esdt_balance(&setup.user_address, WEGLD_TOKEN_ID, &rust_biguint!(0));
+    setup.b_mock.check_esdt_balance(
+        &other_user_address,
+        WEGLD_TOKEN_ID,
+        &rust_biguint!(transfer_amount),
+    );
+
+    // nothing left to send
+    setup
+        .b_mock
+        .execute_tx(
+            &setup.user_address,
+            &setup.tasks_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let all_arg = build_simple_transfer_from_balance_data::<DebugApi>(
+                    &other_user_address,
+                    PaymentType::AllOfToken(EgldOrEsdtTokenIdentifier::esdt(managed_token_id!(
+                        WEGLD_TOKEN_ID
+                    ))),
+                );
+
+                let mut all_args = MultiValueEncoded::new();
+                all_args.push(all_arg);
+
+                sc.multi_call(all_args);
+            },
+        )
+        .assert_user_error("No balance for token");
+}
+
+#[test]
+fn sync_call_then_use_received_egld_test() {
+    let mut setup = GenericCompTasksSetup::new(
+        generic_composable_tasks::contract_obj,
+        multiversx_wegld_swap_sc::contract_obj,
+    );
+
+    let transfer_amount = 100;
+    let dest_sc_address = setup.wegld_swap_wrapper.address_ref().clone();
+    let other_user_address = setup.other_user_address.clone();
+
+    setup
+        .b_mock
+        .execute_tx(
+            &setup.user_address,
+            &setup.wegld_swap_wrapper,
+            &rust_biguint!(transfer_amount),
+            |sc| {
+                let _ = sc.wrap_egld();
+            },
+        )
+        .assert_ok();
+
+    setup
+        .b_mock
+        .execute_esdt_transfer(
+            &setup.user_address,
+            &setup.tasks_wrapper,
+            WEGLD_TOKEN_ID,
+            0,
+            &rust_biguint!(transfer_amount),
+            |sc| {
+                let unwrap_arg = build_sync_call_esdt_transfer_data::<DebugApi>(
+                    &dest_sc_address,
+                    vec![TxTokenTransfer {
+                        token_identifier: WEGLD_TOKEN_ID.to_vec(),
+                        nonce: 0,
+                        value: rust_biguint!(transfer_amount),
+                    }],
+                    UNWRAP_EGLD_ENDPOINT_NAME,
+                    Vec::new(),
+                );
+                let transfer_arg = build_simple_transfer_payments_received_from_sc_data::<DebugApi>(
+                    &other_user_address,
+                );
+
+                let mut all_args = MultiValueEncoded::new();
+                all_args.push(unwrap_arg);
+                all_args.push(transfer_arg);
+
+                sc.multi_call(all_args);
+            },
+        )
+        .assert_ok();
+
+    setup.b_mock.check_egld_balance(
+        &setup.user_address,
+        &rust_biguint!(USER_BALANCE - transfer_amount),
+    );
+    setup.b_mock.check_egld_balance(
+        &other_user_address,
+        &rust_biguint!(USER_BALANCE + transfer_amount),
+    );
+}
+
+#[test]
+fn sync_call_then_use_received_egld_and_esdt_test() {
+    let mut setup = GenericCompTasksSetup::new(
+        generic_composable_tasks::contract_obj,
+        multiversx_wegld_swap_sc::contract_obj,
+    );
+
+    // a second bundle contract, which wraps half of the EGLD and sends back both tokens
+    let inner_tasks_wrapper = setup.b_mock.create_sc_account(
+        &rust_biguint!(0),
+        Some(&setup.owner_address),
+        generic_composable_tasks::contract_obj,
+        "inner generic composable tasks",
+    );
+    setup
+        .b_mock
+        .execute_tx(
+            &setup.owner_address,
+            &inner_tasks_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.init();
+            },
+        )
+        .assert_ok();
+
+    let transfer_amount = 100;
+    let wrap_amount = 40;
+    let inner_sc_address = inner_tasks_wrapper.address_ref().clone();
+    let wegld_sc_address = setup.wegld_swap_wrapper.address_ref().clone();
+    let other_user_address = setup.other_user_address.clone();
+
+    setup
+        .b_mock
+        .execute_tx(
+            &setup.user_address,
+            &setup.tasks_wrapper,
+            &rust_biguint!(transfer_amount),
+            |sc| {
+                let inner_wrap_arg = build_sync_call_egld_transfer_data::<DebugApi>(
+                    &wegld_sc_address,
+                    wrap_amount,
+                    WRAP_EGLD_ENDPOINT_NAME,
+                    Vec::new(),
+                );
+                let (dest_address, payment_type, call_type, gas_limit, opt_exec_arg) =
+                    inner_wrap_arg.into_tuple();
+                let inner_multi_call_args = vec![
+                    top_encode_to_vec_u8_or_panic(&dest_address),
+                    top_encode_to_vec_u8_or_panic(&payment_type),
+                    top_encode_to_vec_u8_or_panic(&call_type),
+                    top_encode_to_vec_u8_or_panic(&gas_limit),
+                    top_encode_to_vec_u8_or_panic(&opt_exec_arg),
+                ];
+
+                let multi_call_arg = build_sync_call_egld_transfer_data::<DebugApi>(
+                    &inner_sc_address,
+                    transfer_amount,
+                    b"multiCall",
+                    inner_multi_call_args,
+                );
+                let transfer_arg = build_simple_transfer_payments_received_from_sc_data::<DebugApi>(
+                    &other_user_address,
+                );
+
+                let mut all_args = MultiValueEncoded::new();
+                all_args.push(multi_call_arg);
+                all_args.push(transfer_arg);
+
+                sc.multi_call(all_args);
+            },
+        )
+        .assert_ok();
+
+    setup.b_mock.check_egld_balance(
+        &setup.user_address,
+        &rust_biguint!(USER_BALANCE - transfer_amount),
+    );
+    setup.b_mock.check_egld_balance(
+        &other_user_address,
+        &rust_biguint!(USER_BALANCE + transfer_amount - wrap_amount),
+    );
+    setup.b_mock.check_esdt_balance(
+        &other_user_address,
+        WEGLD_TOKEN_ID,
+        &rust_biguint!(wrap_amount),
+    );
+    setup
+        .b_mock
+        .check_egld_balance(setup.tasks_wrapper.address_ref(), &rust_biguint!(0));
+}
+
+static DCA_TEMPLATE_NAME: &[u8] = b"dca";
+
+#[test]
+fn task_template_delegated_execution_test() {
+    let mut setup = GenericCompTasksSetup::new(
+        generic_composable_tasks::contract_obj,
+        multiversx_wegld_swap_sc::contract_obj,
+    );
+
+    let deposit_amount = 500;
+    let transfer_amount = 100;
+    let expiry = 100;
+    let dest_sc_address = setup.wegld_swap_wrapper.address_ref().clone();
+    let owner_address = setup.user_address.clone();
+    let executor_address = setup.other_user_address.clone();
+
+    setup
+        .b_mock
+        .execute_tx(
+            &owner_address,
+            &setup.tasks_wrapper,
+            &rust_biguint!(deposit_amount),
+            |sc| {
+                let wrap_arg = build_sync_call_egld_transfer_data::<DebugApi>(
+                    &dest_sc_address,
+                    transfer_amount,
+                    WRAP_EGLD_ENDPOINT_NAME,
+                    Vec::new(),
+                );
+
+                let mut steps = MultiValueEncoded::new();
+                steps.push(with_step_options(wrap_arg, StepOptions::default()));
+                sc.register_task_template(
+                    managed_buffer!(DCA_TEMPLATE_NAME),
+                    ManagedVec::new(),
+                    steps,
+                );
+
+                sc.deposit_task_funds();
+
+                let mut allowed_destinations = ManagedVec::new();
+                allowed_destinations.push(managed_address!(&dest_sc_address));
+                let mut max_spend = ManagedVec::new();
+                max_spend.push(EgldOrEsdtTokenPayment::new(
+                    EgldOrEsdtTokenIdentifier::egld(),
+                    0,
+                    managed_biguint!(transfer_amount * 3 / 2),
+                ));
+                sc.authorize_executor(
+                    managed_address!(&executor_address),
+                    expiry,
+                    allowed_destinations,
+                    max_spend,
+                );
+            },
+        )
+        .assert_ok();
+
+    // a keeper bot runs the template on behalf of the owner
+    setup
+        .b_mock
+        .execute_tx(
+            &executor_address,
+            &setup.tasks_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.execute_task_template(
+                    managed_address!(&owner_address),
+                    managed_buffer!(DCA_TEMPLATE_NAME),
+                    MultiValueEncoded::new(),
+                );
+
+                assert_eq!(
+                    sc.task_funds_egld(&managed_address!(&owner_address)).get(),
+                    managed_biguint!(deposit_amount - transfer_amount)
+                );
+            },
+        )
+        .assert_ok();
+
+    // second run would exceed the spending limit
+    setup
+        .b_mock
+        .execute_tx(
+            &executor_address,
+            &setup.tasks_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.execute_task_template(
+                    managed_address!(&owner_address),
+                    managed_buffer!(DCA_TEMPLATE_NAME),
+                    MultiValueEncoded::new(),
+                );
+            },
+        )
+        .assert_user_error("Spending limit exceeded");
+
+    // the owner is not limited
+    setup
+        .b_mock
+        .execute_tx(
+            &owner_address,
+            &setup.tasks_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.execute_task_template(
+                    managed_address!(&owner_address),
+                    managed_buffer!(DCA_TEMPLATE_NAME),
+                    MultiValueEncoded::new(),
+                );
+            },
+        )
+        .assert_ok();
+
+    setup.b_mock.set_block_timestamp(expiry + 1);
+    setup
+        .b_mock
+        .execute_tx(
+            &executor_address,
+            &setup.tasks_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.execute_task_template(
+                    managed_address!(&owner_address),
+                    managed_buffer!(DCA_TEMPLATE_NAME),
+                    MultiValueEncoded::new(),
+                );
+            },
+        )
+        .assert_user_error("Permission expired");
+
+    // the wrapped tokens stay in the owner's task funds until withdrawn
+    setup
+        .b_mock
+        .execute_tx(
+            &owner_address,
+            &setup.tasks_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.withdraw_task_funds();
+            },
+        )
+        .assert_ok();
+
+    setup.b_mock.check_egld_balance(
+        &owner_address,
+        &rust_biguint!(USER_BALANCE - 2 * transfer_amount),
+    );
+    setup.b_mock.check_esdt_balance(
+        &owner_address,
+        WEGLD_TOKEN_ID,
+        &rust_biguint!(2 * transfer_amount),
+    );
+}
+
+#[test]
+fn task_template_params_test() {
+    let mut setup = GenericCompTasksSetup::new(
+        generic_composable_tasks::contract_obj,
+        multiversx_wegld_swap_sc::contract_obj,
+    );
+
+    let initial_sum = 5u64;
+    let added_value = 7u64;
+    let owner_address = setup.user_address.clone();
+    let executor_address = setup.other_user_address.clone();
+
+    let adder_wrapper = setup.b_mock.create_sc_account(
+        &rust_biguint!(0),
+        Some(&setup.owner_address),
+        adder::contract_obj,
+        "adder",
+    );
+    setup
+        .b_mock
+        .execute_tx(
+            &setup.owner_address,
+            &adder_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.init(managed_biguint!(initial_sum));
+            },
+        )
+        .assert_ok();
+    let adder_sc_address = adder_wrapper.address_ref().clone();
+    let wegld_sc_address = setup.wegld_swap_wrapper.address_ref().clone();
+
+    setup
+        .b_mock
+        .execute_tx(
+            &owner_address,
+            &setup.tasks_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let add_arg = build_sync_call_no_payment_data::<DebugApi>(
+                    &adder_sc_address,
+                    b"add",
+                    vec![Vec::new()],
+                );
+
+                let mut param_slots = ManagedVec::new();
+                param_slots.push(ParamSlot {
+                    step_index: 0,
+                    arg_index: 0,
+                });
+
+                let mut steps = MultiValueEncoded::new();
+                steps.push(with_step_options(add_arg, StepOptions::default()));
+                sc.register_task_template(managed_buffer!(DCA_TEMPLATE_NAME), param_slots, steps);
+
+                // only the wegld swap SC may be called
+                let mut allowed_destinations = ManagedVec::new();
+                allowed_destinations.push(managed_address!(&wegld_sc_address));
+                sc.authorize_executor(
+                    managed_address!(&executor_address),
+                    100,
+                    allowed_destinations,
+                    ManagedVec::new(),
+                );
+            },
+        )
+        .assert_ok();
+
+    setup
+        .b_mock
+        .execute_tx(
+            &executor_address,
+            &setup.tasks_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut params = MultiValueEncoded::new();
+                params.push(managed_biguint!(added_value).to_bytes_be_buffer());
+
+                sc.execute_task_template(
+                    managed_address!(&owner_address),
+                    managed_buffer!(DCA_TEMPLATE_NAME),
+                    params,
+                );
+            },
+        )
+        .assert_user_error("Destination not allowed");
+
+    setup
+        .b_mock
+        .execute_tx(
+            &owner_address,
+            &setup.tasks_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut params = MultiValueEncoded::new();
+                params.push(managed_biguint!(added_value).to_bytes_be_buffer());
+
+                sc.execute_task_template(
+                    managed_address!(&owner_address),
+                    managed_buffer!(DCA_TEMPLATE_NAME),
+                    params,
+                );
+            },
+        )
+        .assert_ok();
+
+    setup
+        .b_mock
+        .execute_query(&adder_wrapper, |sc| {
+            assert_eq!(sc.sum().get(), managed_biguint!(initial_sum + added_value));
+        })
+        .assert_ok();
+}
@@ -6,10 +6,10 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                            4
+// Endpoints:                           18
 // Async Callback (empty):               1
 // Promise callbacks:                    1
-// Total number of exported functions:   8
+// Total number of exported functions:  22
 
 #![no_std]
 
@@ -22,6 +22,20 @@ multiversx_sc_wasm_adapter::endpoints! {
         init => init
         upgrade => upgrade
         multiCall => multi_call
+        multiCallWithOptions => multi_call_with_options
+        registerTaskTemplate => register_task_template
+        removeTaskTemplate => remove_task_template
+        authorizeExecutor => authorize_executor
+        revokeExecutor => revoke_executor
+        depositTaskFunds => deposit_task_funds
+        withdrawTaskFunds => withdraw_task_funds
+        executeTaskTemplate => execute_task_template
+        getTemplateNames => template_names
+        getTemplateSteps => template_steps
+        getTemplateParamSlots => template_param_slots
+        getExecutorPermission => executor_permission
+        getTaskFundsEgld => task_funds_egld
+        getTaskFundsEsdt => task_funds_esdt
         pause => pause_endpoint
         unpause => unpause_endpoint
         isPaused => paused_status
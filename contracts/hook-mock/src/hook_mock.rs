@@ -0,0 +1,82 @@
+#![no_std]
+
+use multiversx_sc::imports::*;
+
+/// Records the calls received from the ERC-3643 hooks
+#[multiversx_sc::contract]
+pub trait HookMock {
+    #[init]
+    fn init(&self, fee_percentage: u32) {
+        require!(fee_percentage <= 100, "Invalid fee percentage");
+
+        self.fee_percentage().set(fee_percentage);
+    }
+
+    /// BeforeTransfer hook: keeps a percentage of the payments and sends the rest back
+    #[payable]
+    #[endpoint(takeFee)]
+    fn take_fee(&self, _original_caller: ManagedAddress, _args: MultiValueEncoded<ManagedBuffer>) {
+        let fee_percentage = self.fee_percentage().get();
+        let mut payments_after_fee = ManagedVec::<Self::Api, EsdtTokenPayment>::new();
+        for payment in self.call_value().all_esdt_transfers().iter() {
+            let fee = &payment.amount * fee_percentage / 100u32;
+            payments_after_fee.push(EsdtTokenPayment::new(
+                payment.token_identifier.clone(),
+                payment.token_nonce,
+                &payment.amount - &fee,
+            ));
+        }
+
+        self.tx()
+            .to(ToCaller)
+            .payment(payments_after_fee)
+            .transfer();
+    }
+
+    #[endpoint(afterTransfer)]
+    fn after_transfer(
+        &self,
+        original_caller: ManagedAddress,
+        dest: ManagedAddress,
+        payments: ManagedVec<EsdtTokenPayment>,
+    ) {
+        for payment in payments.iter() {
+            self.received_amount(&original_caller, &dest)
+                .update(|amount| *amount += &payment.amount);
+        }
+    }
+
+    #[endpoint(beforeInitialize)]
+    fn before_initialize(
+        &self,
+        _token_display_name: ManagedBuffer,
+        token_ticker: ManagedBuffer,
+        _num_decimals: usize,
+    ) {
+        self.token_ticker().set(token_ticker);
+    }
+
+    #[endpoint(afterInitialize)]
+    fn after_initialize(&self, token_id: TokenIdentifier) {
+        self.token_id().set(token_id);
+    }
+
+    #[storage_mapper("feePercentage")]
+    fn fee_percentage(&self) -> SingleValueMapper<u32>;
+
+    #[view(getReceivedAmount)]
+    #[storage_mapper("receivedAmount")]
+    fn received_amount(
+        &self,
+        original_caller: &ManagedAddress,
+        dest: &ManagedAddress,
+    ) -> SingleValueMapper<BigUint>;
+
+    #[view(getTokenTicker)]
+    #[storage_mapper("tokenTicker")]
+    fn token_ticker(&self) -> SingleValueMapper<ManagedBuffer>;
+
+    #[view(getTokenId)]
+    #[storage_mapper("tokenId")]
+    fn token_id(&self) -> SingleValueMapper<TokenIdentifier>;
+}
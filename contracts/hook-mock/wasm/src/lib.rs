@@ -0,0 +1,31 @@
+// Code generated by the multiversx-sc build system. DO NOT EDIT.
+
+////////////////////////////////////////////////////
+////////////////// AUTO-GENERATED //////////////////
+////////////////////////////////////////////////////
+
+// Init:                                 1
+// Endpoints:                            7
+// Async Callback (empty):               1
+// Total number of exported functions:   9
+
+#![no_std]
+
+multiversx_sc_wasm_adapter::allocator!();
+multiversx_sc_wasm_adapter::panic_handler!();
+
+multiversx_sc_wasm_adapter::endpoints! {
+    hook_mock
+    (
+        init => init
+        takeFee => take_fee
+        afterTransfer => after_transfer
+        beforeInitialize => before_initialize
+        afterInitialize => after_initialize
+        getReceivedAmount => received_amount
+        getTokenTicker => token_ticker
+        getTokenId => token_id
+    )
+}
+
+multiversx_sc_wasm_adapter::async_callback_empty! {}
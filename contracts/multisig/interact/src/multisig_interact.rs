@@ -1,6 +1,8 @@
+mod multisig_interact_actions;
 mod multisig_interact_cli;
 mod multisig_interact_config;
 mod multisig_interact_nfts;
+mod multisig_interact_signatures;
 mod multisig_interact_state;
 mod multisig_interact_wegld;
 mod wegld_proxy;
@@ -23,12 +25,39 @@ async fn main() {
 
     let cli = multisig_interact_cli::InteractCli::parse();
     match &cli.command {
+        Some(multisig_interact_cli::InteractCliCommand::ActionEncode(args)) => {
+            multisig_interact.print_encoded_actions(&args.file);
+        }
+        Some(multisig_interact_cli::InteractCliCommand::ActionList) => {
+            multisig_interact.print_pending_actions().await;
+        }
+        Some(multisig_interact_cli::InteractCliCommand::ActionPerform(args)) => {
+            multisig_interact
+                .perform_action_or_batch(args.id, args.batch, args.gas)
+                .await;
+        }
+        Some(multisig_interact_cli::InteractCliCommand::ActionPropose(args)) => {
+            multisig_interact.propose_from_file(&args.file).await;
+        }
+        Some(multisig_interact_cli::InteractCliCommand::ActionSign(args)) => {
+            multisig_interact
+                .sign_action_or_batch(args.id, args.batch, args.pem.as_deref())
+                .await;
+        }
+        Some(multisig_interact_cli::InteractCliCommand::ActionUnsign(args)) => {
+            multisig_interact
+                .unsign_action_or_batch(args.id, args.batch, args.pem.as_deref())
+                .await;
+        }
         Some(multisig_interact_cli::InteractCliCommand::Board) => {
             multisig_interact.print_board().await;
         }
         Some(multisig_interact_cli::InteractCliCommand::Deploy) => {
             multisig_interact.deploy().await;
         }
+        Some(multisig_interact_cli::InteractCliCommand::DeployView) => {
+            multisig_interact.deploy_view().await;
+        }
         Some(multisig_interact_cli::InteractCliCommand::DnsRegister(args)) => {
             multisig_interact.dns_register(&args.name).await;
         }
@@ -61,6 +90,20 @@ async fn main() {
         Some(multisig_interact_cli::InteractCliCommand::Quorum) => {
             multisig_interact.print_quorum().await;
         }
+        Some(multisig_interact_cli::InteractCliCommand::SignaturesPerform(args)) => {
+            multisig_interact.signatures_perform(&args.file).await;
+        }
+        Some(multisig_interact_cli::InteractCliCommand::SignaturesSign(args)) => {
+            multisig_interact
+                .signatures_sign(
+                    &args.pem,
+                    args.action.as_deref(),
+                    args.nonce,
+                    args.expiry,
+                    &args.file,
+                )
+                .await;
+        }
         Some(multisig_interact_cli::InteractCliCommand::UnwrapEgld) => {
             multisig_interact.unwrap_egld().await;
         }
@@ -79,6 +122,7 @@ struct MultisigInteract {
     wallet_address: Bech32Address,
     collection_token_identifier: String,
     multisig_code: BytesValue,
+    multisig_view_code: BytesValue,
     config: Config,
     state: State,
 }
@@ -97,12 +141,17 @@ impl MultisigInteract {
             "mxsc:../output/multisig.mxsc.json",
             &InterpreterContext::default(),
         );
+        let multisig_view_code = BytesValue::interpret_from(
+            "mxsc:../output/multisig-view.mxsc.json",
+            &InterpreterContext::default(),
+        );
 
         Self {
             interactor,
             wallet_address: wallet_address.into(),
             collection_token_identifier: String::new(),
             multisig_code,
+            multisig_view_code,
             config,
             state: State::load_state(),
         }
@@ -155,6 +204,27 @@ impl MultisigInteract {
         self.state.set_multisig_address(new_address);
     }
 
+    /// Deploys the external view contract, which serves the views of the current multisig
+    /// that did not fit in the main contract, such as the pending action list.
+    async fn deploy_view(&mut self) {
+        let multisig_address = self.state.current_multisig_address().clone();
+        let new_address = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .raw_deploy()
+            .argument(&multisig_address)
+            .code(&self.multisig_view_code)
+            .gas(NumExpr("60,000,000"))
+            .returns(ReturnsNewBech32Address)
+            .run()
+            .await;
+
+        println!("new view address: {new_address}");
+
+        self.state.set_multisig_view_address(new_address);
+    }
+
     async fn multi_deploy(&mut self, count: &u8) {
         if *count == 0 {
             println!("count must be greater than 0");
@@ -0,0 +1,1306 @@
+use std::path::Path;
+
+use multisig::multisig_proxy::{
+    Action, ActionType, CallActionData, EsdtOperation, EsdtTransferExecuteData, SpendingLimit,
+};
+use multiversx_sc_snippets::{hex, imports::*, sdk::wallet::Wallet};
+use serde::Deserialize;
+
+use super::*;
+
+const PROPOSE_GAS: u64 = 30_000_000;
+const SIGN_GAS: u64 = 15_000_000;
+
+/// Actions to propose, read from a JSON or TOML file.
+/// A single action is proposed on its own, several actions are proposed as a batch.
+#[derive(Debug, Deserialize)]
+pub struct ActionFile {
+    /// Block timestamp after which the action can no longer be signed or performed.
+    /// Batches can not have one.
+    #[serde(default)]
+    pub expiry: Option<u64>,
+    pub actions: Vec<ActionDefinition>,
+}
+
+/// A multisig action, in a human-writable form.
+/// Amounts are strings of base units, arguments are hex or `str:` prefixed strings.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ActionDefinition {
+    AddBoardMember {
+        address: Bech32Address,
+    },
+    AddProposer {
+        address: Bech32Address,
+    },
+    RemoveUser {
+        address: Bech32Address,
+    },
+    ChangeQuorum {
+        quorum: usize,
+    },
+    TransferEgld {
+        to: Bech32Address,
+        #[serde(default)]
+        amount: String,
+        #[serde(default)]
+        gas_limit: Option<u64>,
+        #[serde(default)]
+        endpoint: String,
+        #[serde(default)]
+        arguments: Vec<String>,
+    },
+    TransferEsdt {
+        to: Bech32Address,
+        tokens: Vec<TokenDefinition>,
+        #[serde(default)]
+        gas_limit: Option<u64>,
+        #[serde(default)]
+        endpoint: String,
+        #[serde(default)]
+        arguments: Vec<String>,
+    },
+    AsyncCall {
+        to: Bech32Address,
+        #[serde(default)]
+        amount: String,
+        #[serde(default)]
+        gas_limit: Option<u64>,
+        endpoint: String,
+        #[serde(default)]
+        arguments: Vec<String>,
+    },
+    SyncCall {
+        to: Bech32Address,
+        #[serde(default)]
+        amount: String,
+        #[serde(default)]
+        gas_limit: Option<u64>,
+        endpoint: String,
+        #[serde(default)]
+        arguments: Vec<String>,
+    },
+    DeployFromSource {
+        #[serde(default)]
+        amount: String,
+        source: Bech32Address,
+        #[serde(default)]
+        code_metadata: Vec<String>,
+        #[serde(default)]
+        arguments: Vec<String>,
+    },
+    UpgradeFromSource {
+        sc_address: Bech32Address,
+        #[serde(default)]
+        amount: String,
+        source: Bech32Address,
+        #[serde(default)]
+        code_metadata: Vec<String>,
+        #[serde(default)]
+        arguments: Vec<String>,
+    },
+    ChangeTimelockDelay {
+        delay: u64,
+    },
+    /// Without a delay, the action type goes back to the default timelock delay.
+    ChangeActionTypeTimelockDelay {
+        action_type: String,
+        #[serde(default)]
+        delay: Option<u64>,
+    },
+    /// Without a quorum, the action type goes back to the default quorum.
+    ChangeActionTypeQuorum {
+        action_type: String,
+        #[serde(default)]
+        quorum: Option<usize>,
+    },
+    ChangeSignerWeight {
+        address: Bech32Address,
+        weight: usize,
+    },
+    SetSpendingLimit {
+        token: String,
+        amount: String,
+        period: u64,
+    },
+    RemoveSpendingLimit {
+        token: String,
+    },
+    IssueFungible {
+        issue_cost: String,
+        name: String,
+        ticker: String,
+        #[serde(default)]
+        initial_supply: String,
+        num_decimals: usize,
+    },
+    SetSpecialRoles {
+        token: String,
+        address: Bech32Address,
+        roles: Vec<String>,
+    },
+    LocalMint {
+        token: String,
+        amount: String,
+    },
+    LocalBurn {
+        token: String,
+        amount: String,
+    },
+    Pause {
+        token: String,
+    },
+    Unpause {
+        token: String,
+    },
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct TokenDefinition {
+    pub token: String,
+    #[serde(default)]
+    pub nonce: u64,
+    pub amount: String,
+}
+
+impl ActionFile {
+    // Deserializes actions from a TOML file, or from a JSON file otherwise
+    pub fn load(file_path: &str) -> Self {
+        let content = std::fs::read_to_string(file_path).unwrap();
+        let is_toml = Path::new(file_path)
+            .extension()
+            .is_some_and(|extension| extension == "toml");
+        Self::parse(&content, is_toml)
+    }
+
+    fn parse(content: &str, is_toml: bool) -> Self {
+        if is_toml {
+            toml::from_str(content).unwrap()
+        } else {
+            serde_json::from_str(content).unwrap()
+        }
+    }
+}
+
+impl ActionDefinition {
+    pub fn to_action(&self) -> Action<StaticApi> {
+        match self {
+            ActionDefinition::AddBoardMember { address } => {
+                Action::AddBoardMember(address.to_address().into())
+            }
+            ActionDefinition::AddProposer { address } => {
+                Action::AddProposer(address.to_address().into())
+            }
+            ActionDefinition::RemoveUser { address } => {
+                Action::RemoveUser(address.to_address().into())
+            }
+            ActionDefinition::ChangeQuorum { quorum } => Action::ChangeQuorum(*quorum),
+            ActionDefinition::TransferEgld {
+                to,
+                amount,
+                gas_limit,
+                endpoint,
+                arguments,
+            } => Action::SendTransferExecuteEgld(call_action_data(
+                to, amount, gas_limit, endpoint, arguments,
+            )),
+            ActionDefinition::TransferEsdt {
+                to,
+                tokens,
+                gas_limit,
+                endpoint,
+                arguments,
+            } => Action::SendTransferExecuteEsdt(EsdtTransferExecuteData {
+                to: to.to_address().into(),
+                tokens: parse_tokens(tokens),
+                opt_gas_limit: *gas_limit,
+                endpoint_name: endpoint.as_str().into(),
+                arguments: parse_arguments(arguments),
+            }),
+            ActionDefinition::AsyncCall {
+                to,
+                amount,
+                gas_limit,
+                endpoint,
+                arguments,
+            } => {
+                Action::SendAsyncCall(call_action_data(to, amount, gas_limit, endpoint, arguments))
+            }
+            ActionDefinition::SyncCall {
+                to,
+                amount,
+                gas_limit,
+                endpoint,
+                arguments,
+            } => Action::SendSyncCall(call_action_data(to, amount, gas_limit, endpoint, arguments)),
+            ActionDefinition::DeployFromSource {
+                amount,
+                source,
+                code_metadata,
+                arguments,
+            } => Action::SCDeployFromSource {
+                amount: parse_amount(amount),
+                source: source.to_address().into(),
+                code_metadata: parse_code_metadata(code_metadata),
+                arguments: parse_arguments(arguments),
+            },
+            ActionDefinition::UpgradeFromSource {
+                sc_address,
+                amount,
+                source,
+                code_metadata,
+                arguments,
+            } => Action::SCUpgradeFromSource {
+                sc_address: sc_address.to_address().into(),
+                amount: parse_amount(amount),
+                source: source.to_address().into(),
+                code_metadata: parse_code_metadata(code_metadata),
+                arguments: parse_arguments(arguments),
+            },
+            ActionDefinition::ChangeTimelockDelay { delay } => Action::ChangeTimelockDelay(*delay),
+            ActionDefinition::ChangeActionTypeTimelockDelay { action_type, delay } => {
+                Action::ChangeActionTypeTimelockDelay {
+                    action_type: parse_action_type(action_type),
+                    opt_new_delay: *delay,
+                }
+            }
+            ActionDefinition::ChangeActionTypeQuorum {
+                action_type,
+                quorum,
+            } => Action::ChangeActionTypeQuorum {
+                action_type: parse_action_type(action_type),
+                opt_new_quorum: *quorum,
+            },
+            ActionDefinition::ChangeSignerWeight { address, weight } => {
+                Action::ChangeSignerWeight {
+                    board_member: address.to_address().into(),
+                    new_weight: *weight,
+                }
+            }
+            ActionDefinition::SetSpendingLimit {
+                token,
+                amount,
+                period,
+            } => Action::ChangeSpendingLimit {
+                token_id: parse_egld_or_esdt_token(token),
+                opt_limit: Some(SpendingLimit {
+                    amount: parse_amount(amount),
+                    period: *period,
+                }),
+            },
+            ActionDefinition::RemoveSpendingLimit { token } => Action::ChangeSpendingLimit {
+                token_id: parse_egld_or_esdt_token(token),
+                opt_limit: None,
+            },
+            ActionDefinition::IssueFungible {
+                issue_cost,
+                name,
+                ticker,
+                initial_supply,
+                num_decimals,
+            } => Action::ManageEsdt(EsdtOperation::IssueFungible {
+                issue_cost: parse_amount(issue_cost),
+                token_display_name: name.as_str().into(),
+                token_ticker: ticker.as_str().into(),
+                initial_supply: parse_amount(initial_supply),
+                num_decimals: *num_decimals,
+            }),
+            ActionDefinition::SetSpecialRoles {
+                token,
+                address,
+                roles,
+            } => Action::ManageEsdt(EsdtOperation::SetSpecialRoles {
+                token_id: token.as_str().into(),
+                address: address.to_address().into(),
+                roles: roles.iter().map(|role| parse_role(role)).collect(),
+            }),
+            ActionDefinition::LocalMint { token, amount } => {
+                Action::ManageEsdt(EsdtOperation::LocalMint {
+                    token_id: token.as_str().into(),
+                    amount: parse_amount(amount),
+                })
+            }
+            ActionDefinition::LocalBurn { token, amount } => {
+                Action::ManageEsdt(EsdtOperation::LocalBurn {
+                    token_id: token.as_str().into(),
+                    amount: parse_amount(amount),
+                })
+            }
+            ActionDefinition::Pause { token } => {
+                Action::ManageEsdt(EsdtOperation::Pause(token.as_str().into()))
+            }
+            ActionDefinition::Unpause { token } => {
+                Action::ManageEsdt(EsdtOperation::Unpause(token.as_str().into()))
+            }
+        }
+    }
+}
+
+fn call_action_data(
+    to: &Bech32Address,
+    amount: &str,
+    gas_limit: &Option<u64>,
+    endpoint: &str,
+    arguments: &[String],
+) -> CallActionData<StaticApi> {
+    CallActionData {
+        to: to.to_address().into(),
+        egld_amount: parse_amount(amount),
+        opt_gas_limit: *gas_limit,
+        endpoint_name: endpoint.into(),
+        arguments: parse_arguments(arguments),
+    }
+}
+
+fn parse_amount(amount: &str) -> BigUint<StaticApi> {
+    if amount.is_empty() {
+        return BigUint::zero();
+    }
+
+    let value: u128 = amount
+        .replace('_', "")
+        .parse()
+        .unwrap_or_else(|_| panic!("invalid amount `{amount}`"));
+    BigUint::from_bytes_be(&value.to_be_bytes())
+}
+
+fn parse_argument(argument: &str) -> ManagedBuffer<StaticApi> {
+    if let Some(text) = argument.strip_prefix("str:") {
+        return ManagedBuffer::from(text);
+    }
+
+    let hex_digits = argument.strip_prefix("0x").unwrap_or(argument);
+    let bytes = hex::decode(hex_digits).unwrap_or_else(|_| panic!("invalid argument `{argument}`"));
+    ManagedBuffer::from(bytes.as_slice())
+}
+
+fn parse_arguments(arguments: &[String]) -> ManagedVec<StaticApi, ManagedBuffer<StaticApi>> {
+    let mut result = ManagedVec::new();
+    for argument in arguments {
+        result.push(parse_argument(argument));
+    }
+    result
+}
+
+fn parse_tokens(tokens: &[TokenDefinition]) -> ManagedVec<StaticApi, EsdtTokenPayment<StaticApi>> {
+    let mut result = ManagedVec::new();
+    for token in tokens {
+        result.push(EsdtTokenPayment::new(
+            TokenIdentifier::from(token.token.as_str()),
+            token.nonce,
+            parse_amount(&token.amount),
+        ));
+    }
+    result
+}
+
+/// Action types are written in snake case, as the action `type` field.
+fn parse_action_type(action_type: &str) -> ActionType {
+    match action_type {
+        "add_board_member" => ActionType::AddBoardMember,
+        "add_proposer" => ActionType::AddProposer,
+        "remove_user" => ActionType::RemoveUser,
+        "change_quorum" => ActionType::ChangeQuorum,
+        "transfer_egld" => ActionType::SendTransferExecuteEgld,
+        "transfer_esdt" => ActionType::SendTransferExecuteEsdt,
+        "async_call" => ActionType::SendAsyncCall,
+        "sync_call" => ActionType::SendSyncCall,
+        "deploy_from_source" => ActionType::SCDeployFromSource,
+        "upgrade_from_source" => ActionType::SCUpgradeFromSource,
+        "change_timelock_delay" => ActionType::ChangeTimelockDelay,
+        "change_action_type_timelock_delay" => ActionType::ChangeActionTypeTimelockDelay,
+        "change_action_type_quorum" => ActionType::ChangeActionTypeQuorum,
+        "change_signer_weight" => ActionType::ChangeSignerWeight,
+        "change_spending_limit" => ActionType::ChangeSpendingLimit,
+        "manage_esdt" => ActionType::ManageEsdt,
+        _ => panic!("unknown action type `{action_type}`"),
+    }
+}
+
+fn parse_egld_or_esdt_token(token: &str) -> EgldOrEsdtTokenIdentifier<StaticApi> {
+    if token == "EGLD" {
+        EgldOrEsdtTokenIdentifier::egld()
+    } else {
+        EgldOrEsdtTokenIdentifier::esdt(token)
+    }
+}
+
+fn parse_role(role: &str) -> EsdtLocalRole {
+    match role {
+        "mint" => EsdtLocalRole::Mint,
+        "burn" => EsdtLocalRole::Burn,
+        "nft_create" => EsdtLocalRole::NftCreate,
+        "nft_add_quantity" => EsdtLocalRole::NftAddQuantity,
+        "nft_burn" => EsdtLocalRole::NftBurn,
+        "nft_update_attributes" => EsdtLocalRole::NftUpdateAttributes,
+        "nft_add_uri" => EsdtLocalRole::NftAddUri,
+        "transfer" => EsdtLocalRole::Transfer,
+        _ => panic!("unknown role `{role}`"),
+    }
+}
+
+fn parse_code_metadata(flags: &[String]) -> CodeMetadata {
+    let mut code_metadata = CodeMetadata::DEFAULT;
+    for flag in flags {
+        code_metadata |= match flag.as_str() {
+            "upgradeable" => CodeMetadata::UPGRADEABLE,
+            "readable" => CodeMetadata::READABLE,
+            "payable" => CodeMetadata::PAYABLE,
+            "payable_by_sc" => CodeMetadata::PAYABLE_BY_SC,
+            _ => panic!("unknown code metadata flag `{flag}`"),
+        };
+    }
+    code_metadata
+}
+
+/// Human-readable description of an action, with its arguments decoded where possible.
+fn describe_action(action: &Action<StaticApi>) -> String {
+    match action {
+        Action::Nothing => "nothing".to_string(),
+        Action::AddBoardMember(address) => {
+            format!("add board member {}", describe_address(address))
+        }
+        Action::AddProposer(address) => format!("add proposer {}", describe_address(address)),
+        Action::RemoveUser(address) => format!("remove user {}", describe_address(address)),
+        Action::ChangeQuorum(quorum) => format!("change quorum to {quorum}"),
+        Action::SendTransferExecuteEgld(call_data) => {
+            format!("transfer-execute {}", describe_call(call_data))
+        }
+        Action::SendTransferExecuteEsdt(call_data) => {
+            let tokens = call_data
+                .tokens
+                .iter()
+                .map(|payment| {
+                    format!(
+                        "{} {} (nonce {})",
+                        describe_amount(&payment.amount),
+                        describe_buffer(payment.token_identifier.as_managed_buffer()),
+                        payment.token_nonce
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join(", ");
+            format!(
+                "transfer-execute [{tokens}] to {}{}",
+                describe_address(&call_data.to),
+                describe_endpoint(&call_data.endpoint_name, &call_data.arguments)
+            )
+        }
+        Action::SendAsyncCall(call_data) => format!("async call {}", describe_call(call_data)),
+        Action::SendSyncCall(call_data) => format!("sync call {}", describe_call(call_data)),
+        Action::SCDeployFromSource {
+            amount,
+            source,
+            code_metadata,
+            arguments,
+        } => format!(
+            "deploy from {} with {} EGLD, code metadata {:?}, arguments [{}]",
+            describe_address(source),
+            describe_amount(amount),
+            code_metadata,
+            describe_arguments(arguments)
+        ),
+        Action::SCUpgradeFromSource {
+            sc_address,
+            amount,
+            source,
+            code_metadata,
+            arguments,
+        } => format!(
+            "upgrade {} from {} with {} EGLD, code metadata {:?}, arguments [{}]",
+            describe_address(sc_address),
+            describe_address(source),
+            describe_amount(amount),
+            code_metadata,
+            describe_arguments(arguments)
+        ),
+        Action::ChangeTimelockDelay(delay) => format!("change timelock delay to {delay}s"),
+        Action::ChangeActionTypeTimelockDelay {
+            action_type,
+            opt_new_delay,
+        } => format!("change timelock delay of {action_type:?} to {opt_new_delay:?}"),
+        Action::ChangeActionTypeQuorum {
+            action_type,
+            opt_new_quorum,
+        } => format!("change quorum of {action_type:?} to {opt_new_quorum:?}"),
+        Action::ChangeSignerWeight {
+            board_member,
+            new_weight,
+        } => format!(
+            "change signer weight of {} to {new_weight}",
+            describe_address(board_member)
+        ),
+        Action::ChangeSpendingLimit {
+            token_id,
+            opt_limit,
+        } => {
+            let token_name = describe_buffer(&token_id.clone().into_name());
+            match opt_limit {
+                Some(limit) => format!(
+                    "set spending limit of {token_name} to {} every {}s",
+                    describe_amount(&limit.amount),
+                    limit.period
+                ),
+                None => format!("remove spending limit of {token_name}"),
+            }
+        }
+        Action::ManageEsdt(operation) => describe_esdt_operation(operation),
+    }
+}
+
+fn describe_esdt_operation(operation: &EsdtOperation<StaticApi>) -> String {
+    match operation {
+        EsdtOperation::IssueFungible {
+            issue_cost,
+            token_display_name,
+            token_ticker,
+            initial_supply,
+            num_decimals,
+        } => format!(
+            "issue {} ({}) with supply {}, {num_decimals} decimals, for {} EGLD",
+            describe_buffer(token_display_name),
+            describe_buffer(token_ticker),
+            describe_amount(initial_supply),
+            describe_amount(issue_cost)
+        ),
+        EsdtOperation::SetSpecialRoles {
+            token_id,
+            address,
+            roles,
+        } => format!(
+            "set roles {:?} of {} for {}",
+            roles.iter().collect::<Vec<_>>(),
+            describe_buffer(token_id.as_managed_buffer()),
+            describe_address(address)
+        ),
+        EsdtOperation::LocalMint { token_id, amount } => format!(
+            "mint {} {}",
+            describe_amount(amount),
+            describe_buffer(token_id.as_managed_buffer())
+        ),
+        EsdtOperation::LocalBurn { token_id, amount } => format!(
+            "burn {} {}",
+            describe_amount(amount),
+            describe_buffer(token_id.as_managed_buffer())
+        ),
+        EsdtOperation::Pause(token_id) => {
+            format!("pause {}", describe_buffer(token_id.as_managed_buffer()))
+        }
+        EsdtOperation::Unpause(token_id) => {
+            format!("unpause {}", describe_buffer(token_id.as_managed_buffer()))
+        }
+    }
+}
+
+fn describe_call(call_data: &CallActionData<StaticApi>) -> String {
+    format!(
+        "{} EGLD to {}{}",
+        describe_amount(&call_data.egld_amount),
+        describe_address(&call_data.to),
+        describe_endpoint(&call_data.endpoint_name, &call_data.arguments)
+    )
+}
+
+fn describe_endpoint(
+    endpoint_name: &ManagedBuffer<StaticApi>,
+    arguments: &ManagedVec<StaticApi, ManagedBuffer<StaticApi>>,
+) -> String {
+    if endpoint_name.is_empty() {
+        return String::new();
+    }
+
+    format!(
+        ", calling `{}` with arguments [{}]",
+        describe_buffer(endpoint_name),
+        describe_arguments(arguments)
+    )
+}
+
+fn describe_amount(amount: &BigUint<StaticApi>) -> String {
+    describe_buffer(&amount.to_display())
+}
+
+fn describe_buffer(buffer: &ManagedBuffer<StaticApi>) -> String {
+    String::from_utf8_lossy(&buffer.to_vec()).into_owned()
+}
+
+fn describe_address(address: &ManagedAddress<StaticApi>) -> String {
+    bech32::encode(&address.to_address())
+}
+
+/// Shows each argument as hex, followed by its text when it is printable.
+fn describe_arguments(arguments: &ManagedVec<StaticApi, ManagedBuffer<StaticApi>>) -> String {
+    arguments
+        .iter()
+        .map(|argument| {
+            let bytes = argument.to_vec();
+            let text = String::from_utf8(bytes.clone())
+                .ok()
+                .filter(|text| !text.is_empty() && text.chars().all(|c| c.is_ascii_graphic()));
+            match text {
+                Some(text) => format!("0x{} (\"{text}\")", hex::encode(&bytes)),
+                None => format!("0x{}", hex::encode(&bytes)),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+impl MultisigInteract {
+    /// Proposes the actions in the file, as a batch if there are several.
+    pub async fn propose_from_file(&mut self, file_path: &str) {
+        let action_file = ActionFile::load(file_path);
+        match action_file.actions.as_slice() {
+            [] => println!("no actions in `{file_path}`"),
+            [definition] => {
+                let action_id = self
+                    .propose_single_action(definition.to_action(), action_file.expiry)
+                    .await;
+                println!("successfully proposed action `{action_id}`");
+            }
+            definitions => {
+                assert!(
+                    action_file.expiry.is_none(),
+                    "a batch of actions can not have an expiry"
+                );
+
+                let mut actions = MultiValueEncoded::new();
+                for definition in definitions {
+                    actions.push(definition.to_action());
+                }
+
+                let group_id = self
+                    .interactor
+                    .tx()
+                    .from(&self.wallet_address)
+                    .to(self.state.current_multisig_address())
+                    .gas(PROPOSE_GAS)
+                    .typed(multisig_proxy::MultisigProxy)
+                    .propose_batch(actions)
+                    .returns(ReturnsResult)
+                    .run()
+                    .await;
+                println!(
+                    "successfully proposed batch `{group_id}` with {} actions",
+                    definitions.len()
+                );
+            }
+        }
+    }
+
+    async fn propose_single_action(
+        &mut self,
+        action: Action<StaticApi>,
+        opt_expiry: Option<u64>,
+    ) -> usize {
+        let tx = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_multisig_address())
+            .gas(PROPOSE_GAS)
+            .typed(multisig_proxy::MultisigProxy);
+        if let Some(expiry) = opt_expiry {
+            return tx
+                .propose_with_expiry(expiry, action)
+                .returns(ReturnsResult)
+                .run()
+                .await;
+        }
+
+        match action {
+            Action::AddBoardMember(address) => {
+                tx.propose_add_board_member(address, OptionalValue::<u64>::None)
+                    .returns(ReturnsResult)
+                    .run()
+                    .await
+            }
+            Action::AddProposer(address) => {
+                tx.propose_add_proposer(address, OptionalValue::<u64>::None)
+                    .returns(ReturnsResult)
+                    .run()
+                    .await
+            }
+            Action::RemoveUser(address) => {
+                tx.propose_remove_user(address, OptionalValue::<u64>::None)
+                    .returns(ReturnsResult)
+                    .run()
+                    .await
+            }
+            Action::ChangeQuorum(quorum) => {
+                tx.propose_change_quorum(quorum, OptionalValue::<u64>::None)
+                    .returns(ReturnsResult)
+                    .run()
+                    .await
+            }
+            Action::SendTransferExecuteEgld(call_data) => {
+                tx.propose_transfer_execute(
+                    call_data.to,
+                    call_data.egld_amount,
+                    call_data.opt_gas_limit,
+                    function_call(call_data.endpoint_name, call_data.arguments),
+                )
+                .returns(ReturnsResult)
+                .run()
+                .await
+            }
+            Action::SendTransferExecuteEsdt(call_data) => {
+                tx.propose_transfer_execute_esdt(
+                    call_data.to,
+                    call_data.tokens,
+                    call_data.opt_gas_limit,
+                    function_call(call_data.endpoint_name, call_data.arguments),
+                )
+                .returns(ReturnsResult)
+                .run()
+                .await
+            }
+            Action::SendAsyncCall(call_data) => {
+                tx.propose_async_call(
+                    call_data.to,
+                    call_data.egld_amount,
+                    call_data.opt_gas_limit,
+                    function_call(call_data.endpoint_name, call_data.arguments),
+                )
+                .returns(ReturnsResult)
+                .run()
+                .await
+            }
+            Action::SendSyncCall(call_data) => {
+                tx.propose_sync_call(
+                    call_data.to,
+                    call_data.egld_amount,
+                    call_data.opt_gas_limit,
+                    function_call(call_data.endpoint_name, call_data.arguments),
+                )
+                .returns(ReturnsResult)
+                .run()
+                .await
+            }
+            Action::SCDeployFromSource {
+                amount,
+                source,
+                code_metadata,
+                arguments,
+            } => {
+                tx.propose_sc_deploy_from_source(
+                    amount,
+                    source,
+                    code_metadata,
+                    MultiValueEncoded::from(arguments),
+                )
+                .returns(ReturnsResult)
+                .run()
+                .await
+            }
+            Action::SCUpgradeFromSource {
+                sc_address,
+                amount,
+                source,
+                code_metadata,
+                arguments,
+            } => {
+                tx.propose_sc_upgrade_from_source(
+                    sc_address,
+                    amount,
+                    source,
+                    code_metadata,
+                    MultiValueEncoded::from(arguments),
+                )
+                .returns(ReturnsResult)
+                .run()
+                .await
+            }
+            Action::ChangeTimelockDelay(delay) => {
+                tx.propose_change_timelock_delay(delay, OptionalValue::<u64>::None)
+                    .returns(ReturnsResult)
+                    .run()
+                    .await
+            }
+            Action::ChangeActionTypeTimelockDelay {
+                action_type,
+                opt_new_delay,
+            } => {
+                tx.propose_change_action_type_timelock_delay(
+                    action_type,
+                    OptionalValue::from(opt_new_delay),
+                )
+                .returns(ReturnsResult)
+                .run()
+                .await
+            }
+            Action::ChangeActionTypeQuorum {
+                action_type,
+                opt_new_quorum,
+            } => {
+                tx.propose_change_action_type_quorum(
+                    action_type,
+                    OptionalValue::from(opt_new_quorum),
+                )
+                .returns(ReturnsResult)
+                .run()
+                .await
+            }
+            Action::ChangeSignerWeight {
+                board_member,
+                new_weight,
+            } => {
+                tx.propose_change_signer_weight(
+                    board_member,
+                    new_weight,
+                    OptionalValue::<u64>::None,
+                )
+                .returns(ReturnsResult)
+                .run()
+                .await
+            }
+            Action::ChangeSpendingLimit {
+                token_id,
+                opt_limit: Some(limit),
+            } => {
+                tx.propose_set_spending_limit(
+                    token_id,
+                    limit.amount,
+                    limit.period,
+                    OptionalValue::<u64>::None,
+                )
+                .returns(ReturnsResult)
+                .run()
+                .await
+            }
+            Action::ChangeSpendingLimit {
+                token_id,
+                opt_limit: None,
+            } => {
+                tx.propose_remove_spending_limit(token_id, OptionalValue::<u64>::None)
+                    .returns(ReturnsResult)
+                    .run()
+                    .await
+            }
+            Action::ManageEsdt(operation) => {
+                tx.propose_manage_esdt(operation, OptionalValue::<u64>::None)
+                    .returns(ReturnsResult)
+                    .run()
+                    .await
+            }
+            Action::Nothing => panic!("an empty action can not be proposed"),
+        }
+    }
+
+    /// Prints the top-encoded actions in the file, hex encoded, e.g. for `signatures-sign`.
+    pub fn print_encoded_actions(&self, file_path: &str) {
+        let action_file = ActionFile::load(file_path);
+        for definition in &action_file.actions {
+            let encoded_action = top_encode_to_vec_u8_or_panic(&definition.to_action());
+            println!("{}", hex::encode(encoded_action));
+        }
+    }
+
+    /// Lists the pending actions through the view contract, with their arguments decoded.
+    pub async fn print_pending_actions(&mut self) {
+        let pending_actions = self
+            .interactor
+            .query()
+            .to(self.state.current_multisig_view_address())
+            .typed(multisig_proxy::MultisigProxy)
+            .get_pending_action_full_info(OptionalValue::<(usize, usize)>::None)
+            .returns(ReturnsResult)
+            .run()
+            .await;
+
+        let mut num_pending_actions = 0;
+        for action_info in pending_actions {
+            num_pending_actions += 1;
+            let quorum_reached = self.quorum_reached(action_info.action_id).await;
+
+            print!("action `{}`", action_info.action_id);
+            if action_info.group_id != 0 {
+                print!(" (batch `{}`)", action_info.group_id);
+            }
+            println!(": {}", describe_action(&action_info.action_data));
+
+            let signers = action_info
+                .signers
+                .iter()
+                .map(|signer| describe_address(&signer))
+                .collect::<Vec<_>>();
+            println!("    signers: [{}]", signers.join(", "));
+            match action_info.opt_timelock_remaining {
+                Some(remaining) => println!("    quorum reached, timelock remaining: {remaining}s"),
+                None => println!("    quorum reached: {quorum_reached}"),
+            }
+        }
+
+        println!("{num_pending_actions} pending action(s)");
+    }
+
+    pub async fn sign_action_or_batch(&mut self, id: usize, is_batch: bool, opt_pem: Option<&str>) {
+        let signer = self.signer_from_pem(opt_pem).await;
+        let tx = self
+            .interactor
+            .tx()
+            .from(&signer)
+            .to(self.state.current_multisig_address())
+            .gas(SIGN_GAS)
+            .typed(multisig_proxy::MultisigProxy);
+        if is_batch {
+            tx.sign_batch(id).run().await;
+        } else {
+            tx.sign(id).run().await;
+        }
+
+        println!("{} - successfully signed `{id}`", bech32::encode(&signer));
+    }
+
+    pub async fn unsign_action_or_batch(
+        &mut self,
+        id: usize,
+        is_batch: bool,
+        opt_pem: Option<&str>,
+    ) {
+        let signer = self.signer_from_pem(opt_pem).await;
+        let tx = self
+            .interactor
+            .tx()
+            .from(&signer)
+            .to(self.state.current_multisig_address())
+            .gas(SIGN_GAS)
+            .typed(multisig_proxy::MultisigProxy);
+        if is_batch {
+            tx.unsign_batch(id).run().await;
+        } else {
+            tx.unsign(id).run().await;
+        }
+
+        println!("{} - successfully unsigned `{id}`", bech32::encode(&signer));
+    }
+
+    pub async fn perform_action_or_batch(&mut self, id: usize, is_batch: bool, gas: u64) {
+        let tx = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_multisig_address())
+            .gas(gas)
+            .typed(multisig_proxy::MultisigProxy);
+        if is_batch {
+            tx.perform_batch(id).run().await;
+            println!("successfully performed batch `{id}`");
+        } else {
+            let opt_new_address = tx
+                .perform_action_endpoint(id)
+                .returns(ReturnsResult)
+                .run()
+                .await;
+            println!("successfully performed action `{id}`");
+            if let Some(new_address) = opt_new_address.into_option() {
+                println!("new deployed address: {}", describe_address(&new_address));
+            }
+        }
+    }
+
+    /// The wallet from the PEM file, or the wallet of the interactor if there is none.
+    async fn signer_from_pem(&mut self, opt_pem: Option<&str>) -> Address {
+        match opt_pem {
+            Some(pem_path) => {
+                let wallet = Wallet::from_pem_file(pem_path).expect("invalid PEM file");
+                self.interactor.register_wallet(wallet).await
+            }
+            None => self.wallet_address.to_address(),
+        }
+    }
+}
+
+fn function_call(
+    endpoint_name: ManagedBuffer<StaticApi>,
+    arguments: ManagedVec<StaticApi, ManagedBuffer<StaticApi>>,
+) -> FunctionCall<StaticApi> {
+    FunctionCall {
+        function_name: endpoint_name,
+        arg_buffer: arguments.into(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALICE: &str = "erd1qyu5wthldzr8wx5c9ucg8kjagg0jfs53s8nr3zpz3hypefsdd8ssycr6th";
+    const BOB: &str = "erd1spyavw0956vq68xj8y4tenjpq2wd5a9p2c6j8gsz7ztyrnpxrruqzu66jx";
+
+    fn address(bech32: &str) -> ManagedAddress<StaticApi> {
+        Bech32Address::from_bech32_string(bech32.to_string())
+            .to_address()
+            .into()
+    }
+
+    fn parse_actions(content: &str, is_toml: bool) -> Vec<Vec<u8>> {
+        ActionFile::parse(content, is_toml)
+            .actions
+            .iter()
+            .map(|definition| top_encode_to_vec_u8_or_panic(&definition.to_action()))
+            .collect()
+    }
+
+    fn encode(actions: &[Action<StaticApi>]) -> Vec<Vec<u8>> {
+        actions.iter().map(top_encode_to_vec_u8_or_panic).collect()
+    }
+
+    #[test]
+    fn parse_json_transfer_with_expiry() {
+        let content = format!(
+            r#"{{
+                "expiry": 1893456000,
+                "actions": [
+                    {{
+                        "type": "transfer_egld",
+                        "to": "{BOB}",
+                        "amount": "10_000",
+                        "gas_limit": 5000000,
+                        "endpoint": "add",
+                        "arguments": ["0x05", "str:abc"]
+                    }}
+                ]
+            }}"#
+        );
+        let action_file = ActionFile::parse(&content, false);
+        assert_eq!(action_file.expiry, Some(1893456000));
+
+        let mut arguments = ManagedVec::new();
+        arguments.push(ManagedBuffer::from(&[5u8][..]));
+        arguments.push(ManagedBuffer::from("abc"));
+        let expected = Action::SendTransferExecuteEgld(CallActionData {
+            to: address(BOB),
+            egld_amount: BigUint::from(10_000u64),
+            opt_gas_limit: Some(5_000_000),
+            endpoint_name: ManagedBuffer::from("add"),
+            arguments,
+        });
+        assert_eq!(parse_actions(&content, false), encode(&[expected]));
+    }
+
+    #[test]
+    fn parse_toml_batch() {
+        let content = format!(
+            r#"
+            [[actions]]
+            type = "transfer_esdt"
+            to = "{BOB}"
+            tokens = [
+                {{ token = "WEGLD-123456", amount = "100" }},
+                {{ token = "NFT-123456", nonce = 3, amount = "1" }},
+            ]
+
+            [[actions]]
+            type = "add_board_member"
+            address = "{ALICE}"
+
+            [[actions]]
+            type = "deploy_from_source"
+            source = "{BOB}"
+            code_metadata = ["upgradeable", "payable"]
+            "#
+        );
+        assert_eq!(ActionFile::parse(&content, true).expiry, None);
+
+        let mut tokens = ManagedVec::new();
+        tokens.push(EsdtTokenPayment::new(
+            TokenIdentifier::from("WEGLD-123456"),
+            0,
+            BigUint::from(100u64),
+        ));
+        tokens.push(EsdtTokenPayment::new(
+            TokenIdentifier::from("NFT-123456"),
+            3,
+            BigUint::from(1u64),
+        ));
+        let expected = [
+            Action::SendTransferExecuteEsdt(EsdtTransferExecuteData {
+                to: address(BOB),
+                tokens,
+                opt_gas_limit: None,
+                endpoint_name: ManagedBuffer::new(),
+                arguments: ManagedVec::new(),
+            }),
+            Action::AddBoardMember(address(ALICE)),
+            Action::SCDeployFromSource {
+                amount: BigUint::zero(),
+                source: address(BOB),
+                code_metadata: CodeMetadata::UPGRADEABLE | CodeMetadata::PAYABLE,
+                arguments: ManagedVec::new(),
+            },
+        ];
+        assert_eq!(parse_actions(&content, true), encode(&expected));
+    }
+
+    #[test]
+    fn parse_governance_actions() {
+        let content = format!(
+            r#"
+            [[actions]]
+            type = "change_timelock_delay"
+            delay = 3600
+
+            [[actions]]
+            type = "change_action_type_timelock_delay"
+            action_type = "sync_call"
+            delay = 60
+
+            [[actions]]
+            type = "change_action_type_timelock_delay"
+            action_type = "sync_call"
+
+            [[actions]]
+            type = "change_action_type_quorum"
+            action_type = "upgrade_from_source"
+            quorum = 3
+
+            [[actions]]
+            type = "change_signer_weight"
+            address = "{ALICE}"
+            weight = 2
+
+            [[actions]]
+            type = "set_spending_limit"
+            token = "EGLD"
+            amount = "1_000"
+            period = 86400
+
+            [[actions]]
+            type = "remove_spending_limit"
+            token = "WEGLD-123456"
+            "#
+        );
+
+        let expected = [
+            Action::ChangeTimelockDelay(3600),
+            Action::ChangeActionTypeTimelockDelay {
+                action_type: ActionType::SendSyncCall,
+                opt_new_delay: Some(60),
+            },
+            Action::ChangeActionTypeTimelockDelay {
+                action_type: ActionType::SendSyncCall,
+                opt_new_delay: None,
+            },
+            Action::ChangeActionTypeQuorum {
+                action_type: ActionType::SCUpgradeFromSource,
+                opt_new_quorum: Some(3),
+            },
+            Action::ChangeSignerWeight {
+                board_member: address(ALICE),
+                new_weight: 2,
+            },
+            Action::ChangeSpendingLimit {
+                token_id: EgldOrEsdtTokenIdentifier::egld(),
+                opt_limit: Some(SpendingLimit {
+                    amount: BigUint::from(1_000u64),
+                    period: 86400,
+                }),
+            },
+            Action::ChangeSpendingLimit {
+                token_id: EgldOrEsdtTokenIdentifier::esdt("WEGLD-123456"),
+                opt_limit: None,
+            },
+        ];
+        assert_eq!(parse_actions(&content, true), encode(&expected));
+    }
+
+    #[test]
+    fn parse_esdt_operations() {
+        let content = format!(
+            r#"{{
+                "actions": [
+                    {{
+                        "type": "issue_fungible",
+                        "issue_cost": "50_000_000_000_000_000",
+                        "name": "Treasury",
+                        "ticker": "TRS",
+                        "initial_supply": "1_000_000",
+                        "num_decimals": 6
+                    }},
+                    {{
+                        "type": "set_special_roles",
+                        "token": "TRS-123456",
+                        "address": "{ALICE}",
+                        "roles": ["mint", "burn"]
+                    }},
+                    {{ "type": "local_mint", "token": "TRS-123456", "amount": "500" }},
+                    {{ "type": "local_burn", "token": "TRS-123456", "amount": "200" }},
+                    {{ "type": "pause", "token": "TRS-123456" }},
+                    {{ "type": "unpause", "token": "TRS-123456" }}
+                ]
+            }}"#
+        );
+
+        let mut roles = ManagedVec::new();
+        roles.push(EsdtLocalRole::Mint);
+        roles.push(EsdtLocalRole::Burn);
+        let expected = [
+            Action::ManageEsdt(EsdtOperation::IssueFungible {
+                issue_cost: BigUint::from(50_000_000_000_000_000u64),
+                token_display_name: ManagedBuffer::from("Treasury"),
+                token_ticker: ManagedBuffer::from("TRS"),
+                initial_supply: BigUint::from(1_000_000u64),
+                num_decimals: 6,
+            }),
+            Action::ManageEsdt(EsdtOperation::SetSpecialRoles {
+                token_id: TokenIdentifier::from("TRS-123456"),
+                address: address(ALICE),
+                roles,
+            }),
+            Action::ManageEsdt(EsdtOperation::LocalMint {
+                token_id: TokenIdentifier::from("TRS-123456"),
+                amount: BigUint::from(500u64),
+            }),
+            Action::ManageEsdt(EsdtOperation::LocalBurn {
+                token_id: TokenIdentifier::from("TRS-123456"),
+                amount: BigUint::from(200u64),
+            }),
+            Action::ManageEsdt(EsdtOperation::Pause(TokenIdentifier::from("TRS-123456"))),
+            Action::ManageEsdt(EsdtOperation::Unpause(TokenIdentifier::from("TRS-123456"))),
+        ];
+        assert_eq!(parse_actions(&content, false), encode(&expected));
+    }
+
+    #[test]
+    fn parse_amounts_and_arguments() {
+        assert_eq!(parse_amount(""), BigUint::zero());
+        assert_eq!(parse_amount("1_000"), BigUint::from(1_000u64));
+        assert_eq!(
+            parse_amount("340282366920938463463374607431768211455"),
+            BigUint::from(u128::MAX)
+        );
+
+        assert_eq!(parse_argument("str:add"), ManagedBuffer::from("add"));
+        assert_eq!(parse_argument("0x0102"), ManagedBuffer::from(&[1u8, 2][..]));
+        assert_eq!(parse_argument("0102"), ManagedBuffer::from(&[1u8, 2][..]));
+    }
+
+    #[test]
+    #[should_panic(expected = "unknown action type `transfer`")]
+    fn parse_unknown_action_type() {
+        parse_actions(
+            r#"
+            [[actions]]
+            type = "change_action_type_quorum"
+            action_type = "transfer"
+            quorum = 2
+            "#,
+            true,
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid amount `ten`")]
+    fn parse_invalid_amount() {
+        parse_actions(
+            r#"
+            [[actions]]
+            type = "local_mint"
+            token = "TRS-123456"
+            amount = "ten"
+            "#,
+            true,
+        );
+    }
+}
@@ -12,10 +12,33 @@ pub struct InteractCli {
 /// Multisig Interact CLI Commands
 #[derive(Clone, PartialEq, Eq, Debug, Subcommand)]
 pub enum InteractCliCommand {
+    #[command(
+        name = "action-encode",
+        about = "Print the hex encoded actions of an action file"
+    )]
+    ActionEncode(ActionFileArgs),
+    #[command(name = "action-list", about = "List pending actions")]
+    ActionList,
+    #[command(name = "action-perform", about = "Perform an action or a batch")]
+    ActionPerform(ActionPerformArgs),
+    #[command(
+        name = "action-propose",
+        about = "Propose the actions of an action file"
+    )]
+    ActionPropose(ActionFileArgs),
+    #[command(name = "action-sign", about = "Sign an action or a batch")]
+    ActionSign(ActionSignArgs),
+    #[command(name = "action-unsign", about = "Unsign an action or a batch")]
+    ActionUnsign(ActionSignArgs),
     #[command(name = "board", about = "Print board")]
     Board,
     #[command(name = "deploy", about = "Deploy contract")]
     Deploy,
+    #[command(
+        name = "deploy-view",
+        about = "Deploy the external view contract of the multisig"
+    )]
+    DeployView,
     #[command(name = "dns-register", about = "Register DNS")]
     DnsRegister(DnsRegisterArgs),
     #[command(name = "feed", about = "Feed contract EGLD")]
@@ -42,6 +65,16 @@ pub enum InteractCliCommand {
     NftSpecial,
     #[command(name = "quorum", about = "Print quorum")]
     Quorum,
+    #[command(
+        name = "signatures-perform",
+        about = "Perform an action with the collected off-chain signatures"
+    )]
+    SignaturesPerform(SignaturesPerformArgs),
+    #[command(
+        name = "signatures-sign",
+        about = "Sign an action off-chain and add the signature to a signatures file"
+    )]
+    SignaturesSign(SignaturesSignArgs),
     #[command(name = "unwrap-egld", about = "Unwrap EGLD")]
     UnwrapEgld,
     #[command(
@@ -53,6 +86,50 @@ pub enum InteractCliCommand {
     WrapEgld,
 }
 
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct ActionFileArgs {
+    /// The JSON or TOML file with the action definitions
+    /// Several actions are proposed as a batch
+    #[arg(short = 'f', long = "file", verbatim_doc_comment)]
+    pub file: String,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct ActionSignArgs {
+    /// The ID of the action, or of the batch
+    #[arg(short = 'i', long = "id", verbatim_doc_comment)]
+    pub id: usize,
+
+    /// Whether the ID is a batch ID
+    #[arg(short = 'b', long = "batch", verbatim_doc_comment)]
+    pub batch: bool,
+
+    /// The PEM file of the board member
+    /// The interactor wallet is used if missing
+    #[arg(short = 'k', long = "pem", verbatim_doc_comment)]
+    pub pem: Option<String>,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct ActionPerformArgs {
+    /// The ID of the action, or of the batch
+    #[arg(short = 'i', long = "id", verbatim_doc_comment)]
+    pub id: usize,
+
+    /// Whether the ID is a batch ID
+    #[arg(short = 'b', long = "batch", verbatim_doc_comment)]
+    pub batch: bool,
+
+    /// The gas limit of the transaction
+    #[arg(
+        short = 'g',
+        long = "gas",
+        default_value = "80000000",
+        verbatim_doc_comment
+    )]
+    pub gas: u64,
+}
+
 #[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
 pub struct DnsRegisterArgs {
     /// The name used for the registration (herotag)
@@ -66,3 +143,46 @@ pub struct MultiDeployArgs {
     #[arg(short = 'c', long = "count", verbatim_doc_comment)]
     pub count: u8,
 }
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct SignaturesSignArgs {
+    /// The PEM file of the signing board member
+    #[arg(short = 'k', long = "pem", verbatim_doc_comment)]
+    pub pem: String,
+
+    /// The top-encoded action, hex encoded
+    /// Only needed for the first signature
+    #[arg(short = 'a', long = "action", verbatim_doc_comment)]
+    pub action: Option<String>,
+
+    /// The signature nonce, it can only be used once
+    /// Only needed for the first signature
+    #[arg(short = 'n', long = "nonce", verbatim_doc_comment)]
+    pub nonce: Option<u64>,
+
+    /// The block timestamp after which the signatures are no longer valid
+    /// Only needed for the first signature
+    #[arg(short = 'e', long = "expiry", verbatim_doc_comment)]
+    pub expiry: Option<u64>,
+
+    /// The file where signatures are collected
+    #[arg(
+        short = 'f',
+        long = "file",
+        default_value = "signatures.toml",
+        verbatim_doc_comment
+    )]
+    pub file: String,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct SignaturesPerformArgs {
+    /// The file where signatures are collected
+    #[arg(
+        short = 'f',
+        long = "file",
+        default_value = "signatures.toml",
+        verbatim_doc_comment
+    )]
+    pub file: String,
+}
@@ -0,0 +1,187 @@
+use std::{
+    io::{Read, Write},
+    path::Path,
+};
+
+use multiversx_sc_snippets::{
+    hex,
+    imports::*,
+    sdk::{crypto::private_key::PrivateKey, wallet::Wallet},
+};
+use serde::{Deserialize, Serialize};
+
+use super::*;
+
+const PERFORM_WITH_SIGNATURES_GAS: u64 = 80_000_000;
+
+/// Off-chain signatures collected for a single action.
+/// Board members add their signatures to the same file, one after the other.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SignatureBundle {
+    /// The top-encoded action, hex encoded
+    action: String,
+    /// The signature nonce picked for this action, it can only be used once
+    nonce: u64,
+    /// The block timestamp after which the signatures are no longer valid
+    expiry: u64,
+    #[serde(default)]
+    signatures: Vec<SignatureEntry>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SignatureEntry {
+    signer: Bech32Address,
+    signature: String,
+}
+
+impl SignatureBundle {
+    // Deserializes signatures from file, if it exists
+    fn load(file_path: &str) -> Option<Self> {
+        if !Path::new(file_path).exists() {
+            return None;
+        }
+
+        let mut file = std::fs::File::open(file_path).unwrap();
+        let mut content = String::new();
+        file.read_to_string(&mut content).unwrap();
+        Some(toml::from_str(&content).unwrap())
+    }
+
+    // Serializes signatures to file
+    fn save(&self, file_path: &str) {
+        let mut file = std::fs::File::create(file_path).unwrap();
+        file.write_all(toml::to_string(self).unwrap().as_bytes())
+            .unwrap();
+    }
+
+    fn decode_action(&self) -> multisig_proxy::Action<StaticApi> {
+        let encoded_action = hex::decode(&self.action).expect("action is not valid hex");
+        multisig_proxy::Action::top_decode(encoded_action.as_slice())
+            .expect("action could not be decoded")
+    }
+}
+
+impl MultisigInteract {
+    /// Signs the action with the key from the PEM file and adds the signature to the signatures file.
+    /// The action, nonce and expiry are only needed for the first signature,
+    /// afterwards they are read from the file.
+    pub async fn signatures_sign(
+        &mut self,
+        pem_path: &str,
+        opt_action: Option<&str>,
+        opt_nonce: Option<u64>,
+        opt_expiry: Option<u64>,
+        file_path: &str,
+    ) {
+        let mut bundle = match SignatureBundle::load(file_path) {
+            Some(bundle) => {
+                if let Some(action) = opt_action {
+                    assert_eq!(
+                        bundle.action, action,
+                        "signatures file was collected for another action"
+                    );
+                }
+                bundle
+            }
+            None => SignatureBundle {
+                action: opt_action
+                    .expect("no signatures file yet, the action is required")
+                    .to_string(),
+                nonce: opt_nonce.expect("no signatures file yet, the nonce is required"),
+                expiry: opt_expiry.expect("no signatures file yet, the expiry is required"),
+                signatures: Vec::new(),
+            },
+        };
+        self.require_signature_nonce_unused(bundle.nonce).await;
+
+        let message = self
+            .interactor
+            .query()
+            .to(self.state.current_multisig_address())
+            .typed(multisig_proxy::MultisigProxy)
+            .get_signature_message(bundle.nonce, bundle.expiry, bundle.decode_action())
+            .returns(ReturnsResult)
+            .run()
+            .await;
+
+        let (private_key_hex, _) = Wallet::get_wallet_keys_pem(pem_path);
+        let private_key = PrivateKey::from_hex_str(&private_key_hex).expect("invalid PEM file");
+        let signer = Wallet::from_pem_file(pem_path)
+            .expect("invalid PEM file")
+            .to_address();
+        let signature = private_key.sign(message.to_vec());
+
+        bundle
+            .signatures
+            .retain(|entry| entry.signer.to_address() != signer);
+        bundle.signatures.push(SignatureEntry {
+            signer: signer.clone().into(),
+            signature: hex::encode(signature),
+        });
+        bundle.save(file_path);
+
+        println!(
+            "{} - signed action with nonce `{}`, {} signature(s) collected",
+            bech32::encode(&signer),
+            bundle.nonce,
+            bundle.signatures.len()
+        );
+    }
+
+    /// Performs the action from the signatures file in one transaction.
+    pub async fn signatures_perform(&mut self, file_path: &str) {
+        let bundle = SignatureBundle::load(file_path).expect("signatures file not found");
+        self.require_signature_nonce_unused(bundle.nonce).await;
+
+        let mut signatures = MultiValueEncoded::new();
+        for entry in &bundle.signatures {
+            let signature = hex::decode(&entry.signature).expect("signature is not valid hex");
+            let signature: [u8; 64] = signature.try_into().expect("signatures are 64 bytes long");
+            signatures.push(MultiValue2::from((
+                ManagedAddress::from(entry.signer.to_address()),
+                ManagedByteArray::new_from_bytes(&signature),
+            )));
+        }
+
+        let opt_new_address = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_multisig_address())
+            .gas(PERFORM_WITH_SIGNATURES_GAS)
+            .typed(multisig_proxy::MultisigProxy)
+            .perform_with_signatures(
+                bundle.nonce,
+                bundle.expiry,
+                bundle.decode_action(),
+                signatures,
+            )
+            .returns(ReturnsResult)
+            .run()
+            .await;
+
+        println!(
+            "successfully performed action with {} signature(s)",
+            bundle.signatures.len()
+        );
+        if let Some(new_address) = opt_new_address.into_option() {
+            println!("new deployed address: {new_address:#?}");
+        }
+    }
+
+    async fn require_signature_nonce_unused(&mut self, nonce: u64) {
+        let used = self
+            .interactor
+            .query()
+            .to(self.state.current_multisig_address())
+            .typed(multisig_proxy::MultisigProxy)
+            .signature_nonce_used(nonce)
+            .returns(ReturnsResult)
+            .run()
+            .await;
+        assert!(
+            !used,
+            "signatures file is outdated, the nonce was already used"
+        );
+    }
+}
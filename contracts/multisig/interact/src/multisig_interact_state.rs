@@ -12,6 +12,7 @@ const STATE_FILE: &str = "state.toml";
 #[derive(Debug, Default, Serialize, Deserialize)]
 pub struct State {
     multisig_address: Option<Bech32Address>,
+    multisig_view_address: Option<Bech32Address>,
 }
 
 impl State {
@@ -37,6 +38,17 @@ impl State {
             .as_ref()
             .expect("no known multisig contract, deploy first")
     }
+
+    /// Sets the address of the external view contract
+    pub fn set_multisig_view_address(&mut self, address: Bech32Address) {
+        self.multisig_view_address = Some(address);
+    }
+
+    pub fn current_multisig_view_address(&self) -> &Bech32Address {
+        self.multisig_view_address
+            .as_ref()
+            .expect("no known multisig view contract, run deploy-view first")
+    }
 }
 
 impl Drop for State {
@@ -1,6 +1,9 @@
 use multiversx_sc::{
     api::ManagedTypeApi,
-    types::{BigUint, CodeMetadata, ManagedAddress, ManagedBuffer, ManagedVec},
+    types::{
+        BigUint, CodeMetadata, EgldOrEsdtTokenIdentifier, EsdtLocalRole, ManagedAddress,
+        ManagedBuffer, ManagedVec, TokenIdentifier,
+    },
 };
 use multiversx_sc_modules::transfer_role_proxy::PaymentsVec;
 
@@ -30,6 +33,53 @@ pub struct EsdtTransferExecuteData<M: ManagedTypeApi> {
     pub arguments: ManagedVec<M, ManagedBuffer<M>>,
 }
 
+/// Amount of a token that can be spent without quorum, every `period` seconds.
+#[type_abi]
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, Clone, PartialEq, Debug)]
+pub struct SpendingLimit<M: ManagedTypeApi> {
+    pub amount: BigUint<M>,
+    pub period: u64,
+}
+
+/// Issues and manages ESDT tokens owned by the multisig.
+/// Minting and burning require the multisig to hold the corresponding local roles.
+#[type_abi]
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, Clone)]
+pub enum EsdtOperation<M: ManagedTypeApi> {
+    IssueFungible {
+        issue_cost: BigUint<M>,
+        token_display_name: ManagedBuffer<M>,
+        token_ticker: ManagedBuffer<M>,
+        initial_supply: BigUint<M>,
+        num_decimals: usize,
+    },
+    SetSpecialRoles {
+        token_id: TokenIdentifier<M>,
+        address: ManagedAddress<M>,
+        roles: ManagedVec<M, EsdtLocalRole>,
+    },
+    LocalMint {
+        token_id: TokenIdentifier<M>,
+        amount: BigUint<M>,
+    },
+    LocalBurn {
+        token_id: TokenIdentifier<M>,
+        amount: BigUint<M>,
+    },
+    Pause(TokenIdentifier<M>),
+    Unpause(TokenIdentifier<M>),
+}
+
+impl<M: ManagedTypeApi> EsdtOperation<M> {
+    /// Operations that go through the system smart contract end the execution.
+    pub fn is_async_call(&self) -> bool {
+        !matches!(
+            self,
+            EsdtOperation::LocalMint { .. } | EsdtOperation::LocalBurn { .. }
+        )
+    }
+}
+
 #[type_abi]
 #[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, Clone)]
 pub enum Action<M: ManagedTypeApi> {
@@ -55,6 +105,48 @@ pub enum Action<M: ManagedTypeApi> {
         arguments: ManagedVec<M, ManagedBuffer<M>>,
     },
     SendSyncCall(CallActionData<M>),
+    ChangeTimelockDelay(u64),
+    ChangeActionTypeTimelockDelay {
+        action_type: ActionType,
+        opt_new_delay: Option<u64>,
+    },
+    ChangeActionTypeQuorum {
+        action_type: ActionType,
+        opt_new_quorum: Option<usize>,
+    },
+    ChangeSignerWeight {
+        board_member: ManagedAddress<M>,
+        new_weight: usize,
+    },
+    ChangeSpendingLimit {
+        token_id: EgldOrEsdtTokenIdentifier<M>,
+        opt_limit: Option<SpendingLimit<M>>,
+    },
+    ManageEsdt(EsdtOperation<M>),
+}
+
+/// Identifies an `Action` variant, without its data.
+/// Used to configure settings that differ per kind of action.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ActionType {
+    Nothing,
+    AddBoardMember,
+    AddProposer,
+    RemoveUser,
+    ChangeQuorum,
+    SendTransferExecuteEgld,
+    SendTransferExecuteEsdt,
+    SendAsyncCall,
+    SCDeployFromSource,
+    SCUpgradeFromSource,
+    SendSyncCall,
+    ChangeTimelockDelay,
+    ChangeActionTypeTimelockDelay,
+    ChangeActionTypeQuorum,
+    ChangeSignerWeight,
+    ChangeSpendingLimit,
+    ManageEsdt,
 }
 
 impl<M: ManagedTypeApi> Action<M> {
@@ -70,7 +162,11 @@ impl<M: ManagedTypeApi> Action<M> {
     }
 
     pub fn is_async_call(&self) -> bool {
-        matches!(*self, Action::SendAsyncCall(_))
+        match self {
+            Action::SendAsyncCall(_) => true,
+            Action::ManageEsdt(operation) => operation.is_async_call(),
+            _ => false,
+        }
     }
 
     pub fn is_sc_upgrade(&self) -> bool {
@@ -85,27 +181,91 @@ impl<M: ManagedTypeApi> Action<M> {
             }
         )
     }
+
+    pub fn action_type(&self) -> ActionType {
+        match self {
+            Action::Nothing => ActionType::Nothing,
+            Action::AddBoardMember(_) => ActionType::AddBoardMember,
+            Action::AddProposer(_) => ActionType::AddProposer,
+            Action::RemoveUser(_) => ActionType::RemoveUser,
+            Action::ChangeQuorum(_) => ActionType::ChangeQuorum,
+            Action::SendTransferExecuteEgld(_) => ActionType::SendTransferExecuteEgld,
+            Action::SendTransferExecuteEsdt(_) => ActionType::SendTransferExecuteEsdt,
+            Action::SendAsyncCall(_) => ActionType::SendAsyncCall,
+            Action::SCDeployFromSource { .. } => ActionType::SCDeployFromSource,
+            Action::SCUpgradeFromSource { .. } => ActionType::SCUpgradeFromSource,
+            Action::SendSyncCall(_) => ActionType::SendSyncCall,
+            Action::ChangeTimelockDelay(_) => ActionType::ChangeTimelockDelay,
+            Action::ChangeActionTypeTimelockDelay { .. } => {
+                ActionType::ChangeActionTypeTimelockDelay
+            }
+            Action::ChangeActionTypeQuorum { .. } => ActionType::ChangeActionTypeQuorum,
+            Action::ChangeSignerWeight { .. } => ActionType::ChangeSignerWeight,
+            Action::ChangeSpendingLimit { .. } => ActionType::ChangeSpendingLimit,
+            Action::ManageEsdt(_) => ActionType::ManageEsdt,
+        }
+    }
 }
 
 /// Not used internally, just to retrieve results via endpoint.
 #[type_abi]
-#[derive(TopEncode)]
+#[derive(TopEncode, TopDecode)]
 pub struct ActionFullInfo<M: ManagedTypeApi> {
     pub action_id: ActionId,
     pub group_id: GroupId,
     pub action_data: Action<M>,
     pub signers: ManagedVec<M, ManagedAddress<M>>,
+    /// Seconds left until the action can be performed.
+    /// `None` while the action has not reached quorum.
+    pub opt_timelock_remaining: Option<u64>,
 }
 
 #[cfg(test)]
 mod test {
     use multiversx_sc_scenario::api::StaticApi;
 
-    use super::Action;
+    use multiversx_sc::types::{BigUint, ManagedBuffer, TokenIdentifier};
+
+    use super::{Action, ActionType, EsdtOperation};
 
     #[test]
     fn test_is_pending() {
         assert!(!Action::<StaticApi>::Nothing.is_pending());
         assert!(Action::<StaticApi>::ChangeQuorum(5).is_pending());
     }
+
+    #[test]
+    fn test_action_type() {
+        assert_eq!(
+            Action::<StaticApi>::ChangeQuorum(5).action_type(),
+            ActionType::ChangeQuorum
+        );
+        assert_eq!(
+            Action::<StaticApi>::ChangeTimelockDelay(60).action_type(),
+            ActionType::ChangeTimelockDelay
+        );
+    }
+
+    #[test]
+    fn test_manage_esdt_is_async_call() {
+        let token_id = TokenIdentifier::<StaticApi>::from("TOKEN-123456");
+        let mint = Action::ManageEsdt(EsdtOperation::LocalMint {
+            token_id: token_id.clone(),
+            amount: BigUint::from(100u64),
+        });
+        assert!(!mint.is_async_call());
+        assert_eq!(mint.action_type(), ActionType::ManageEsdt);
+
+        assert!(Action::ManageEsdt(EsdtOperation::Pause(token_id)).is_async_call());
+        assert!(
+            Action::<StaticApi>::ManageEsdt(EsdtOperation::IssueFungible {
+                issue_cost: BigUint::from(50_000_000_000_000_000u64),
+                token_display_name: ManagedBuffer::from("Token"),
+                token_ticker: ManagedBuffer::from("TOKEN"),
+                initial_supply: BigUint::from(1_000u64),
+                num_decimals: 18,
+            })
+            .is_async_call()
+        );
+    }
 }
@@ -6,10 +6,14 @@ pub mod multisig_perform;
 pub mod multisig_propose;
 pub mod multisig_proxy;
 pub mod multisig_sign;
+pub mod multisig_signatures;
+pub mod multisig_spending_limit;
 pub mod multisig_state;
+pub mod multisig_timelock;
+pub mod multisig_treasury;
 pub mod user_role;
 
-use action::ActionFullInfo;
+use action::{Action, ActionFullInfo};
 use multisig_state::{ActionId, ActionStatus};
 use user_role::UserRole;
 
@@ -25,6 +29,10 @@ pub trait Multisig:
     + multisig_sign::MultisigSignModule
     + multisig_perform::MultisigPerformModule
     + multisig_events::MultisigEventsModule
+    + multisig_timelock::MultisigTimelockModule
+    + multisig_spending_limit::MultisigSpendingLimitModule
+    + multisig_signatures::MultisigSignaturesModule
+    + multisig_treasury::MultisigTreasuryModule
     + multiversx_sc_modules::dns::DnsModule
 {
     #[init]
@@ -46,18 +54,39 @@ pub trait Multisig:
     }
 
     #[upgrade]
-    fn upgrade(&self) {}
+    fn upgrade(&self) {
+        // actions proposed before the pending action ids were tracked
+        let action_mapper = self.action_mapper();
+        let mut pending_action_ids = self.pending_action_ids();
+        if pending_action_ids.is_empty() {
+            for action_id in 1..=action_mapper.len() {
+                if !action_mapper.item_is_empty_unchecked(action_id) {
+                    let _ = pending_action_ids.insert(action_id);
+                }
+            }
+        }
+    }
 
     /// Allows the contract to receive funds even if it is marked as unpayable in the protocol.
+    /// ESDT tokens deposited by board members and proposers are recorded, so that they show up in `getTreasury`.
+    /// Anyone else can deposit too, but their tokens are not recorded,
+    /// so that the recorded tokens can not be flooded with unsolicited ones.
     #[payable]
     #[endpoint]
-    fn deposit(&self) {}
+    fn deposit(&self) {
+        let (_, caller_role) = self.get_caller_id_and_role();
+        if caller_role.can_propose() {
+            let payments = self.call_value().all_esdt_transfers();
+            self.record_treasury_tokens(&payments);
+        }
+    }
 
     /// Iterates through all actions and retrieves those that are still pending.
     /// Serialized full action data:
     /// - the action id
     /// - the serialized action data
-    /// - (number of signers followed by) list of signer addresses.
+    /// - (number of signers followed by) list of signer addresses
+    /// - the seconds left until the action can be performed, if it reached quorum.
     #[label("multisig-external-view")]
     #[allow_multiple_var_args]
     #[view(getPendingActionFullInfo)]
@@ -86,11 +115,13 @@ pub trait Multisig:
         for action_id in index_of_first_action..=index_of_last_action {
             let action_data = action_mapper.get(action_id);
             if action_data.is_pending() {
+                let opt_timelock_remaining = self.get_timelock_remaining(action_id, &action_data);
                 result.push(ActionFullInfo {
                     action_id,
                     action_data,
                     signers: self.get_action_signers(action_id),
                     group_id: self.group_for_action(action_id).get(),
+                    opt_timelock_remaining,
                 });
             }
         }
@@ -168,6 +199,31 @@ pub trait Multisig:
         }
     }
 
+    /// Turns all expired actions with IDs in the given range, including both ends, into `Nothing`
+    /// and removes their signatures and quorum. Anyone can call this.
+    #[endpoint(cleanupExpired)]
+    fn cleanup_expired(&self, first_action_id: ActionId, last_action_id: ActionId) {
+        require!(
+            first_action_id <= last_action_id && last_action_id <= self.get_action_last_index(),
+            "invalid action id range"
+        );
+
+        let mut action_mapper = self.action_mapper();
+        for action_id in first_action_id..=last_action_id {
+            if !self.is_action_expired(action_id) || action_mapper.get(action_id).is_nothing() {
+                continue;
+            }
+
+            self.abort_batch_of_action(action_id);
+            action_mapper.set(action_id, &Action::Nothing);
+            let _ = self.pending_action_ids().swap_remove(&action_id);
+            self.action_signer_ids(action_id).clear();
+            self.quorum_for_action(action_id).clear();
+            self.quorum_reached_timestamp(action_id).clear();
+            self.action_expiry(action_id).clear();
+        }
+    }
+
     fn discard_action(&self, action_id: ActionId) {
         require!(
             self.get_action_valid_signer_count(action_id) == 0,
@@ -1,7 +1,7 @@
 use multiversx_sc_modules::transfer_role_proxy::PaymentsVec;
 
 use crate::{
-    action::{ActionFullInfo, GasLimit},
+    action::{Action, ActionFullInfo, ActionType, EsdtOperation, GasLimit, SpendingLimit},
     multisig_state::ActionId,
     user_role::UserRole,
 };
@@ -30,6 +30,64 @@ pub trait MultisigEventsModule {
         #[indexed] new_quorum: usize,
     );
 
+    #[event("performChangeTimelockDelay")]
+    fn perform_change_timelock_delay_event(
+        &self,
+        #[indexed] action_id: ActionId,
+        #[indexed] new_delay: u64,
+    );
+
+    #[event("performChangeActionTypeTimelockDelay")]
+    fn perform_change_action_type_timelock_delay_event(
+        &self,
+        #[indexed] action_id: ActionId,
+        #[indexed] action_type: ActionType,
+        #[indexed] opt_new_delay: Option<u64>,
+    );
+
+    #[event("performChangeActionTypeQuorum")]
+    fn perform_change_action_type_quorum_event(
+        &self,
+        #[indexed] action_id: ActionId,
+        #[indexed] action_type: ActionType,
+        #[indexed] opt_new_quorum: Option<usize>,
+    );
+
+    #[event("performChangeSignerWeight")]
+    fn perform_change_signer_weight_event(
+        &self,
+        #[indexed] action_id: ActionId,
+        #[indexed] board_member: &ManagedAddress,
+        #[indexed] new_weight: usize,
+    );
+
+    #[event("performChangeSpendingLimit")]
+    fn perform_change_spending_limit_event(
+        &self,
+        #[indexed] action_id: ActionId,
+        #[indexed] token_id: &EgldOrEsdtTokenIdentifier,
+        opt_limit: &Option<SpendingLimit<Self::Api>>,
+    );
+
+    #[event("spendWithinLimit")]
+    fn spend_within_limit_event(
+        &self,
+        #[indexed] spender: &ManagedAddress,
+        #[indexed] to: &ManagedAddress,
+        #[indexed] token_id: &EgldOrEsdtTokenIdentifier,
+        #[indexed] amount: &BigUint,
+        #[indexed] spent_in_period: &BigUint,
+    );
+
+    #[event("performWithSignatures")]
+    fn perform_with_signatures_event(
+        &self,
+        #[indexed] action_id: ActionId,
+        #[indexed] nonce: u64,
+        #[indexed] signers: &ManagedVec<ManagedAddress>,
+        action: &Action<Self::Api>,
+    );
+
     #[event("performAsyncCall")]
     fn perform_async_call_event(
         &self,
@@ -96,4 +154,11 @@ pub trait MultisigEventsModule {
         #[indexed] endpoint: &ManagedBuffer,
         #[indexed] arguments: &MultiValueManagedVec<ManagedBuffer>,
     );
+
+    #[event("performManageEsdt")]
+    fn perform_manage_esdt_event(
+        &self,
+        #[indexed] action_id: ActionId,
+        operation: &EsdtOperation<Self::Api>,
+    );
 }
@@ -1,6 +1,7 @@
 use crate::{
-    action::{Action, ActionFullInfo, GasLimit},
+    action::{Action, ActionFullInfo, EsdtOperation, GasLimit},
     multisig_state::{ActionId, ActionStatus, GroupId},
+    multisig_timelock::MAX_TIMELOCK_DELAY,
     user_role::UserRole,
 };
 
@@ -17,7 +18,11 @@ fn usize_add_isize(value: &mut usize, delta: isize) {
 /// Contains all events that can be emitted by the contract.
 #[multiversx_sc::module]
 pub trait MultisigPerformModule:
-    crate::multisig_state::MultisigStateModule + crate::multisig_events::MultisigEventsModule
+    crate::multisig_state::MultisigStateModule
+    + crate::multisig_events::MultisigEventsModule
+    + crate::multisig_timelock::MultisigTimelockModule
+    + crate::multisig_spending_limit::MultisigSpendingLimitModule
+    + crate::multisig_treasury::MultisigTreasuryModule
 {
     fn ensure_and_get_gas_for_transfer_exec(&self) -> GasLimit {
         let gas_left = self.blockchain().get_gas_left();
@@ -54,6 +59,10 @@ pub trait MultisigPerformModule:
         let user_id_to_role_mapper = self.user_id_to_role(user_id);
         let old_role = user_id_to_role_mapper.get();
         user_id_to_role_mapper.set(new_role);
+        if new_role != UserRole::BoardMember {
+            // weights only apply to board members, a returning board member starts over
+            self.user_weight(user_id).clear();
+        }
 
         self.perform_change_user_event(action_id, &user_address, old_role, new_role);
 
@@ -81,19 +90,19 @@ pub trait MultisigPerformModule:
             self.num_proposers()
                 .update(|value| usize_add_isize(value, proposers_delta));
         }
-    }
 
-    /// Returns `true` (`1`) if `getActionValidSignerCount >= getQuorum`.
-    #[view(quorumReached)]
-    fn quorum_reached(&self, action_id: ActionId) -> bool {
-        let quorum = self.quorum_for_action(action_id).get();
-        let valid_signers_count = self.get_action_valid_signer_count(action_id);
-        valid_signers_count >= quorum
+        // only board member signatures count towards quorum
+        if board_members_delta != 0 {
+            self.update_all_quorum_reached_timestamps();
+        }
     }
 
     fn clear_action(&self, action_id: ActionId) {
         self.action_mapper().clear_entry_unchecked(action_id);
+        let _ = self.pending_action_ids().swap_remove(&action_id);
         self.action_signer_ids(action_id).clear();
+        self.quorum_reached_timestamp(action_id).clear();
+        self.action_expiry(action_id).clear();
 
         let group_id = self.group_for_action(action_id).take();
         if group_id != 0 {
@@ -126,7 +135,8 @@ pub trait MultisigPerformModule:
             caller_role.can_perform_action(),
             "only board members and proposers can perform actions"
         );
-        if self.quorum_reached(action_id) {
+        let action = self.action_mapper().get(action_id);
+        if self.quorum_reached(action_id) && self.is_timelock_elapsed(action_id, &action) {
             let group_id = self.group_for_action(action_id).get();
             require!(group_id == 0, "May not execute this action by itself");
 
@@ -170,6 +180,8 @@ pub trait MultisigPerformModule:
 
     fn perform_action(&self, action_id: ActionId) -> OptionalValue<ManagedAddress> {
         let action = self.action_mapper().get(action_id);
+        // cleaned up actions have no quorum left to reach
+        require!(!action.is_nothing(), "action does not exist");
 
         let group_id = self.group_for_action(action_id).get();
         if group_id != 0 {
@@ -179,11 +191,15 @@ pub trait MultisigPerformModule:
                 "cannot perform actions of an aborted batch"
             );
         }
+        require!(!self.is_action_expired(action_id), "action expired");
+        self.require_timelock_elapsed(action_id, &action);
+
         self.start_perform_action_event(&ActionFullInfo {
             action_id,
             action_data: action.clone(),
             signers: self.get_action_signers(action_id),
             group_id,
+            opt_timelock_remaining: self.get_timelock_remaining(action_id, &action),
         });
 
         // clean up storage
@@ -191,6 +207,16 @@ pub trait MultisigPerformModule:
         // syntax aside, the async_call_raw kills contract execution so cleanup cannot happen afterwards
         self.clear_action(action_id);
 
+        self.execute_action(action_id, action)
+    }
+
+    /// Runs the action itself, without any checks.
+    /// Some of the branches end the execution, so all cleanup must happen beforehand.
+    fn execute_action(
+        &self,
+        action_id: ActionId,
+        action: Action<Self::Api>,
+    ) -> OptionalValue<ManagedAddress> {
         match action {
             Action::Nothing => OptionalValue::None,
             Action::AddBoardMember(board_member_address) => {
@@ -206,10 +232,7 @@ pub trait MultisigPerformModule:
                 self.change_user_role(action_id, proposer_address, UserRole::Proposer);
 
                 // validation required for the scenario when a board member becomes a proposer
-                require!(
-                    self.quorum().get() <= self.num_board_members().get(),
-                    "quorum cannot exceed board size"
-                );
+                self.require_quorums_within_board_weight();
 
                 OptionalValue::None
             }
@@ -222,16 +245,13 @@ pub trait MultisigPerformModule:
                     num_board_members + num_proposers > 0,
                     "cannot remove all board members and proposers"
                 );
-                require!(
-                    self.quorum().get() <= num_board_members,
-                    "quorum cannot exceed board size"
-                );
+                self.require_quorums_within_board_weight();
 
                 OptionalValue::None
             }
             Action::ChangeQuorum(new_quorum) => {
                 require!(
-                    new_quorum <= self.num_board_members().get(),
+                    new_quorum <= self.get_total_board_weight(),
                     "quorum cannot exceed board size"
                 );
                 self.quorum().set(new_quorum);
@@ -239,6 +259,101 @@ pub trait MultisigPerformModule:
 
                 OptionalValue::None
             }
+            Action::ChangeTimelockDelay(new_delay) => {
+                require!(
+                    new_delay <= MAX_TIMELOCK_DELAY,
+                    "timelock delay cannot exceed limit"
+                );
+                self.timelock_delay().set(new_delay);
+                self.perform_change_timelock_delay_event(action_id, new_delay);
+
+                OptionalValue::None
+            }
+            Action::ChangeActionTypeTimelockDelay {
+                action_type,
+                opt_new_delay,
+            } => {
+                let delay_mapper = self.action_type_timelock_delay(action_type);
+                match opt_new_delay {
+                    Some(new_delay) => {
+                        require!(
+                            new_delay <= MAX_TIMELOCK_DELAY,
+                            "timelock delay cannot exceed limit"
+                        );
+                        delay_mapper.set(new_delay);
+                    }
+                    None => delay_mapper.clear(),
+                }
+                self.perform_change_action_type_timelock_delay_event(
+                    action_id,
+                    action_type,
+                    opt_new_delay,
+                );
+
+                OptionalValue::None
+            }
+            Action::ChangeActionTypeQuorum {
+                action_type,
+                opt_new_quorum,
+            } => {
+                match opt_new_quorum {
+                    Some(new_quorum) => {
+                        require!(new_quorum > 0, "action type quorum cannot be zero");
+                        require!(
+                            new_quorum <= self.get_total_board_weight(),
+                            "action type quorum cannot exceed board size"
+                        );
+                        let _ = self.action_type_quorum().insert(action_type, new_quorum);
+                    }
+                    None => {
+                        let _ = self.action_type_quorum().remove(&action_type);
+                    }
+                }
+                self.perform_change_action_type_quorum_event(
+                    action_id,
+                    action_type,
+                    opt_new_quorum,
+                );
+
+                OptionalValue::None
+            }
+            Action::ChangeSignerWeight {
+                board_member,
+                new_weight,
+            } => {
+                require!(new_weight > 0, "signer weight cannot be zero");
+                let user_id = self.user_mapper().get_user_id(&board_member);
+                require!(
+                    user_id != 0 && self.user_id_to_role(user_id).get().can_sign(),
+                    "only board members have a signer weight"
+                );
+                self.user_weight(user_id).set(new_weight);
+                self.require_quorums_within_board_weight();
+                // the signatures of this board member now count with the new weight
+                self.update_all_quorum_reached_timestamps();
+                self.perform_change_signer_weight_event(action_id, &board_member, new_weight);
+
+                OptionalValue::None
+            }
+            Action::ChangeSpendingLimit {
+                token_id,
+                opt_limit,
+            } => {
+                match &opt_limit {
+                    Some(limit) => {
+                        require!(limit.period > 0, "spending limit period cannot be zero");
+                        self.spending_limit(&token_id).set(limit);
+                    }
+                    None => {
+                        self.spending_limit(&token_id).clear();
+                        self.recent_spends(&token_id).clear();
+                        self.spent_in_period(&token_id).clear();
+                    }
+                }
+                self.perform_change_spending_limit_event(action_id, &token_id, &opt_limit);
+
+                OptionalValue::None
+            }
             Action::SendTransferExecuteEgld(call_data) => {
                 let gas = call_data
                     .opt_gas_limit
@@ -287,6 +402,7 @@ pub trait MultisigPerformModule:
                 if let Result::Err(e) = result {
                     sc_panic!(e);
                 }
+                self.remove_spent_treasury_tokens(&call_data.tokens);
 
                 OptionalValue::None
             }
@@ -385,17 +501,95 @@ pub trait MultisigPerformModule:
                     .with_gas_limit(gas)
                     .execute_on_dest_context_with_back_transfers();
 
+                OptionalValue::None
+            }
+            Action::ManageEsdt(operation) => {
+                self.perform_manage_esdt_event(action_id, &operation);
+                self.perform_esdt_operation(operation);
+
                 OptionalValue::None
             }
         }
     }
 
-    /// Callback only performs logging.
+    fn perform_esdt_operation(&self, operation: EsdtOperation<Self::Api>) {
+        match operation {
+            EsdtOperation::IssueFungible {
+                issue_cost,
+                token_display_name,
+                token_ticker,
+                initial_supply,
+                num_decimals,
+            } => self
+                .tx()
+                .to(ESDTSystemSCAddress)
+                .typed(ESDTSystemSCProxy)
+                .issue_fungible(
+                    issue_cost,
+                    &token_display_name,
+                    &token_ticker,
+                    &initial_supply,
+                    FungibleTokenProperties {
+                        num_decimals,
+                        can_freeze: true,
+                        can_wipe: true,
+                        can_pause: true,
+                        can_mint: true,
+                        can_burn: true,
+                        can_change_owner: true,
+                        can_upgrade: true,
+                        can_add_special_roles: true,
+                    },
+                )
+                .callback(self.callbacks().perform_async_call_callback())
+                .async_call_and_exit(),
+            EsdtOperation::SetSpecialRoles {
+                token_id,
+                address,
+                roles,
+            } => self
+                .tx()
+                .to(ESDTSystemSCAddress)
+                .typed(ESDTSystemSCProxy)
+                .set_special_roles(&address, &token_id, roles.iter())
+                .callback(self.callbacks().perform_async_call_callback())
+                .async_call_and_exit(),
+            EsdtOperation::LocalMint { token_id, amount } => {
+                self.send().esdt_local_mint(&token_id, 0, &amount);
+                let minted = EsdtTokenPayment::new(token_id, 0, amount);
+                self.record_treasury_tokens(&ManagedVec::from_single_item(minted));
+            }
+            EsdtOperation::LocalBurn { token_id, amount } => {
+                self.send().esdt_local_burn(&token_id, 0, &amount);
+                let burned = EsdtTokenPayment::new(token_id, 0, amount);
+                self.remove_spent_treasury_tokens(&ManagedVec::from_single_item(burned));
+            }
+            EsdtOperation::Pause(token_id) => self
+                .tx()
+                .to(ESDTSystemSCAddress)
+                .typed(ESDTSystemSCProxy)
+                .pause(&token_id)
+                .callback(self.callbacks().perform_async_call_callback())
+                .async_call_and_exit(),
+            EsdtOperation::Unpause(token_id) => self
+                .tx()
+                .to(ESDTSystemSCAddress)
+                .typed(ESDTSystemSCProxy)
+                .unpause(&token_id)
+                .callback(self.callbacks().perform_async_call_callback())
+                .async_call_and_exit(),
+        }
+    }
+
+    /// Callback performs logging and records the tokens received, e.g. from an issue.
     #[callback]
     fn perform_async_call_callback(
         &self,
         #[call_result] call_result: ManagedAsyncCallResult<MultiValueEncoded<ManagedBuffer>>,
     ) {
+        let payments = self.call_value().all_esdt_transfers();
+        self.record_treasury_tokens(&payments);
+
         match call_result {
             ManagedAsyncCallResult::Ok(results) => {
                 self.async_call_success(results);
@@ -1,7 +1,10 @@
 use multiversx_sc_modules::transfer_role_proxy::PaymentsVec;
 
 use crate::{
-    action::{Action, CallActionData, EsdtTransferExecuteData, GasLimit},
+    action::{
+        Action, ActionType, CallActionData, EsdtOperation, EsdtTransferExecuteData, GasLimit,
+        SpendingLimit,
+    },
     multisig_state::{ActionId, ActionStatus, GroupId},
 };
 
@@ -9,21 +12,30 @@ use multiversx_sc::imports::*;
 
 /// Contains all events that can be emitted by the contract.
 #[multiversx_sc::module]
-pub trait MultisigProposeModule: crate::multisig_state::MultisigStateModule {
-    fn propose_action(&self, action: Action<Self::Api>) -> ActionId {
+pub trait MultisigProposeModule:
+    crate::multisig_state::MultisigStateModule + crate::multisig_timelock::MultisigTimelockModule
+{
+    /// Every proposal can carry an optional expiry: the block timestamp after which
+    /// the action can no longer be signed or performed.
+    fn propose_action(&self, action: Action<Self::Api>, opt_expiry: Option<u64>) -> ActionId {
         let (caller_id, caller_role) = self.get_caller_id_and_role();
         require!(
             caller_role.can_propose(),
             "only board members and proposers can propose"
         );
+        self.require_valid_expiry(opt_expiry);
 
         let action_id = self.action_mapper().push(&action);
-        self.quorum_for_action(action_id).set(self.quorum().get());
+        let _ = self.pending_action_ids().insert(action_id);
+        self.set_action_expiry(action_id, opt_expiry);
+        self.quorum_for_action(action_id)
+            .set(self.get_action_type_quorum(action.action_type()));
         if caller_role.can_sign() {
             // also sign
             // since the action is newly created, the caller can be the only signer
             let _ = self.action_signer_ids(action_id).insert(caller_id);
         }
+        self.update_quorum_reached_timestamp(action_id);
 
         action_id
     }
@@ -31,26 +43,149 @@ pub trait MultisigProposeModule: crate::multisig_state::MultisigStateModule {
     /// Initiates board member addition process.
     /// Can also be used to promote a proposer to board member.
     #[endpoint(proposeAddBoardMember)]
-    fn propose_add_board_member(&self, board_member_address: ManagedAddress) -> ActionId {
-        self.propose_action(Action::AddBoardMember(board_member_address))
+    fn propose_add_board_member(
+        &self,
+        board_member_address: ManagedAddress,
+        opt_expiry: OptionalValue<u64>,
+    ) -> ActionId {
+        self.propose_action(
+            Action::AddBoardMember(board_member_address),
+            opt_expiry.into_option(),
+        )
     }
 
     /// Initiates proposer addition process..
     /// Can also be used to demote a board member to proposer.
     #[endpoint(proposeAddProposer)]
-    fn propose_add_proposer(&self, proposer_address: ManagedAddress) -> ActionId {
-        self.propose_action(Action::AddProposer(proposer_address))
+    fn propose_add_proposer(
+        &self,
+        proposer_address: ManagedAddress,
+        opt_expiry: OptionalValue<u64>,
+    ) -> ActionId {
+        self.propose_action(
+            Action::AddProposer(proposer_address),
+            opt_expiry.into_option(),
+        )
     }
 
     /// Removes user regardless of whether it is a board member or proposer.
     #[endpoint(proposeRemoveUser)]
-    fn propose_remove_user(&self, user_address: ManagedAddress) -> ActionId {
-        self.propose_action(Action::RemoveUser(user_address))
+    fn propose_remove_user(
+        &self,
+        user_address: ManagedAddress,
+        opt_expiry: OptionalValue<u64>,
+    ) -> ActionId {
+        self.propose_action(Action::RemoveUser(user_address), opt_expiry.into_option())
     }
 
     #[endpoint(proposeChangeQuorum)]
-    fn propose_change_quorum(&self, new_quorum: usize) -> ActionId {
-        self.propose_action(Action::ChangeQuorum(new_quorum))
+    fn propose_change_quorum(&self, new_quorum: usize, opt_expiry: OptionalValue<u64>) -> ActionId {
+        self.propose_action(Action::ChangeQuorum(new_quorum), opt_expiry.into_option())
+    }
+
+    /// Sets a quorum specific to one action type, e.g. unanimity for upgrades.
+    /// Without a quorum, the action type goes back to using the global quorum.
+    /// Only applies to actions proposed afterwards.
+    #[endpoint(proposeChangeActionTypeQuorum)]
+    fn propose_change_action_type_quorum(
+        &self,
+        action_type: ActionType,
+        opt_new_quorum: OptionalValue<usize>,
+    ) -> ActionId {
+        self.propose_action(
+            Action::ChangeActionTypeQuorum {
+                action_type,
+                opt_new_quorum: opt_new_quorum.into_option(),
+            },
+            None,
+        )
+    }
+
+    /// Changes how much the signature of a board member counts towards quorum.
+    /// Board members have a weight of 1 by default.
+    #[endpoint(proposeChangeSignerWeight)]
+    fn propose_change_signer_weight(
+        &self,
+        board_member: ManagedAddress,
+        new_weight: usize,
+        opt_expiry: OptionalValue<u64>,
+    ) -> ActionId {
+        self.propose_action(
+            Action::ChangeSignerWeight {
+                board_member,
+                new_weight,
+            },
+            opt_expiry.into_option(),
+        )
+    }
+
+    /// Allows any board member to send up to `amount` of the token every `period` seconds,
+    /// without a proposal. Replaces the previous limit of the token, if any.
+    #[endpoint(proposeSetSpendingLimit)]
+    fn propose_set_spending_limit(
+        &self,
+        token_id: EgldOrEsdtTokenIdentifier,
+        amount: BigUint,
+        period: u64,
+        opt_expiry: OptionalValue<u64>,
+    ) -> ActionId {
+        require!(amount > 0, "spending limit cannot be zero");
+        require!(period > 0, "spending limit period cannot be zero");
+
+        self.propose_action(
+            Action::ChangeSpendingLimit {
+                token_id,
+                opt_limit: Some(SpendingLimit { amount, period }),
+            },
+            opt_expiry.into_option(),
+        )
+    }
+
+    /// After this, every transfer of the token needs quorum again.
+    #[endpoint(proposeRemoveSpendingLimit)]
+    fn propose_remove_spending_limit(
+        &self,
+        token_id: EgldOrEsdtTokenIdentifier,
+        opt_expiry: OptionalValue<u64>,
+    ) -> ActionId {
+        self.propose_action(
+            Action::ChangeSpendingLimit {
+                token_id,
+                opt_limit: None,
+            },
+            opt_expiry.into_option(),
+        )
+    }
+
+    /// Changes the delay between an action reaching quorum and it being performable.
+    /// Only applies to action types that have no delay of their own.
+    #[endpoint(proposeChangeTimelockDelay)]
+    fn propose_change_timelock_delay(
+        &self,
+        new_delay: u64,
+        opt_expiry: OptionalValue<u64>,
+    ) -> ActionId {
+        self.propose_action(
+            Action::ChangeTimelockDelay(new_delay),
+            opt_expiry.into_option(),
+        )
+    }
+
+    /// Sets a timelock delay specific to one action type, overriding the global one.
+    /// Without a delay, the action type goes back to using the global delay.
+    #[endpoint(proposeChangeActionTypeTimelockDelay)]
+    fn propose_change_action_type_timelock_delay(
+        &self,
+        action_type: ActionType,
+        opt_new_delay: OptionalValue<u64>,
+    ) -> ActionId {
+        self.propose_action(
+            Action::ChangeActionTypeTimelockDelay {
+                action_type,
+                opt_new_delay: opt_new_delay.into_option(),
+            },
+            None,
+        )
     }
 
     /// Propose a transaction in which the contract will perform a transfer-execute call.
@@ -78,7 +213,7 @@ pub trait MultisigProposeModule: crate::multisig_state::MultisigStateModule {
             arguments: function_call.arg_buffer.into_vec_of_buffers(),
         };
 
-        self.propose_action(Action::SendTransferExecuteEgld(call_data))
+        self.propose_action(Action::SendTransferExecuteEgld(call_data), None)
     }
 
     #[endpoint(proposeTransferExecuteEsdt)]
@@ -99,7 +234,44 @@ pub trait MultisigProposeModule: crate::multisig_state::MultisigStateModule {
             arguments: function_call.arg_buffer.into_vec_of_buffers(),
         };
 
-        self.propose_action(Action::SendTransferExecuteEsdt(call_data))
+        self.propose_action(Action::SendTransferExecuteEsdt(call_data), None)
+    }
+
+    /// Same as `proposeTransferExecuteEsdt`, for a single NFT or SFT.
+    #[endpoint(proposeTransferNft)]
+    fn propose_transfer_nft(
+        &self,
+        to: ManagedAddress,
+        token_id: TokenIdentifier,
+        nonce: u64,
+        amount: BigUint,
+        opt_gas_limit: Option<GasLimit>,
+        function_call: FunctionCall,
+    ) -> ActionId {
+        require!(nonce > 0, "NFT nonce cannot be zero");
+        require!(amount > 0, "amount cannot be zero");
+
+        let tokens = ManagedVec::from_single_item(EsdtTokenPayment::new(token_id, nonce, amount));
+        self.propose_transfer_execute_esdt(to, tokens, opt_gas_limit, function_call)
+    }
+
+    /// Proposes a transfer of several tokens at once, given as (token, nonce, amount) triples.
+    /// Only sends the tokens, without calling any endpoint.
+    #[endpoint(proposeTransferMultiToken)]
+    fn propose_transfer_multi_token(
+        &self,
+        to: ManagedAddress,
+        opt_gas_limit: Option<GasLimit>,
+        tokens: MultiValueEncoded<MultiValue3<TokenIdentifier, u64, BigUint>>,
+    ) -> ActionId {
+        let mut payments = PaymentsVec::new();
+        for token in tokens {
+            let (token_id, nonce, amount) = token.into_tuple();
+            require!(amount > 0, "amount cannot be zero");
+            payments.push(EsdtTokenPayment::new(token_id, nonce, amount));
+        }
+
+        self.propose_transfer_execute_esdt(to, payments, opt_gas_limit, FunctionCall::empty())
     }
 
     /// Propose a transaction in which the contract will perform an async call.
@@ -128,7 +300,7 @@ pub trait MultisigProposeModule: crate::multisig_state::MultisigStateModule {
             arguments: function_call.arg_buffer.into_vec_of_buffers(),
         };
 
-        self.propose_action(Action::SendAsyncCall(call_data))
+        self.propose_action(Action::SendAsyncCall(call_data), None)
     }
 
     #[endpoint(proposeSCDeployFromSource)]
@@ -139,12 +311,15 @@ pub trait MultisigProposeModule: crate::multisig_state::MultisigStateModule {
         code_metadata: CodeMetadata,
         arguments: MultiValueEncoded<ManagedBuffer>,
     ) -> ActionId {
-        self.propose_action(Action::SCDeployFromSource {
-            amount,
-            source,
-            code_metadata,
-            arguments: arguments.into_vec_of_buffers(),
-        })
+        self.propose_action(
+            Action::SCDeployFromSource {
+                amount,
+                source,
+                code_metadata,
+                arguments: arguments.into_vec_of_buffers(),
+            },
+            None,
+        )
     }
 
     #[endpoint(proposeSCUpgradeFromSource)]
@@ -156,13 +331,16 @@ pub trait MultisigProposeModule: crate::multisig_state::MultisigStateModule {
         code_metadata: CodeMetadata,
         arguments: MultiValueEncoded<ManagedBuffer>,
     ) -> ActionId {
-        self.propose_action(Action::SCUpgradeFromSource {
-            sc_address,
-            amount,
-            source,
-            code_metadata,
-            arguments: arguments.into_vec_of_buffers(),
-        })
+        self.propose_action(
+            Action::SCUpgradeFromSource {
+                sc_address,
+                amount,
+                source,
+                code_metadata,
+                arguments: arguments.into_vec_of_buffers(),
+            },
+            None,
+        )
     }
 
     /// Propose a transaction in which the contract will perform a sync call.
@@ -191,11 +369,50 @@ pub trait MultisigProposeModule: crate::multisig_state::MultisigStateModule {
             arguments: function_call.arg_buffer.into_vec_of_buffers(),
         };
 
-        self.propose_action(Action::SendSyncCall(call_data))
+        self.propose_action(Action::SendSyncCall(call_data), None)
+    }
+
+    /// Proposes any single action with an expiry.
+    /// Meant for the actions whose `propose*` endpoint can not take a trailing expiry,
+    /// because it ends with a variable number of arguments or with an optional one.
+    #[endpoint(proposeWithExpiry)]
+    fn propose_with_expiry(&self, expiry: u64, action: Action<Self::Api>) -> ActionId {
+        self.require_valid_proposal(&action);
+
+        self.propose_action(action, Some(expiry))
+    }
+
+    /// Issues or manages an ESDT token owned by the multisig: roles, minting, burning, pausing.
+    /// The issue cost is paid from the EGLD balance of the multisig.
+    #[endpoint(proposeManageEsdt)]
+    fn propose_manage_esdt(
+        &self,
+        operation: EsdtOperation<Self::Api>,
+        opt_expiry: OptionalValue<u64>,
+    ) -> ActionId {
+        self.propose_action(Action::ManageEsdt(operation), opt_expiry.into_option())
     }
 
     #[endpoint(proposeBatch)]
     fn propose_batch(&self, actions: MultiValueEncoded<Action<Self::Api>>) -> GroupId {
+        self.propose_action_batch(actions, None)
+    }
+
+    /// Proposes a batch whose actions all expire at the given block timestamp.
+    #[endpoint(proposeBatchWithExpiry)]
+    fn propose_batch_with_expiry(
+        &self,
+        expiry: u64,
+        actions: MultiValueEncoded<Action<Self::Api>>,
+    ) -> GroupId {
+        self.propose_action_batch(actions, Some(expiry))
+    }
+
+    fn propose_action_batch(
+        &self,
+        actions: MultiValueEncoded<Action<Self::Api>>,
+        opt_expiry: Option<u64>,
+    ) -> GroupId {
         let group_id = self.last_action_group_id().get() + 1;
         require!(!actions.is_empty(), "No actions");
 
@@ -204,6 +421,7 @@ pub trait MultisigProposeModule: crate::multisig_state::MultisigStateModule {
             caller_role.can_propose(),
             "only board members and proposers can propose"
         );
+        self.require_valid_expiry(opt_expiry);
 
         let mut action_mapper = self.action_mapper();
         let mut action_groups_mapper = self.action_groups(group_id);
@@ -220,17 +438,61 @@ pub trait MultisigProposeModule: crate::multisig_state::MultisigStateModule {
             self.ensure_valid_transfer_action(&action);
 
             let action_id = action_mapper.push(&action);
+            let _ = self.pending_action_ids().insert(action_id);
+            self.set_action_expiry(action_id, opt_expiry);
+            self.quorum_for_action(action_id)
+                .set(self.get_action_type_quorum(action.action_type()));
             if caller_role.can_sign() {
                 let _ = self.action_signer_ids(action_id).insert(caller_id);
             }
 
             let _ = action_groups_mapper.insert(action_id);
             self.group_for_action(action_id).set(group_id);
+            self.update_quorum_reached_timestamp(action_id);
         }
         self.last_action_group_id().set(group_id);
         group_id
     }
 
+    /// The checks that the dedicated `propose*` endpoints do on their arguments.
+    fn require_valid_proposal(&self, action: &Action<Self::Api>) {
+        match action {
+            Action::Nothing => sc_panic!("proposed action has no effect"),
+            Action::SendTransferExecuteEgld(call_data)
+            | Action::SendAsyncCall(call_data)
+            | Action::SendSyncCall(call_data) => require!(
+                call_data.egld_amount > 0 || !call_data.endpoint_name.is_empty(),
+                "proposed action has no effect"
+            ),
+            Action::SendTransferExecuteEsdt(call_data) => {
+                require!(!call_data.tokens.is_empty(), "No tokens to transfer")
+            }
+            Action::ChangeSpendingLimit {
+                opt_limit: Some(limit),
+                ..
+            } => {
+                require!(limit.amount > 0, "spending limit cannot be zero");
+                require!(limit.period > 0, "spending limit period cannot be zero");
+            }
+            _ => {}
+        }
+    }
+
+    fn require_valid_expiry(&self, opt_expiry: Option<u64>) {
+        if let Some(expiry) = opt_expiry {
+            require!(
+                expiry > self.blockchain().get_block_timestamp(),
+                "expiry must be in the future"
+            );
+        }
+    }
+
+    fn set_action_expiry(&self, action_id: ActionId, opt_expiry: Option<u64>) {
+        if let Some(expiry) = opt_expiry {
+            self.action_expiry(action_id).set(expiry);
+        }
+    }
+
     fn require_valid_action_type(&self, action: &Action<Self::Api>) {
         require!(
             !action.is_nothing() && !action.is_async_call() && !action.is_sc_upgrade(),
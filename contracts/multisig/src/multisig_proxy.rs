@@ -89,6 +89,9 @@ where
     Gas: TxGas<Env>,
 {
     /// Allows the contract to receive funds even if it is marked as unpayable in the protocol. 
+    /// ESDT tokens deposited by board members and proposers are recorded, so that they show up in `getTreasury`. 
+    /// Anyone else can deposit too, but their tokens are not recorded, 
+    /// so that the recorded tokens can not be flooded with unsolicited ones. 
     pub fn deposit(
         self,
     ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
@@ -101,7 +104,8 @@ where
     /// Serialized full action data: 
     /// - the action id 
     /// - the serialized action data 
-    /// - (number of signers followed by) list of signer addresses. 
+    /// - (number of signers followed by) list of signer addresses 
+    /// - the seconds left until the action can be performed, if it reached quorum. 
     pub fn get_pending_action_full_info<
         Arg0: ProxyArg<OptionalValue<(usize, usize)>>,
     >(
@@ -182,6 +186,24 @@ where
             .original_result()
     }
 
+    /// Turns all expired actions with IDs in the given range, including both ends, into `Nothing` 
+    /// and removes their signatures. Anyone can call this. 
+    pub fn cleanup_expired<
+        Arg0: ProxyArg<usize>,
+        Arg1: ProxyArg<usize>,
+    >(
+        self,
+        first_action_id: Arg0,
+        last_action_id: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("cleanupExpired")
+            .argument(&first_action_id)
+            .argument(&last_action_id)
+            .original_result()
+    }
+
     /// Minimum number of signatures needed to perform any action. 
     pub fn quorum(
         self,
@@ -283,6 +305,21 @@ where
             .original_result()
     }
 
+    /// Block timestamp after which the action can no longer be signed or performed. 
+    /// `0` means the action does not expire. 
+    pub fn action_expiry<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        action_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getActionExpiry")
+            .argument(&action_id)
+            .original_result()
+    }
+
     /// Gets addresses of all users who signed an action. 
     /// Does not check if those users are still board members or not, 
     /// so the result may contain invalid signers. 
@@ -319,6 +356,7 @@ where
     /// therefore the contract needs to re-check every time when actions are performed. 
     /// This function is used to validate the signers before performing an action. 
     /// It also makes it easy to check before performing an action. 
+    /// Each valid signer counts with its weight, which is 1 unless configured otherwise. 
     pub fn get_action_valid_signer_count<
         Arg0: ProxyArg<usize>,
     >(
@@ -332,18 +370,76 @@ where
             .original_result()
     }
 
+    /// Returns `true` (`1`) if `getActionValidSignerCount >= getQuorum`. 
+    /// The quorum is the one that applied to the action type when the action was proposed. 
+    pub fn quorum_reached<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        action_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("quorumReached")
+            .argument(&action_id)
+            .original_result()
+    }
+
+    /// The quorum that newly proposed actions of the given type need to reach. 
+    pub fn get_action_type_quorum<
+        Arg0: ProxyArg<ActionType>,
+    >(
+        self,
+        action_type: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getActionTypeQuorum")
+            .argument(&action_type)
+            .original_result()
+    }
+
+    /// Weight of the user's signature. 
+    /// Users that are not board members have no weight. 
+    pub fn get_signer_weight<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        user: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getSignerWeight")
+            .argument(&user)
+            .original_result()
+    }
+
+    /// Sum of the weights of all board members. 
+    /// All quorums must stay within this value. 
+    pub fn get_total_board_weight(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTotalBoardWeight")
+            .original_result()
+    }
+
     /// Initiates board member addition process. 
     /// Can also be used to promote a proposer to board member. 
     pub fn propose_add_board_member<
         Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<OptionalValue<u64>>,
     >(
         self,
         board_member_address: Arg0,
+        opt_expiry: Arg1,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
         self.wrapped_tx
             .payment(NotPayable)
             .raw_call("proposeAddBoardMember")
             .argument(&board_member_address)
+            .argument(&opt_expiry)
             .original_result()
     }
 
@@ -351,41 +447,167 @@ where
     /// Can also be used to demote a board member to proposer. 
     pub fn propose_add_proposer<
         Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<OptionalValue<u64>>,
     >(
         self,
         proposer_address: Arg0,
+        opt_expiry: Arg1,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
         self.wrapped_tx
             .payment(NotPayable)
             .raw_call("proposeAddProposer")
             .argument(&proposer_address)
+            .argument(&opt_expiry)
             .original_result()
     }
 
     /// Removes user regardless of whether it is a board member or proposer. 
     pub fn propose_remove_user<
         Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<OptionalValue<u64>>,
     >(
         self,
         user_address: Arg0,
+        opt_expiry: Arg1,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
         self.wrapped_tx
             .payment(NotPayable)
             .raw_call("proposeRemoveUser")
             .argument(&user_address)
+            .argument(&opt_expiry)
             .original_result()
     }
 
     pub fn propose_change_quorum<
         Arg0: ProxyArg<usize>,
+        Arg1: ProxyArg<OptionalValue<u64>>,
     >(
         self,
         new_quorum: Arg0,
+        opt_expiry: Arg1,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
         self.wrapped_tx
             .payment(NotPayable)
             .raw_call("proposeChangeQuorum")
             .argument(&new_quorum)
+            .argument(&opt_expiry)
+            .original_result()
+    }
+
+    /// Sets a quorum specific to one action type, e.g. unanimity for upgrades. 
+    /// Without a quorum, the action type goes back to using the global quorum. 
+    /// Only applies to actions proposed afterwards. 
+    pub fn propose_change_action_type_quorum<
+        Arg0: ProxyArg<ActionType>,
+        Arg1: ProxyArg<OptionalValue<usize>>,
+    >(
+        self,
+        action_type: Arg0,
+        opt_new_quorum: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("proposeChangeActionTypeQuorum")
+            .argument(&action_type)
+            .argument(&opt_new_quorum)
+            .original_result()
+    }
+
+    /// Changes how much the signature of a board member counts towards quorum. 
+    /// Board members have a weight of 1 by default. 
+    pub fn propose_change_signer_weight<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<usize>,
+        Arg2: ProxyArg<OptionalValue<u64>>,
+    >(
+        self,
+        board_member: Arg0,
+        new_weight: Arg1,
+        opt_expiry: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("proposeChangeSignerWeight")
+            .argument(&board_member)
+            .argument(&new_weight)
+            .argument(&opt_expiry)
+            .original_result()
+    }
+
+    /// Allows any board member to send up to `amount` of the token every `period` seconds, 
+    /// without a proposal. Replaces the previous limit of the token, if any. 
+    pub fn propose_set_spending_limit<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+        Arg2: ProxyArg<u64>,
+        Arg3: ProxyArg<OptionalValue<u64>>,
+    >(
+        self,
+        token_id: Arg0,
+        amount: Arg1,
+        period: Arg2,
+        opt_expiry: Arg3,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("proposeSetSpendingLimit")
+            .argument(&token_id)
+            .argument(&amount)
+            .argument(&period)
+            .argument(&opt_expiry)
+            .original_result()
+    }
+
+    /// After this, every transfer of the token needs quorum again. 
+    pub fn propose_remove_spending_limit<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<OptionalValue<u64>>,
+    >(
+        self,
+        token_id: Arg0,
+        opt_expiry: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("proposeRemoveSpendingLimit")
+            .argument(&token_id)
+            .argument(&opt_expiry)
+            .original_result()
+    }
+
+    /// Changes the delay between an action reaching quorum and it being performable. 
+    /// Only applies to action types that have no delay of their own. 
+    pub fn propose_change_timelock_delay<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<OptionalValue<u64>>,
+    >(
+        self,
+        new_delay: Arg0,
+        opt_expiry: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("proposeChangeTimelockDelay")
+            .argument(&new_delay)
+            .argument(&opt_expiry)
+            .original_result()
+    }
+
+    /// Sets a timelock delay specific to one action type, overriding the global one. 
+    /// Without a delay, the action type goes back to using the global delay. 
+    pub fn propose_change_action_type_timelock_delay<
+        Arg0: ProxyArg<ActionType>,
+        Arg1: ProxyArg<OptionalValue<u64>>,
+    >(
+        self,
+        action_type: Arg0,
+        opt_new_delay: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("proposeChangeActionTypeTimelockDelay")
+            .argument(&action_type)
+            .argument(&opt_new_delay)
             .original_result()
     }
 
@@ -437,6 +659,56 @@ where
             .original_result()
     }
 
+    /// Same as `proposeTransferExecuteEsdt`, for a single NFT or SFT. 
+    pub fn propose_transfer_nft<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<TokenIdentifier<Env::Api>>,
+        Arg2: ProxyArg<u64>,
+        Arg3: ProxyArg<BigUint<Env::Api>>,
+        Arg4: ProxyArg<Option<u64>>,
+        Arg5: ProxyArg<FunctionCall<Env::Api>>,
+    >(
+        self,
+        to: Arg0,
+        token_id: Arg1,
+        nonce: Arg2,
+        amount: Arg3,
+        opt_gas_limit: Arg4,
+        function_call: Arg5,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("proposeTransferNft")
+            .argument(&to)
+            .argument(&token_id)
+            .argument(&nonce)
+            .argument(&amount)
+            .argument(&opt_gas_limit)
+            .argument(&function_call)
+            .original_result()
+    }
+
+    /// Proposes a transfer of several tokens at once, given as (token, nonce, amount) triples. 
+    /// Only sends the tokens, without calling any endpoint. 
+    pub fn propose_transfer_multi_token<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<Option<u64>>,
+        Arg2: ProxyArg<MultiValueEncoded<Env::Api, MultiValue3<TokenIdentifier<Env::Api>, u64, BigUint<Env::Api>>>>,
+    >(
+        self,
+        to: Arg0,
+        opt_gas_limit: Arg1,
+        tokens: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("proposeTransferMultiToken")
+            .argument(&to)
+            .argument(&opt_gas_limit)
+            .argument(&tokens)
+            .original_result()
+    }
+
     /// Propose a transaction in which the contract will perform an async call call. 
     /// Can call smart contract endpoints directly. 
     /// Can use ESDTTransfer/ESDTNFTTransfer/MultiESDTTransfer to send tokens, while also optionally calling endpoints. 
@@ -511,6 +783,70 @@ where
             .original_result()
     }
 
+    /// Propose a transaction in which the contract will perform a sync call. 
+    /// Can call smart contract endpoints directly. 
+    /// Can use ESDTTransfer/ESDTNFTTransfer/MultiESDTTransfer to send tokens, while also optionally calling endpoints. 
+    /// Works well with builtin functions. 
+    /// Cannot simply send EGLD directly without calling anything. 
+    pub fn propose_sync_call<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+        Arg2: ProxyArg<Option<u64>>,
+        Arg3: ProxyArg<FunctionCall<Env::Api>>,
+    >(
+        self,
+        to: Arg0,
+        egld_amount: Arg1,
+        opt_gas_limit: Arg2,
+        function_call: Arg3,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("proposeSyncCall")
+            .argument(&to)
+            .argument(&egld_amount)
+            .argument(&opt_gas_limit)
+            .argument(&function_call)
+            .original_result()
+    }
+
+    /// Proposes any single action with an expiry. 
+    /// Meant for the actions whose `propose*` endpoint can not take a trailing expiry, 
+    /// because it ends with a variable number of arguments or with an optional one. 
+    pub fn propose_with_expiry<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<Action<Env::Api>>,
+    >(
+        self,
+        expiry: Arg0,
+        action: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("proposeWithExpiry")
+            .argument(&expiry)
+            .argument(&action)
+            .original_result()
+    }
+
+    /// Issues or manages an ESDT token owned by the multisig: roles, minting, burning, pausing. 
+    /// The issue cost is paid from the EGLD balance of the multisig. 
+    pub fn propose_manage_esdt<
+        Arg0: ProxyArg<EsdtOperation<Env::Api>>,
+        Arg1: ProxyArg<OptionalValue<u64>>,
+    >(
+        self,
+        operation: Arg0,
+        opt_expiry: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("proposeManageEsdt")
+            .argument(&operation)
+            .argument(&opt_expiry)
+            .original_result()
+    }
+
     pub fn propose_batch<
         Arg0: ProxyArg<MultiValueEncoded<Env::Api, Action<Env::Api>>>,
     >(
@@ -524,6 +860,23 @@ where
             .original_result()
     }
 
+    /// Proposes a batch whose actions all expire at the given block timestamp. 
+    pub fn propose_batch_with_expiry<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<MultiValueEncoded<Env::Api, Action<Env::Api>>>,
+    >(
+        self,
+        expiry: Arg0,
+        actions: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("proposeBatchWithExpiry")
+            .argument(&expiry)
+            .argument(&actions)
+            .original_result()
+    }
+
     /// Used by board members to sign actions. 
     pub fn sign<
         Arg0: ProxyArg<usize>,
@@ -641,20 +994,6 @@ where
             .original_result()
     }
 
-    /// Returns `true` (`1`) if `getActionValidSignerCount >= getQuorum`. 
-    pub fn quorum_reached<
-        Arg0: ProxyArg<usize>,
-    >(
-        self,
-        action_id: Arg0,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
-        self.wrapped_tx
-            .payment(NotPayable)
-            .raw_call("quorumReached")
-            .argument(&action_id)
-            .original_result()
-    }
-
     /// Proposers and board members use this to launch signed actions. 
     pub fn perform_action_endpoint<
         Arg0: ProxyArg<usize>,
@@ -683,6 +1022,175 @@ where
             .original_result()
     }
 
+    /// Delay, in seconds, between reaching quorum and being able to perform an action. 
+    /// Applies to all action types that have no delay of their own. 
+    pub fn timelock_delay(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTimelockDelay")
+            .original_result()
+    }
+
+    /// Block timestamp of the moment the action reached quorum. 
+    /// `None` while the action does not have enough valid signatures. 
+    /// Stored as an `Option`, so that a timestamp of 0 can be told apart from an empty entry. 
+    pub fn quorum_reached_timestamp<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        action_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, Option<u64>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getQuorumReachedTimestamp")
+            .argument(&action_id)
+            .original_result()
+    }
+
+    /// The delay that applies to the given action type: 
+    /// its own delay if one was set, the global delay otherwise. 
+    pub fn get_action_type_timelock_delay<
+        Arg0: ProxyArg<ActionType>,
+    >(
+        self,
+        action_type: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getActionTypeTimelockDelay")
+            .argument(&action_type)
+            .original_result()
+    }
+
+    /// Sends tokens directly, without a proposal, as long as the amount fits in the 
+    /// remaining allowance of the token, i.e. the limit minus what was spent in the last `period` seconds. 
+    /// Only board members can spend. 
+    pub fn spend_within_limit<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+        Arg2: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        to: Arg0,
+        token_id: Arg1,
+        amount: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("spendWithinLimit")
+            .argument(&to)
+            .argument(&token_id)
+            .argument(&amount)
+            .original_result()
+    }
+
+    /// How much of the token can still be spent without quorum right now. 
+    pub fn get_remaining_allowance<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getRemainingAllowance")
+            .argument(&token_id)
+            .original_result()
+    }
+
+    pub fn get_spending_limit<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, Option<SpendingLimit<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getSpendingLimit")
+            .argument(&token_id)
+            .original_result()
+    }
+
+    /// Performs an action that was never proposed, based on ed25519 signatures of board members. 
+    /// Each signer signs the message returned by `getSignatureMessage`. 
+    ///  
+    /// The nonce is picked by the signers and can only be used once, so several signed actions 
+    /// can be collected at the same time. The signatures are no longer valid after the expiry timestamp. 
+    ///  
+    /// Signatures are checked in order. The action is performed as soon as the signer weights 
+    /// reach the quorum of its action type; the signatures that follow are ignored. 
+    /// Action types that have a timelock delay can only go through the regular flow. 
+    /// The action gets an ID like a proposed one, which is returned by the perform events. 
+    pub fn perform_with_signatures<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<u64>,
+        Arg2: ProxyArg<Action<Env::Api>>,
+        Arg3: ProxyArg<MultiValueEncoded<Env::Api, MultiValue2<ManagedAddress<Env::Api>, ManagedByteArray<Env::Api, 64usize>>>>,
+    >(
+        self,
+        nonce: Arg0,
+        expiry: Arg1,
+        action: Arg2,
+        signatures: Arg3,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("performWithSignatures")
+            .argument(&nonce)
+            .argument(&expiry)
+            .argument(&action)
+            .argument(&signatures)
+            .original_result()
+    }
+
+    /// The message board members sign to authorize the action with `performWithSignatures`: 
+    /// the contract address, the signature nonce, the expiry timestamp and the top-encoded action. 
+    pub fn get_signature_message<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<u64>,
+        Arg2: ProxyArg<Action<Env::Api>>,
+    >(
+        self,
+        nonce: Arg0,
+        expiry: Arg1,
+        action: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedBuffer<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getSignatureMessage")
+            .argument(&nonce)
+            .argument(&expiry)
+            .argument(&action)
+            .original_result()
+    }
+
+    /// Whether an action was already performed with this signature nonce. 
+    pub fn signature_nonce_used<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        nonce: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isSignatureNonceUsed")
+            .argument(&nonce)
+            .original_result()
+    }
+
+    /// Lists the current balance of EGLD and of every token the multisig has recorded, 
+    /// skipping the ones that have been spent entirely. 
+    pub fn get_treasury(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, EgldOrEsdtTokenPayment<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTreasury")
+            .original_result()
+    }
+
     pub fn dns_register<
         Arg0: ProxyArg<ManagedAddress<Env::Api>>,
         Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
@@ -700,7 +1208,7 @@ where
 }
 
 #[type_abi]
-#[derive(TopEncode)]
+#[derive(TopEncode, TopDecode)]
 pub struct ActionFullInfo<Api>
 where
     Api: ManagedTypeApi,
@@ -709,6 +1217,7 @@ where
     pub group_id: usize,
     pub action_data: Action<Api>,
     pub signers: ManagedVec<Api, ManagedAddress<Api>>,
+    pub opt_timelock_remaining: Option<u64>,
 }
 
 #[rustfmt::skip]
@@ -739,6 +1248,25 @@ where
         code_metadata: CodeMetadata,
         arguments: ManagedVec<Api, ManagedBuffer<Api>>,
     },
+    SendSyncCall(CallActionData<Api>),
+    ChangeTimelockDelay(u64),
+    ChangeActionTypeTimelockDelay {
+        action_type: ActionType,
+        opt_new_delay: Option<u64>,
+    },
+    ChangeActionTypeQuorum {
+        action_type: ActionType,
+        opt_new_quorum: Option<usize>,
+    },
+    ChangeSignerWeight {
+        board_member: ManagedAddress<Api>,
+        new_weight: usize,
+    },
+    ChangeSpendingLimit {
+        token_id: EgldOrEsdtTokenIdentifier<Api>,
+        opt_limit: Option<SpendingLimit<Api>>,
+    },
+    ManageEsdt(EsdtOperation<Api>),
 }
 
 #[type_abi]
@@ -767,6 +1295,69 @@ where
     pub arguments: ManagedVec<Api, ManagedBuffer<Api>>,
 }
 
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ActionType {
+    Nothing,
+    AddBoardMember,
+    AddProposer,
+    RemoveUser,
+    ChangeQuorum,
+    SendTransferExecuteEgld,
+    SendTransferExecuteEsdt,
+    SendAsyncCall,
+    SCDeployFromSource,
+    SCUpgradeFromSource,
+    SendSyncCall,
+    ChangeTimelockDelay,
+    ChangeActionTypeTimelockDelay,
+    ChangeActionTypeQuorum,
+    ChangeSignerWeight,
+    ChangeSpendingLimit,
+    ManageEsdt,
+}
+
+#[type_abi]
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, Clone, PartialEq, Debug)]
+pub struct SpendingLimit<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub amount: BigUint<Api>,
+    pub period: u64,
+}
+
+#[rustfmt::skip]
+#[type_abi]
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, Clone)]
+pub enum EsdtOperation<Api>
+where
+    Api: ManagedTypeApi,
+{
+    IssueFungible {
+        issue_cost: BigUint<Api>,
+        token_display_name: ManagedBuffer<Api>,
+        token_ticker: ManagedBuffer<Api>,
+        initial_supply: BigUint<Api>,
+        num_decimals: usize,
+    },
+    SetSpecialRoles {
+        token_id: TokenIdentifier<Api>,
+        address: ManagedAddress<Api>,
+        roles: ManagedVec<Api, EsdtLocalRole>,
+    },
+    LocalMint {
+        token_id: TokenIdentifier<Api>,
+        amount: BigUint<Api>,
+    },
+    LocalBurn {
+        token_id: TokenIdentifier<Api>,
+        amount: BigUint<Api>,
+    },
+    Pause(TokenIdentifier<Api>),
+    Unpause(TokenIdentifier<Api>),
+}
+
 #[type_abi]
 #[derive(TopEncode, TopDecode, Clone, Copy, PartialEq, Eq, Debug)]
 pub enum UserRole {
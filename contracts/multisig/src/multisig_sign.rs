@@ -8,6 +8,9 @@ pub trait MultisigSignModule:
     + crate::multisig_propose::MultisigProposeModule
     + crate::multisig_perform::MultisigPerformModule
     + crate::multisig_events::MultisigEventsModule
+    + crate::multisig_timelock::MultisigTimelockModule
+    + crate::multisig_spending_limit::MultisigSpendingLimitModule
+    + crate::multisig_treasury::MultisigTreasuryModule
 {
     /// Used by board members to sign actions.
     #[endpoint]
@@ -24,10 +27,12 @@ pub trait MultisigSignModule:
                 "cannot sign actions of an aborted batch"
             );
         }
+        require!(!self.is_action_expired(action_id), "action expired");
         let (caller_id, caller_role) = self.get_caller_id_and_role();
         require!(caller_role.can_sign(), "only board members can sign");
 
         let _ = self.action_signer_ids(action_id).insert(caller_id);
+        self.update_quorum_reached_timestamp(action_id);
     }
 
     /// Sign all the actions in the given batch
@@ -49,8 +54,10 @@ pub trait MultisigSignModule:
                 !self.action_mapper().item_is_empty_unchecked(action_id),
                 "action does not exist"
             );
+            require!(!self.is_action_expired(action_id), "action expired");
 
             let _ = self.action_signer_ids(action_id).insert(caller_id);
+            self.update_quorum_reached_timestamp(action_id);
         }
     }
 
@@ -70,15 +77,16 @@ pub trait MultisigSignModule:
             "only board members and proposers can perform actions"
         );
 
-        let mut quorums_reached = true;
+        let mut all_performable = true;
 
         for action_id in self.action_groups(group_id).iter() {
-            if !self.quorum_reached(action_id) {
-                quorums_reached = false;
+            let action = self.action_mapper().get(action_id);
+            if !self.quorum_reached(action_id) || !self.is_timelock_elapsed(action_id, &action) {
+                all_performable = false;
             }
         }
 
-        if !quorums_reached {
+        if !all_performable {
             return;
         }
 
@@ -124,6 +132,7 @@ pub trait MultisigSignModule:
         );
 
         let _ = self.action_signer_ids(action_id).swap_remove(&caller_id);
+        self.update_quorum_reached_timestamp(action_id);
     }
 
     /// Returns `true` (`1`) if the user has signed the action.
@@ -161,5 +170,6 @@ pub trait MultisigSignModule:
         for member in board_members_to_remove.iter() {
             self.action_signer_ids(action_id).swap_remove(&member);
         }
+        self.update_quorum_reached_timestamp(action_id);
     }
 }
@@ -0,0 +1,124 @@
+use crate::action::Action;
+
+use multiversx_sc::{api::ED25519_SIGNATURE_BYTE_LEN, imports::*};
+
+pub type Signature<M> = ManagedByteArray<M, ED25519_SIGNATURE_BYTE_LEN>;
+
+/// Lets board members sign actions off-chain,
+/// so that a single transaction can carry all the signatures and perform the action.
+#[multiversx_sc::module]
+pub trait MultisigSignaturesModule:
+    crate::multisig_state::MultisigStateModule
+    + crate::multisig_events::MultisigEventsModule
+    + crate::multisig_timelock::MultisigTimelockModule
+    + crate::multisig_spending_limit::MultisigSpendingLimitModule
+    + crate::multisig_treasury::MultisigTreasuryModule
+    + crate::multisig_perform::MultisigPerformModule
+{
+    /// Performs an action that was never proposed, based on ed25519 signatures of board members.
+    /// Each signer signs the message returned by `getSignatureMessage`.
+    ///
+    /// The nonce is picked by the signers and can only be used once, so several signed actions
+    /// can be collected at the same time. The signatures are no longer valid after the expiry timestamp.
+    ///
+    /// Signatures are checked in order. The action is performed as soon as the signer weights
+    /// reach the quorum of its action type; the signatures that follow are ignored.
+    /// Action types that have a timelock delay can only go through the regular flow.
+    /// The action gets an ID like a proposed one, which is returned by the perform events.
+    #[endpoint(performWithSignatures)]
+    fn perform_with_signatures(
+        &self,
+        nonce: u64,
+        expiry: u64,
+        action: Action<Self::Api>,
+        signatures: MultiValueEncoded<MultiValue2<ManagedAddress, Signature<Self::Api>>>,
+    ) -> OptionalValue<ManagedAddress> {
+        let (_, caller_role) = self.get_caller_id_and_role();
+        require!(
+            caller_role.can_perform_action(),
+            "only board members and proposers can perform actions"
+        );
+
+        let action_type = action.action_type();
+        require!(
+            self.get_action_type_timelock_delay(action_type) == 0,
+            "action type is subject to a timelock"
+        );
+
+        require!(
+            self.blockchain().get_block_timestamp() <= expiry,
+            "signatures expired"
+        );
+        require!(
+            !self.signature_nonce_used(nonce).get(),
+            "signature nonce already used"
+        );
+
+        let message = self.get_signature_message(nonce, expiry, action.clone());
+        let quorum = self.get_action_type_quorum(action_type);
+        require!(quorum > 0, "quorum cannot be zero");
+
+        let mut signer_ids = ManagedVec::<Self::Api, usize>::new();
+        let mut signers = ManagedVec::new();
+        let mut signed_weight = 0;
+        for signature_entry in signatures {
+            if signed_weight >= quorum {
+                break;
+            }
+
+            let (signer, signature) = signature_entry.into_tuple();
+            let signer_id = self.user_mapper().get_user_id(&signer);
+            require!(
+                signer_id != 0 && self.user_id_to_role(signer_id).get().can_sign(),
+                "only board members can sign"
+            );
+            require!(!signer_ids.contains(&signer_id), "duplicate signer");
+
+            self.crypto().verify_ed25519(
+                signer.as_managed_buffer(),
+                &message,
+                signature.as_managed_buffer(),
+            );
+
+            signed_weight += self.get_user_weight(signer_id);
+            signer_ids.push(signer_id);
+            signers.push(signer);
+        }
+        require!(signed_weight >= quorum, "quorum has not been reached");
+
+        self.signature_nonce_used(nonce).set(true);
+
+        let action_id = self.action_mapper().push(&action);
+        self.clear_action(action_id);
+        self.perform_with_signatures_event(action_id, nonce, &signers, &action);
+
+        self.execute_action(action_id, action)
+    }
+
+    /// The message board members sign to authorize the action with `performWithSignatures`:
+    /// the contract address, the signature nonce, the expiry timestamp and the top-encoded action.
+    #[view(getSignatureMessage)]
+    fn get_signature_message(
+        &self,
+        nonce: u64,
+        expiry: u64,
+        action: Action<Self::Api>,
+    ) -> ManagedBuffer {
+        let mut message = self
+            .blockchain()
+            .get_sc_address()
+            .as_managed_buffer()
+            .clone();
+        message.append(&ManagedBuffer::from(&nonce.to_be_bytes()[..]));
+        message.append(&ManagedBuffer::from(&expiry.to_be_bytes()[..]));
+        let mut encoded_action = ManagedBuffer::new();
+        let _ = action.top_encode(&mut encoded_action);
+        message.append(&encoded_action);
+        message
+    }
+
+    /// Whether an action was already performed with this signature nonce.
+    #[view(isSignatureNonceUsed)]
+    #[storage_mapper("signature_nonce_used")]
+    fn signature_nonce_used(&self, nonce: u64) -> SingleValueMapper<bool>;
+}
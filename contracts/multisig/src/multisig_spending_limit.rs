@@ -0,0 +1,132 @@
+use crate::action::SpendingLimit;
+
+use multiversx_sc::derive_imports::*;
+use multiversx_sc::imports::*;
+
+/// A spend made without quorum. Counts towards the limit until `period` seconds after `timestamp`.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct Spend<M: ManagedTypeApi> {
+    pub timestamp: u64,
+    pub amount: BigUint<M>,
+}
+
+/// Lets any board member send small amounts without quorum,
+/// within budgets that the board approved per token.
+#[multiversx_sc::module]
+pub trait MultisigSpendingLimitModule:
+    crate::multisig_state::MultisigStateModule + crate::multisig_events::MultisigEventsModule
+{
+    /// Sends tokens directly, without a proposal, as long as the amount fits in the
+    /// remaining allowance of the token, i.e. the limit minus what was spent in the last `period` seconds.
+    /// Only board members can spend.
+    #[endpoint(spendWithinLimit)]
+    fn spend_within_limit(
+        &self,
+        to: ManagedAddress,
+        token_id: EgldOrEsdtTokenIdentifier,
+        amount: BigUint,
+    ) {
+        let (_, caller_role) = self.get_caller_id_and_role();
+        require!(caller_role.can_sign(), "only board members can spend");
+        require!(amount > 0, "amount cannot be zero");
+
+        let limit_mapper = self.spending_limit(&token_id);
+        require!(!limit_mapper.is_empty(), "no spending limit for token");
+
+        let limit = limit_mapper.get();
+        self.remove_expired_spends(&token_id, limit.period);
+
+        let spent_mapper = self.spent_in_period(&token_id);
+        let spent = spent_mapper.get() + &amount;
+        require!(spent <= limit.amount, "spending limit exceeded");
+        spent_mapper.set(&spent);
+
+        self.recent_spends(&token_id).push_back(Spend {
+            timestamp: self.blockchain().get_block_timestamp(),
+            amount: amount.clone(),
+        });
+
+        self.tx()
+            .to(&to)
+            .egld_or_single_esdt(&token_id, 0, &amount)
+            .transfer();
+
+        let caller = self.blockchain().get_caller();
+        self.spend_within_limit_event(&caller, &to, &token_id, &amount, &spent);
+    }
+
+    /// How much of the token can still be spent without quorum right now.
+    #[view(getRemainingAllowance)]
+    fn get_remaining_allowance(&self, token_id: EgldOrEsdtTokenIdentifier) -> BigUint {
+        let limit_mapper = self.spending_limit(&token_id);
+        if limit_mapper.is_empty() {
+            return BigUint::zero();
+        }
+
+        let limit = limit_mapper.get();
+        let spent = self.get_spent_in_period(&token_id, limit.period);
+        if spent >= limit.amount {
+            BigUint::zero()
+        } else {
+            limit.amount - spent
+        }
+    }
+
+    /// The sum of the spends of the last `period` seconds.
+    /// Expired spends are only removed on the next spend, so they are left out here.
+    fn get_spent_in_period(&self, token_id: &EgldOrEsdtTokenIdentifier, period: u64) -> BigUint {
+        let current_timestamp = self.blockchain().get_block_timestamp();
+        let mut spent = self.spent_in_period(token_id).get();
+        for spend in self.recent_spends(token_id).iter() {
+            if spend.timestamp + period > current_timestamp {
+                break;
+            }
+
+            spent -= spend.amount;
+        }
+
+        spent
+    }
+
+    /// Spends are stored in chronological order, so the expired ones are at the front.
+    fn remove_expired_spends(&self, token_id: &EgldOrEsdtTokenIdentifier, period: u64) {
+        let current_timestamp = self.blockchain().get_block_timestamp();
+        let mut spends_mapper = self.recent_spends(token_id);
+        let spent_mapper = self.spent_in_period(token_id);
+        while let Some(spend) = spends_mapper.front() {
+            if spend.timestamp + period > current_timestamp {
+                break;
+            }
+
+            let _ = spends_mapper.pop_front();
+            spent_mapper.update(|spent| *spent -= spend.amount);
+        }
+    }
+
+    #[view(getSpendingLimit)]
+    fn get_spending_limit(
+        &self,
+        token_id: EgldOrEsdtTokenIdentifier,
+    ) -> Option<SpendingLimit<Self::Api>> {
+        let limit_mapper = self.spending_limit(&token_id);
+        if limit_mapper.is_empty() {
+            None
+        } else {
+            Some(limit_mapper.get())
+        }
+    }
+
+    #[storage_mapper("spending_limit")]
+    fn spending_limit(
+        &self,
+        token_id: &EgldOrEsdtTokenIdentifier,
+    ) -> SingleValueMapper<SpendingLimit<Self::Api>>;
+
+    #[storage_mapper("recent_spends")]
+    fn recent_spends(&self, token_id: &EgldOrEsdtTokenIdentifier) -> QueueMapper<Spend<Self::Api>>;
+
+    /// Sum of `recent_spends`.
+    #[storage_mapper("spent_in_period")]
+    fn spent_in_period(&self, token_id: &EgldOrEsdtTokenIdentifier) -> SingleValueMapper<BigUint>;
+}
@@ -1,5 +1,8 @@
 use crate::multisig_perform::MAX_BOARD_MEMBERS;
-use crate::{action::Action, user_role::UserRole};
+use crate::{
+    action::{Action, ActionType},
+    user_role::UserRole,
+};
 
 use multiversx_sc::derive_imports::*;
 use multiversx_sc::imports::*;
@@ -8,6 +11,9 @@ pub type ActionId = usize;
 pub type GroupId = usize;
 pub type UserId = usize;
 
+/// Weight of a board member that was never given a specific one.
+pub const DEFAULT_SIGNER_WEIGHT: usize = 1;
+
 #[type_abi]
 #[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Eq, Clone, Copy, Debug)]
 pub enum ActionStatus {
@@ -23,6 +29,11 @@ pub trait MultisigStateModule {
     #[storage_mapper("quorum_ids")]
     fn quorum(&self) -> SingleValueMapper<usize>;
 
+    /// Quorum overrides for specific action types.
+    /// Action types without an override use the global quorum.
+    #[storage_mapper("action_type_quorum")]
+    fn action_type_quorum(&self) -> MapMapper<ActionType, usize>;
+
     #[storage_mapper("user_ids")]
     fn user_mapper(&self) -> UserMapper;
 
@@ -32,6 +43,9 @@ pub trait MultisigStateModule {
     #[storage_mapper("user_role")]
     fn user_id_to_role(&self, user_id: UserId) -> SingleValueMapper<UserRole>;
 
+    #[storage_mapper("user_weight")]
+    fn user_weight(&self, user_id: UserId) -> SingleValueMapper<usize>;
+
     fn get_caller_id_and_role(&self) -> (UserId, UserRole) {
         let caller_address = self.blockchain().get_caller();
         let caller_id = self.user_mapper().get_user_id(&caller_address);
@@ -113,6 +127,17 @@ pub trait MultisigStateModule {
         self.action_mapper().get(action_id)
     }
 
+    /// Block timestamp after which the action can no longer be signed or performed.
+    /// `0` means the action does not expire.
+    #[view(getActionExpiry)]
+    #[storage_mapper("action_expiry")]
+    fn action_expiry(&self, action_id: ActionId) -> SingleValueMapper<u64>;
+
+    fn is_action_expired(&self, action_id: ActionId) -> bool {
+        let expiry_mapper = self.action_expiry(action_id);
+        !expiry_mapper.is_empty() && self.blockchain().get_block_timestamp() > expiry_mapper.get()
+    }
+
     #[storage_mapper("action_signer_ids")]
     fn action_signer_ids(&self, action_id: ActionId) -> UnorderedSetMapper<UserId>;
 
@@ -143,6 +168,7 @@ pub trait MultisigStateModule {
     /// therefore the contract needs to re-check every time when actions are performed.
     /// This function is used to validate the signers before performing an action.
     /// It also makes it easy to check before performing an action.
+    /// Each valid signer counts with its weight, which is 1 unless configured otherwise.
     #[label("multisig-external-view")]
     #[view(getActionValidSignerCount)]
     fn get_action_valid_signer_count(&self, action_id: ActionId) -> usize {
@@ -153,6 +179,70 @@ pub trait MultisigStateModule {
                 let signer_role = self.user_id_to_role(*signer_id).get();
                 signer_role.can_sign()
             })
-            .count()
+            .map(|signer_id| self.get_user_weight(signer_id))
+            .sum()
+    }
+
+    /// Returns `true` (`1`) if `getActionValidSignerCount >= getQuorum`.
+    /// The quorum is the one that applied to the action type when the action was proposed.
+    #[view(quorumReached)]
+    fn quorum_reached(&self, action_id: ActionId) -> bool {
+        let quorum = self.quorum_for_action(action_id).get();
+        let valid_signers_count = self.get_action_valid_signer_count(action_id);
+        valid_signers_count >= quorum
+    }
+
+    /// The quorum that newly proposed actions of the given type need to reach.
+    #[view(getActionTypeQuorum)]
+    fn get_action_type_quorum(&self, action_type: ActionType) -> usize {
+        self.action_type_quorum()
+            .get(&action_type)
+            .unwrap_or_else(|| self.quorum().get())
+    }
+
+    /// Weight of the user's signature.
+    /// Users that are not board members have no weight.
+    #[view(getSignerWeight)]
+    fn get_signer_weight(&self, user: ManagedAddress) -> usize {
+        let user_id = self.user_mapper().get_user_id(&user);
+        if user_id == 0 || !self.user_id_to_role(user_id).get().can_sign() {
+            return 0;
+        }
+
+        self.get_user_weight(user_id)
+    }
+
+    /// Sum of the weights of all board members.
+    /// All quorums must stay within this value.
+    #[view(getTotalBoardWeight)]
+    fn get_total_board_weight(&self) -> usize {
+        let num_users = self.user_mapper().get_user_count();
+        (1..=num_users)
+            .filter(|user_id| self.user_id_to_role(*user_id).get().can_sign())
+            .map(|user_id| self.get_user_weight(user_id))
+            .sum()
+    }
+
+    fn get_user_weight(&self, user_id: UserId) -> usize {
+        let weight_mapper = self.user_weight(user_id);
+        if weight_mapper.is_empty() {
+            DEFAULT_SIGNER_WEIGHT
+        } else {
+            weight_mapper.get()
+        }
+    }
+
+    fn require_quorums_within_board_weight(&self) {
+        let total_board_weight = self.get_total_board_weight();
+        require!(
+            self.quorum().get() <= total_board_weight,
+            "quorum cannot exceed board size"
+        );
+        for (_, action_type_quorum) in self.action_type_quorum().iter() {
+            require!(
+                action_type_quorum <= total_board_weight,
+                "action type quorum cannot exceed board size"
+            );
+        }
     }
 }
@@ -0,0 +1,96 @@
+use crate::{
+    action::{Action, ActionType},
+    multisig_state::ActionId,
+};
+
+use multiversx_sc::imports::*;
+
+/// Upper bound for any timelock delay (30 days), so that a delay change can not lock the contract.
+pub const MAX_TIMELOCK_DELAY: u64 = 30 * 24 * 60 * 60;
+
+/// Keeps actions from being performed until some time has passed since they reached quorum,
+/// giving stakeholders a window to react.
+#[multiversx_sc::module]
+pub trait MultisigTimelockModule: crate::multisig_state::MultisigStateModule {
+    /// Delay, in seconds, between reaching quorum and being able to perform an action.
+    /// Applies to all action types that have no delay of their own.
+    #[view(getTimelockDelay)]
+    #[storage_mapper("timelock_delay")]
+    fn timelock_delay(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("action_type_timelock_delay")]
+    fn action_type_timelock_delay(&self, action_type: ActionType) -> SingleValueMapper<u64>;
+
+    /// Actions that were proposed and not yet performed or discarded,
+    /// so that role changes only have to go through those.
+    #[storage_mapper("pending_action_ids")]
+    fn pending_action_ids(&self) -> UnorderedSetMapper<ActionId>;
+
+    /// Block timestamp of the moment the action reached quorum.
+    /// `None` while the action does not have enough valid signatures.
+    /// Stored as an `Option`, so that a timestamp of 0 can be told apart from an empty entry.
+    #[view(getQuorumReachedTimestamp)]
+    #[storage_mapper("quorum_reached_timestamp")]
+    fn quorum_reached_timestamp(&self, action_id: ActionId) -> SingleValueMapper<Option<u64>>;
+
+    /// The delay that applies to the given action type:
+    /// its own delay if one was set, the global delay otherwise.
+    #[view(getActionTypeTimelockDelay)]
+    fn get_action_type_timelock_delay(&self, action_type: ActionType) -> u64 {
+        let action_type_delay_mapper = self.action_type_timelock_delay(action_type);
+        if action_type_delay_mapper.is_empty() {
+            self.timelock_delay().get()
+        } else {
+            action_type_delay_mapper.get()
+        }
+    }
+
+    /// Called whenever the signatures of an action change.
+    /// Starts the timelock when quorum is reached and resets it if quorum is lost.
+    fn update_quorum_reached_timestamp(&self, action_id: ActionId) {
+        let timestamp_mapper = self.quorum_reached_timestamp(action_id);
+        let action_exists = !self.action_mapper().item_is_empty_unchecked(action_id);
+        if !action_exists || !self.quorum_reached(action_id) {
+            timestamp_mapper.clear();
+            return;
+        }
+
+        if timestamp_mapper.is_empty() {
+            timestamp_mapper.set(Some(self.blockchain().get_block_timestamp()));
+        }
+    }
+
+    /// A change of roles or signer weights can make pending actions reach or lose quorum without anyone signing them.
+    fn update_all_quorum_reached_timestamps(&self) {
+        for action_id in self.pending_action_ids().iter() {
+            self.update_quorum_reached_timestamp(action_id);
+        }
+    }
+
+    fn get_timelock_remaining(
+        &self,
+        action_id: ActionId,
+        action: &Action<Self::Api>,
+    ) -> Option<u64> {
+        let quorum_reached_timestamp = self.quorum_reached_timestamp(action_id).get()?;
+        let unlock_timestamp =
+            quorum_reached_timestamp + self.get_action_type_timelock_delay(action.action_type());
+        let current_timestamp = self.blockchain().get_block_timestamp();
+        Some(unlock_timestamp.saturating_sub(current_timestamp))
+    }
+
+    fn is_timelock_elapsed(&self, action_id: ActionId, action: &Action<Self::Api>) -> bool {
+        match self.get_timelock_remaining(action_id, action) {
+            Some(remaining) => remaining == 0,
+            // actions that reached quorum before the timelock was introduced have no timestamp
+            None => self.get_action_type_timelock_delay(action.action_type()) == 0,
+        }
+    }
+
+    fn require_timelock_elapsed(&self, action_id: ActionId, action: &Action<Self::Api>) {
+        require!(
+            self.is_timelock_elapsed(action_id, action),
+            "timelock has not expired"
+        );
+    }
+}
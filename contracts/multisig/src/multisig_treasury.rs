@@ -0,0 +1,58 @@
+use multiversx_sc::imports::*;
+
+/// Keeps track of the tokens the multisig has received, so that its holdings can be listed.
+#[multiversx_sc::module]
+pub trait MultisigTreasuryModule {
+    /// Lists the current balance of EGLD and of every token the multisig has recorded,
+    /// skipping the ones that have been spent entirely.
+    #[view(getTreasury)]
+    fn get_treasury(&self) -> MultiValueEncoded<EgldOrEsdtTokenPayment> {
+        let mut result = MultiValueEncoded::new();
+
+        let egld_balance = self
+            .blockchain()
+            .get_sc_balance(&EgldOrEsdtTokenIdentifier::egld(), 0);
+        if egld_balance > 0 {
+            result.push(EgldOrEsdtTokenPayment::new(
+                EgldOrEsdtTokenIdentifier::egld(),
+                0,
+                egld_balance,
+            ));
+        }
+
+        for (token_id, nonce) in self.treasury_tokens().iter() {
+            let token_id = EgldOrEsdtTokenIdentifier::esdt(token_id);
+            let balance = self.blockchain().get_sc_balance(&token_id, nonce);
+            if balance > 0 {
+                result.push(EgldOrEsdtTokenPayment::new(token_id, nonce, balance));
+            }
+        }
+
+        result
+    }
+
+    fn record_treasury_tokens(&self, payments: &ManagedVec<EsdtTokenPayment>) {
+        let mut treasury_tokens = self.treasury_tokens();
+        for payment in payments {
+            let _ = treasury_tokens.insert((payment.token_identifier.clone(), payment.token_nonce));
+        }
+    }
+
+    /// Forgets the tokens among the given ones that the multisig no longer holds.
+    fn remove_spent_treasury_tokens(&self, payments: &ManagedVec<EsdtTokenPayment>) {
+        let mut treasury_tokens = self.treasury_tokens();
+        for payment in payments {
+            let balance = self.blockchain().get_sc_balance(
+                &EgldOrEsdtTokenIdentifier::esdt(payment.token_identifier.clone()),
+                payment.token_nonce,
+            );
+            if balance == 0 {
+                let _ = treasury_tokens
+                    .swap_remove(&(payment.token_identifier.clone(), payment.token_nonce));
+            }
+        }
+    }
+
+    #[storage_mapper("treasury_tokens")]
+    fn treasury_tokens(&self) -> UnorderedSetMapper<(TokenIdentifier, u64)>;
+}
@@ -1,3 +1,4 @@
+use ed25519_dalek::{Signer, SigningKey};
 use multiversx_sc::{codec::top_encode_to_vec_u8_or_panic, types::BigUint};
 use multiversx_sc_scenario::imports::*;
 
@@ -14,6 +15,11 @@ const OWNER_ADDRESS: TestAddress = TestAddress::new("owner");
 const PROPOSER_ADDRESS: TestAddress = TestAddress::new("proposer");
 const PROPOSER_BALANCE: u64 = 100_000_000;
 const QUORUM_SIZE: usize = 1;
+const FUNGIBLE_TOKEN_ID: TestTokenIdentifier = TestTokenIdentifier::new("FUNG-123456");
+const NFT_TOKEN_ID: TestTokenIdentifier = TestTokenIdentifier::new("NFT-123456");
+const UNSOLICITED_TOKEN_ID: TestTokenIdentifier = TestTokenIdentifier::new("SPAM-123456");
+const SIGNER_A_SECRET_KEY: [u8; 32] = [7u8; 32];
+const SIGNER_B_SECRET_KEY: [u8; 32] = [8u8; 32];
 
 fn world() -> ScenarioWorld {
     let mut blockchain = ScenarioWorld::new();
@@ -63,7 +69,7 @@ impl MultisigTestState {
             .from(BOARD_MEMBER_ADDRESS)
             .to(MULTISIG_ADDRESS)
             .typed(multisig_proxy::MultisigProxy)
-            .propose_add_proposer(PROPOSER_ADDRESS)
+            .propose_add_proposer(PROPOSER_ADDRESS, OptionalValue::<u64>::None)
             .returns(ReturnsResult)
             .run();
 
@@ -92,7 +98,7 @@ impl MultisigTestState {
             .from(PROPOSER_ADDRESS)
             .to(MULTISIG_ADDRESS)
             .typed(multisig_proxy::MultisigProxy)
-            .propose_add_board_member(board_member_address)
+            .propose_add_board_member(board_member_address, OptionalValue::<u64>::None)
             .returns(ReturnsResult)
             .run()
     }
@@ -103,7 +109,7 @@ impl MultisigTestState {
             .from(PROPOSER_ADDRESS)
             .to(MULTISIG_ADDRESS)
             .typed(multisig_proxy::MultisigProxy)
-            .propose_add_proposer(proposer_address)
+            .propose_add_proposer(proposer_address, OptionalValue::<u64>::None)
             .returns(ReturnsResult)
             .run()
     }
@@ -114,7 +120,70 @@ impl MultisigTestState {
             .from(PROPOSER_ADDRESS)
             .to(MULTISIG_ADDRESS)
             .typed(multisig_proxy::MultisigProxy)
-            .propose_change_quorum(new_quorum)
+            .propose_change_quorum(new_quorum, OptionalValue::<u64>::None)
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    fn propose_change_action_type_quorum(
+        &mut self,
+        action_type: multisig_proxy::ActionType,
+        opt_new_quorum: Option<usize>,
+    ) -> usize {
+        self.world
+            .tx()
+            .from(PROPOSER_ADDRESS)
+            .to(MULTISIG_ADDRESS)
+            .typed(multisig_proxy::MultisigProxy)
+            .propose_change_action_type_quorum(action_type, OptionalValue::from(opt_new_quorum))
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    fn propose_change_signer_weight(
+        &mut self,
+        board_member_address: TestAddress,
+        new_weight: usize,
+    ) -> usize {
+        self.world
+            .tx()
+            .from(PROPOSER_ADDRESS)
+            .to(MULTISIG_ADDRESS)
+            .typed(multisig_proxy::MultisigProxy)
+            .propose_change_signer_weight(
+                board_member_address,
+                new_weight,
+                OptionalValue::<u64>::None,
+            )
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    fn propose_change_timelock_delay(&mut self, new_delay: u64) -> usize {
+        self.world
+            .tx()
+            .from(PROPOSER_ADDRESS)
+            .to(MULTISIG_ADDRESS)
+            .typed(multisig_proxy::MultisigProxy)
+            .propose_change_timelock_delay(new_delay, OptionalValue::<u64>::None)
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    fn propose_change_action_type_timelock_delay(
+        &mut self,
+        action_type: multisig_proxy::ActionType,
+        opt_new_delay: Option<u64>,
+    ) -> usize {
+        self.world
+            .tx()
+            .from(PROPOSER_ADDRESS)
+            .to(MULTISIG_ADDRESS)
+            .typed(multisig_proxy::MultisigProxy)
+            .propose_change_action_type_timelock_delay(
+                action_type,
+                OptionalValue::from(opt_new_delay),
+            )
             .returns(ReturnsResult)
             .run()
     }
@@ -157,7 +226,7 @@ impl MultisigTestState {
             .from(PROPOSER_ADDRESS)
             .to(MULTISIG_ADDRESS)
             .typed(multisig_proxy::MultisigProxy)
-            .propose_remove_user(user_address)
+            .propose_remove_user(user_address, OptionalValue::<u64>::None)
             .returns(ReturnsResult)
             .run()
     }
@@ -219,9 +288,13 @@ impl MultisigTestState {
     }
 
     fn sign(&mut self, action_id: usize) {
+        self.sign_as(BOARD_MEMBER_ADDRESS, action_id);
+    }
+
+    fn sign_as(&mut self, signer: TestAddress, action_id: usize) {
         self.world
             .tx()
-            .from(BOARD_MEMBER_ADDRESS)
+            .from(signer)
             .to(MULTISIG_ADDRESS)
             .typed(multisig_proxy::MultisigProxy)
             .sign(action_id)
@@ -640,3 +713,1030 @@ fn test_deploy_and_upgrade_from_source() {
         .check_account(ADDER_ADDRESS)
         .code(factorial_path);
 }
+
+#[test]
+fn test_timelock_delay() {
+    let mut state = MultisigTestState::new();
+    state.deploy_multisig_contract();
+
+    state.world.current_block().block_timestamp(1_000);
+
+    // no delay configured yet, so the delay change is performed right away
+    let action_id = state.propose_change_timelock_delay(100);
+    state.sign(action_id);
+    state.perform(action_id);
+
+    state
+        .world
+        .query()
+        .to(MULTISIG_ADDRESS)
+        .typed(multisig_proxy::MultisigProxy)
+        .timelock_delay()
+        .returns(ExpectValue(100u64))
+        .run();
+
+    let action_id = state.propose_add_proposer(TestAddress::new("new-proposer"));
+    state.sign(action_id);
+
+    state
+        .world
+        .query()
+        .to(MULTISIG_ADDRESS)
+        .typed(multisig_proxy::MultisigProxy)
+        .quorum_reached_timestamp(action_id)
+        .returns(ExpectValue(Some(1_000u64)))
+        .run();
+
+    state.perform_and_expect_err(action_id, "timelock has not expired");
+
+    state.world.current_block().block_timestamp(1_099);
+    state.perform_and_expect_err(action_id, "timelock has not expired");
+
+    state.world.current_block().block_timestamp(1_100);
+    state.perform(action_id);
+}
+
+#[test]
+fn test_timelock_starts_when_quorum_reached_through_role_change() {
+    let mut state = MultisigTestState::new();
+    state.deploy_multisig_contract();
+
+    let new_board_member_address = TestAddress::new("new-board-member");
+    state.world.account(new_board_member_address).nonce(1);
+
+    let action_id = state.propose_add_board_member(new_board_member_address);
+    state.sign(action_id);
+    state.perform(action_id);
+
+    state.world.current_block().block_timestamp(1_000);
+
+    let action_id = state.propose_change_timelock_delay(100);
+    state.sign(action_id);
+    state.perform(action_id);
+
+    // only signed by the new board member
+    let pending_action_id = state.propose_add_proposer(TestAddress::new("other-proposer"));
+    state
+        .world
+        .tx()
+        .from(new_board_member_address)
+        .to(MULTISIG_ADDRESS)
+        .typed(multisig_proxy::MultisigProxy)
+        .sign(pending_action_id)
+        .run();
+
+    // demoting the signer loses the quorum
+    let action_id = state.propose_add_proposer(new_board_member_address);
+    state.sign(action_id);
+    state.world.current_block().block_timestamp(1_100);
+    state.perform(action_id);
+
+    state
+        .world
+        .query()
+        .to(MULTISIG_ADDRESS)
+        .typed(multisig_proxy::MultisigProxy)
+        .quorum_reached_timestamp(pending_action_id)
+        .returns(ExpectValue(None::<u64>))
+        .run();
+
+    // promoting them again reaches it without a new signature
+    let action_id = state.propose_add_board_member(new_board_member_address);
+    state.sign(action_id);
+    state.world.current_block().block_timestamp(1_200);
+    state.perform(action_id);
+
+    state
+        .world
+        .query()
+        .to(MULTISIG_ADDRESS)
+        .typed(multisig_proxy::MultisigProxy)
+        .quorum_reached_timestamp(pending_action_id)
+        .returns(ExpectValue(Some(1_200u64)))
+        .run();
+
+    state.world.current_block().block_timestamp(1_299);
+    state.perform_and_expect_err(pending_action_id, "timelock has not expired");
+
+    state.world.current_block().block_timestamp(1_300);
+    state.perform(pending_action_id);
+}
+
+#[test]
+fn test_action_type_timelock_delay() {
+    let mut state = MultisigTestState::new();
+    state.deploy_multisig_contract();
+
+    state.world.current_block().block_timestamp(1_000);
+
+    let action_id = state.propose_change_timelock_delay(100);
+    state.sign(action_id);
+    state.perform(action_id);
+
+    // the override itself waits for the global delay
+    let action_id = state.propose_change_action_type_timelock_delay(
+        multisig_proxy::ActionType::ChangeQuorum,
+        Some(500),
+    );
+    state.sign(action_id);
+    state.perform_and_expect_err(action_id, "timelock has not expired");
+
+    state.world.current_block().block_timestamp(1_100);
+    state.perform(action_id);
+
+    state
+        .world
+        .query()
+        .to(MULTISIG_ADDRESS)
+        .typed(multisig_proxy::MultisigProxy)
+        .get_action_type_timelock_delay(multisig_proxy::ActionType::ChangeQuorum)
+        .returns(ExpectValue(500u64))
+        .run();
+    state
+        .world
+        .query()
+        .to(MULTISIG_ADDRESS)
+        .typed(multisig_proxy::MultisigProxy)
+        .get_action_type_timelock_delay(multisig_proxy::ActionType::AddProposer)
+        .returns(ExpectValue(100u64))
+        .run();
+
+    let action_id = state.propose_change_quorum(1);
+    state.sign(action_id);
+
+    state.world.current_block().block_timestamp(1_599);
+    state.perform_and_expect_err(action_id, "timelock has not expired");
+
+    state.world.current_block().block_timestamp(1_600);
+    state.perform(action_id);
+
+    // removing the override falls back to the global delay
+    let action_id = state
+        .propose_change_action_type_timelock_delay(multisig_proxy::ActionType::ChangeQuorum, None);
+    state.sign(action_id);
+    state.world.current_block().block_timestamp(1_700);
+    state.perform(action_id);
+
+    state
+        .world
+        .query()
+        .to(MULTISIG_ADDRESS)
+        .typed(multisig_proxy::MultisigProxy)
+        .get_action_type_timelock_delay(multisig_proxy::ActionType::ChangeQuorum)
+        .returns(ExpectValue(100u64))
+        .run();
+}
+
+#[test]
+fn test_weighted_signers_and_action_type_quorum() {
+    let mut state = MultisigTestState::new();
+    state.deploy_multisig_contract();
+
+    let second_board_member = TestAddress::new("second-board-member");
+    state.world.account(second_board_member).nonce(1);
+
+    let action_id = state.propose_add_board_member(second_board_member);
+    state.sign(action_id);
+    state.perform(action_id);
+
+    let action_id = state.propose_change_quorum(2);
+    state.sign(action_id);
+    state.perform(action_id);
+
+    let action_id = state.propose_change_signer_weight(BOARD_MEMBER_ADDRESS, 2);
+    state.sign(action_id);
+    state.perform_and_expect_err(action_id, "quorum has not been reached");
+    state.sign_as(second_board_member, action_id);
+    state.perform(action_id);
+
+    state
+        .world
+        .query()
+        .to(MULTISIG_ADDRESS)
+        .typed(multisig_proxy::MultisigProxy)
+        .get_signer_weight(BOARD_MEMBER_ADDRESS)
+        .returns(ExpectValue(2usize))
+        .run();
+    state
+        .world
+        .query()
+        .to(MULTISIG_ADDRESS)
+        .typed(multisig_proxy::MultisigProxy)
+        .get_total_board_weight()
+        .returns(ExpectValue(3usize))
+        .run();
+
+    // the heavier signer reaches quorum alone
+    let action_id =
+        state.propose_change_action_type_quorum(multisig_proxy::ActionType::ChangeQuorum, Some(3));
+    state.sign(action_id);
+    state.perform(action_id);
+
+    state
+        .world
+        .query()
+        .to(MULTISIG_ADDRESS)
+        .typed(multisig_proxy::MultisigProxy)
+        .get_action_type_quorum(multisig_proxy::ActionType::ChangeQuorum)
+        .returns(ExpectValue(3usize))
+        .run();
+
+    // changing the quorum now needs the whole board
+    let action_id = state.propose_change_quorum(1);
+    state.sign(action_id);
+    state.perform_and_expect_err(action_id, "quorum has not been reached");
+    state.sign_as(second_board_member, action_id);
+    state.perform(action_id);
+
+    // the board can not become lighter than the quorum override
+    let action_id = state.propose_remove_user(second_board_member);
+    state.sign(action_id);
+    state.perform_and_expect_err(action_id, "action type quorum cannot exceed board size");
+
+    let action_id =
+        state.propose_change_action_type_quorum(multisig_proxy::ActionType::ChangeQuorum, Some(0));
+    state.sign(action_id);
+    state.sign_as(second_board_member, action_id);
+    state.perform_and_expect_err(action_id, "action type quorum cannot be zero");
+}
+
+#[test]
+fn test_signer_weight_change_updates_quorum_reached_timestamps() {
+    let mut state = MultisigTestState::new();
+    state.deploy_multisig_contract();
+
+    let second_board_member = TestAddress::new("second-board-member");
+    state.world.account(second_board_member).nonce(1);
+
+    let action_id = state.propose_add_board_member(second_board_member);
+    state.sign(action_id);
+    state.perform(action_id);
+
+    let action_id = state.propose_change_quorum(2);
+    state.sign(action_id);
+    state.perform(action_id);
+
+    state.world.current_block().block_timestamp(1_000);
+
+    // only signed by the first board member
+    let pending_action_id = state.propose_add_proposer(TestAddress::new("other-proposer"));
+    state.sign(pending_action_id);
+
+    state
+        .world
+        .query()
+        .to(MULTISIG_ADDRESS)
+        .typed(multisig_proxy::MultisigProxy)
+        .quorum_reached_timestamp(pending_action_id)
+        .returns(ExpectValue(None::<u64>))
+        .run();
+
+    // a heavier signer reaches quorum without a new signature
+    let action_id = state.propose_change_signer_weight(BOARD_MEMBER_ADDRESS, 2);
+    state.sign(action_id);
+    state.sign_as(second_board_member, action_id);
+    state.world.current_block().block_timestamp(1_100);
+    state.perform(action_id);
+
+    state
+        .world
+        .query()
+        .to(MULTISIG_ADDRESS)
+        .typed(multisig_proxy::MultisigProxy)
+        .quorum_reached_timestamp(pending_action_id)
+        .returns(ExpectValue(Some(1_100u64)))
+        .run();
+
+    // and lowering the weight loses it again
+    let action_id = state.propose_change_signer_weight(BOARD_MEMBER_ADDRESS, 1);
+    state.sign(action_id);
+    state.world.current_block().block_timestamp(1_200);
+    state.perform(action_id);
+
+    state
+        .world
+        .query()
+        .to(MULTISIG_ADDRESS)
+        .typed(multisig_proxy::MultisigProxy)
+        .quorum_reached_timestamp(pending_action_id)
+        .returns(ExpectValue(None::<u64>))
+        .run();
+    state.perform_and_expect_err(pending_action_id, "quorum has not been reached");
+}
+
+#[test]
+fn test_spend_within_limit() {
+    let mut state = MultisigTestState::new();
+    state.deploy_multisig_contract();
+
+    let payee = TestAddress::new("payee");
+    state.world.account(payee).nonce(1);
+    state.world.current_block().block_timestamp(1_000);
+
+    state
+        .world
+        .tx()
+        .from(PROPOSER_ADDRESS)
+        .to(MULTISIG_ADDRESS)
+        .typed(multisig_proxy::MultisigProxy)
+        .deposit()
+        .egld(5_000)
+        .run();
+
+    let action_id = state
+        .world
+        .tx()
+        .from(PROPOSER_ADDRESS)
+        .to(MULTISIG_ADDRESS)
+        .typed(multisig_proxy::MultisigProxy)
+        .propose_set_spending_limit(
+            EgldOrEsdtTokenIdentifier::egld(),
+            1_000u64,
+            86_400u64,
+            OptionalValue::<u64>::None,
+        )
+        .returns(ReturnsResult)
+        .run();
+    state.sign(action_id);
+    state.perform(action_id);
+
+    // proposers can not spend
+    state
+        .world
+        .tx()
+        .from(PROPOSER_ADDRESS)
+        .to(MULTISIG_ADDRESS)
+        .typed(multisig_proxy::MultisigProxy)
+        .spend_within_limit(payee, EgldOrEsdtTokenIdentifier::egld(), 600u64)
+        .with_result(ExpectError(4, "only board members can spend"))
+        .run();
+
+    state
+        .world
+        .tx()
+        .from(BOARD_MEMBER_ADDRESS)
+        .to(MULTISIG_ADDRESS)
+        .typed(multisig_proxy::MultisigProxy)
+        .spend_within_limit(payee, EgldOrEsdtTokenIdentifier::egld(), 600u64)
+        .run();
+
+    state
+        .world
+        .tx()
+        .from(BOARD_MEMBER_ADDRESS)
+        .to(MULTISIG_ADDRESS)
+        .typed(multisig_proxy::MultisigProxy)
+        .spend_within_limit(payee, EgldOrEsdtTokenIdentifier::egld(), 600u64)
+        .with_result(ExpectError(4, "spending limit exceeded"))
+        .run();
+
+    state.world.current_block().block_timestamp(1_000 + 43_200);
+    state
+        .world
+        .tx()
+        .from(BOARD_MEMBER_ADDRESS)
+        .to(MULTISIG_ADDRESS)
+        .typed(multisig_proxy::MultisigProxy)
+        .spend_within_limit(payee, EgldOrEsdtTokenIdentifier::egld(), 400u64)
+        .run();
+
+    state.world.check_account(payee).balance(1_000);
+    state
+        .world
+        .query()
+        .to(MULTISIG_ADDRESS)
+        .typed(multisig_proxy::MultisigProxy)
+        .get_remaining_allowance(EgldOrEsdtTokenIdentifier::egld())
+        .returns(ExpectValue(0u64))
+        .run();
+
+    // the window is rolling: only the first spend is older than a period
+    state.world.current_block().block_timestamp(1_000 + 86_400);
+    state
+        .world
+        .query()
+        .to(MULTISIG_ADDRESS)
+        .typed(multisig_proxy::MultisigProxy)
+        .get_remaining_allowance(EgldOrEsdtTokenIdentifier::egld())
+        .returns(ExpectValue(600u64))
+        .run();
+
+    state
+        .world
+        .tx()
+        .from(BOARD_MEMBER_ADDRESS)
+        .to(MULTISIG_ADDRESS)
+        .typed(multisig_proxy::MultisigProxy)
+        .spend_within_limit(payee, EgldOrEsdtTokenIdentifier::egld(), 1_000u64)
+        .with_result(ExpectError(4, "spending limit exceeded"))
+        .run();
+
+    state
+        .world
+        .tx()
+        .from(BOARD_MEMBER_ADDRESS)
+        .to(MULTISIG_ADDRESS)
+        .typed(multisig_proxy::MultisigProxy)
+        .spend_within_limit(payee, EgldOrEsdtTokenIdentifier::egld(), 600u64)
+        .run();
+
+    state
+        .world
+        .current_block()
+        .block_timestamp(1_000 + 43_200 + 86_400);
+    state
+        .world
+        .query()
+        .to(MULTISIG_ADDRESS)
+        .typed(multisig_proxy::MultisigProxy)
+        .get_remaining_allowance(EgldOrEsdtTokenIdentifier::egld())
+        .returns(ExpectValue(400u64))
+        .run();
+
+    state
+        .world
+        .tx()
+        .from(BOARD_MEMBER_ADDRESS)
+        .to(MULTISIG_ADDRESS)
+        .typed(multisig_proxy::MultisigProxy)
+        .spend_within_limit(payee, EgldOrEsdtTokenIdentifier::egld(), 400u64)
+        .run();
+
+    state.world.check_account(payee).balance(2_000);
+    state.world.check_account(MULTISIG_ADDRESS).balance(3_000);
+}
+
+#[test]
+fn test_action_expiry_and_cleanup() {
+    let mut state = MultisigTestState::new();
+    state.deploy_multisig_contract();
+
+    state.world.current_block().block_timestamp(1_000);
+
+    state
+        .world
+        .tx()
+        .from(PROPOSER_ADDRESS)
+        .to(MULTISIG_ADDRESS)
+        .typed(multisig_proxy::MultisigProxy)
+        .propose_add_proposer(
+            TestAddress::new("new-proposer"),
+            OptionalValue::Some(999u64),
+        )
+        .with_result(ExpectError(4, "expiry must be in the future"))
+        .run();
+
+    let action_id: usize = state
+        .world
+        .tx()
+        .from(PROPOSER_ADDRESS)
+        .to(MULTISIG_ADDRESS)
+        .typed(multisig_proxy::MultisigProxy)
+        .propose_add_proposer(
+            TestAddress::new("new-proposer"),
+            OptionalValue::Some(1_100u64),
+        )
+        .returns(ReturnsResult)
+        .run();
+
+    state
+        .world
+        .query()
+        .to(MULTISIG_ADDRESS)
+        .typed(multisig_proxy::MultisigProxy)
+        .action_expiry(action_id)
+        .returns(ExpectValue(1_100u64))
+        .run();
+
+    // not expired yet, so cleanup leaves the action alone
+    state
+        .world
+        .tx()
+        .from(PROPOSER_ADDRESS)
+        .to(MULTISIG_ADDRESS)
+        .typed(multisig_proxy::MultisigProxy)
+        .cleanup_expired(action_id, action_id)
+        .run();
+
+    state.world.current_block().block_timestamp(1_101);
+    state
+        .world
+        .tx()
+        .from(BOARD_MEMBER_ADDRESS)
+        .to(MULTISIG_ADDRESS)
+        .typed(multisig_proxy::MultisigProxy)
+        .sign(action_id)
+        .with_result(ExpectError(4, "action expired"))
+        .run();
+
+    // anyone can clean up expired actions
+    let cleaner = TestAddress::new("cleaner");
+    state.world.account(cleaner).nonce(1);
+    state
+        .world
+        .tx()
+        .from(cleaner)
+        .to(MULTISIG_ADDRESS)
+        .typed(multisig_proxy::MultisigProxy)
+        .cleanup_expired(action_id, action_id)
+        .run();
+
+    state
+        .world
+        .query()
+        .to(MULTISIG_ADDRESS)
+        .typed(multisig_proxy::MultisigProxy)
+        .action_expiry(action_id)
+        .returns(ExpectValue(0u64))
+        .run();
+
+    state
+        .world
+        .tx()
+        .from(BOARD_MEMBER_ADDRESS)
+        .to(MULTISIG_ADDRESS)
+        .typed(multisig_proxy::MultisigProxy)
+        .sign(action_id)
+        .with_result(ExpectError(4, "action does not exist"))
+        .run();
+}
+
+#[test]
+fn test_propose_with_expiry() {
+    let mut state = MultisigTestState::new();
+    state.deploy_multisig_contract();
+
+    state.world.current_block().block_timestamp(1_000);
+
+    state
+        .world
+        .tx()
+        .from(PROPOSER_ADDRESS)
+        .to(MULTISIG_ADDRESS)
+        .typed(multisig_proxy::MultisigProxy)
+        .propose_with_expiry(1_100u64, multisig_proxy::Action::Nothing)
+        .with_result(ExpectError(4, "proposed action has no effect"))
+        .run();
+
+    let payee = TestAddress::new("payee");
+    let call_data = multisig_proxy::CallActionData {
+        to: payee.to_managed_address(),
+        egld_amount: BigUint::from(100u64),
+        opt_gas_limit: Option::<u64>::None,
+        endpoint_name: ManagedBuffer::new(),
+        arguments: ManagedVec::new(),
+    };
+    let action_id: usize = state
+        .world
+        .tx()
+        .from(PROPOSER_ADDRESS)
+        .to(MULTISIG_ADDRESS)
+        .typed(multisig_proxy::MultisigProxy)
+        .propose_with_expiry(
+            1_100u64,
+            multisig_proxy::Action::SendTransferExecuteEgld(call_data),
+        )
+        .returns(ReturnsResult)
+        .run();
+    state.sign(action_id);
+
+    state.world.current_block().block_timestamp(1_101);
+    state.perform_and_expect_err(action_id, "action expired");
+}
+
+#[test]
+fn test_propose_batch_with_expiry() {
+    let mut state = MultisigTestState::new();
+    state.deploy_multisig_contract();
+
+    state.world.current_block().block_timestamp(1_000);
+
+    let mut actions = MultiValueEncoded::new();
+    actions.push(multisig_proxy::Action::ChangeQuorum(1));
+    actions.push(multisig_proxy::Action::AddProposer(
+        TestAddress::new("new-proposer").to_managed_address(),
+    ));
+
+    state
+        .world
+        .tx()
+        .from(PROPOSER_ADDRESS)
+        .to(MULTISIG_ADDRESS)
+        .typed(multisig_proxy::MultisigProxy)
+        .propose_batch_with_expiry(1_000u64, actions.clone())
+        .with_result(ExpectError(4, "expiry must be in the future"))
+        .run();
+
+    let group_id: usize = state
+        .world
+        .tx()
+        .from(PROPOSER_ADDRESS)
+        .to(MULTISIG_ADDRESS)
+        .typed(multisig_proxy::MultisigProxy)
+        .propose_batch_with_expiry(1_100u64, actions)
+        .returns(ReturnsResult)
+        .run();
+
+    // every action of the batch gets the expiry
+    let action_ids: MultiValueVec<usize> = state
+        .world
+        .query()
+        .to(MULTISIG_ADDRESS)
+        .typed(multisig_proxy::MultisigProxy)
+        .action_groups(group_id)
+        .returns(ReturnsResultUnmanaged)
+        .run();
+    assert_eq!(action_ids.len(), 2);
+    for action_id in action_ids.into_vec() {
+        state
+            .world
+            .query()
+            .to(MULTISIG_ADDRESS)
+            .typed(multisig_proxy::MultisigProxy)
+            .action_expiry(action_id)
+            .returns(ExpectValue(1_100u64))
+            .run();
+    }
+
+    state.world.current_block().block_timestamp(1_101);
+    state
+        .world
+        .tx()
+        .from(BOARD_MEMBER_ADDRESS)
+        .to(MULTISIG_ADDRESS)
+        .typed(multisig_proxy::MultisigProxy)
+        .sign_batch_and_perform(group_id)
+        .with_result(ExpectError(4, "action expired"))
+        .run();
+}
+
+fn signer_address(signing_key: &SigningKey) -> Address {
+    Address::from(signing_key.verifying_key().to_bytes())
+}
+
+fn signature_message(
+    nonce: u64,
+    expiry: u64,
+    action: &multisig_proxy::Action<StaticApi>,
+) -> Vec<u8> {
+    let mut message = MULTISIG_ADDRESS.to_address().to_vec();
+    message.extend_from_slice(&nonce.to_be_bytes());
+    message.extend_from_slice(&expiry.to_be_bytes());
+    message.extend_from_slice(&top_encode_to_vec_u8_or_panic(action));
+    message
+}
+
+fn sign_action(
+    signing_key: &SigningKey,
+    nonce: u64,
+    expiry: u64,
+    action: &multisig_proxy::Action<StaticApi>,
+) -> MultiValue2<ManagedAddress<StaticApi>, ManagedByteArray<StaticApi, 64>> {
+    let message = signature_message(nonce, expiry, action);
+    let signature = ManagedByteArray::new_from_bytes(&signing_key.sign(&message).to_bytes());
+    MultiValue2::from((ManagedAddress::from(signer_address(signing_key)), signature))
+}
+
+#[test]
+fn test_perform_with_signatures() {
+    let mut state = MultisigTestState::new();
+    state.deploy_multisig_contract();
+
+    let signer_a = SigningKey::from_bytes(&SIGNER_A_SECRET_KEY);
+    let signer_b = SigningKey::from_bytes(&SIGNER_B_SECRET_KEY);
+    for signing_key in [&signer_a, &signer_b] {
+        let action_id: usize = state
+            .world
+            .tx()
+            .from(PROPOSER_ADDRESS)
+            .to(MULTISIG_ADDRESS)
+            .typed(multisig_proxy::MultisigProxy)
+            .propose_add_board_member(signer_address(signing_key), OptionalValue::<u64>::None)
+            .returns(ReturnsResult)
+            .run();
+        state.sign(action_id);
+        state.perform(action_id);
+    }
+
+    let action_id = state.propose_change_quorum(2);
+    state.sign(action_id);
+    state.perform(action_id);
+
+    state.world.current_block().block_timestamp(1_000);
+    let expiry = 1_100u64;
+
+    let new_proposer = TestAddress::new("new-proposer");
+    let action =
+        multisig_proxy::Action::<StaticApi>::AddProposer(new_proposer.to_managed_address());
+    let other_proposer = TestAddress::new("other-proposer");
+    let other_action =
+        multisig_proxy::Action::<StaticApi>::AddProposer(other_proposer.to_managed_address());
+
+    state
+        .world
+        .query()
+        .to(MULTISIG_ADDRESS)
+        .typed(multisig_proxy::MultisigProxy)
+        .get_signature_message(5u64, expiry, action.clone())
+        .returns(ExpectValue(ManagedBuffer::<StaticApi>::new_from_bytes(
+            &signature_message(5, expiry, &action),
+        )))
+        .run();
+
+    let mut signatures = MultiValueEncoded::new();
+    signatures.push(sign_action(&signer_a, 5, expiry, &action));
+    state
+        .world
+        .tx()
+        .from(PROPOSER_ADDRESS)
+        .to(MULTISIG_ADDRESS)
+        .typed(multisig_proxy::MultisigProxy)
+        .perform_with_signatures(5u64, expiry, action.clone(), signatures)
+        .with_result(ExpectError(4, "quorum has not been reached"))
+        .run();
+
+    let mut signatures = MultiValueEncoded::new();
+    signatures.push(sign_action(&signer_a, 5, expiry, &action));
+    signatures.push(sign_action(&signer_a, 5, expiry, &action));
+    state
+        .world
+        .tx()
+        .from(PROPOSER_ADDRESS)
+        .to(MULTISIG_ADDRESS)
+        .typed(multisig_proxy::MultisigProxy)
+        .perform_with_signatures(5u64, expiry, action.clone(), signatures)
+        .with_result(ExpectError(4, "duplicate signer"))
+        .run();
+
+    // signer B signed for a different expiry
+    let mut signatures = MultiValueEncoded::new();
+    signatures.push(sign_action(&signer_a, 5, expiry, &action));
+    signatures.push(sign_action(&signer_b, 5, expiry + 1, &action));
+    state
+        .world
+        .tx()
+        .from(PROPOSER_ADDRESS)
+        .to(MULTISIG_ADDRESS)
+        .typed(multisig_proxy::MultisigProxy)
+        .perform_with_signatures(5u64, expiry, action.clone(), signatures)
+        .with_result(ExpectError(10, "invalid signature"))
+        .run();
+
+    // both actions are signed before either of them is performed
+    let mut signatures = MultiValueEncoded::new();
+    signatures.push(sign_action(&signer_a, 5, expiry, &action));
+    signatures.push(sign_action(&signer_b, 5, expiry, &action));
+    let mut other_signatures = MultiValueEncoded::new();
+    other_signatures.push(sign_action(&signer_a, 6, expiry, &other_action));
+    other_signatures.push(sign_action(&signer_b, 6, expiry, &other_action));
+
+    let last_action_id: usize = state
+        .world
+        .query()
+        .to(MULTISIG_ADDRESS)
+        .typed(multisig_proxy::MultisigProxy)
+        .get_action_last_index()
+        .returns(ReturnsResult)
+        .run();
+    state
+        .world
+        .tx()
+        .from(PROPOSER_ADDRESS)
+        .to(MULTISIG_ADDRESS)
+        .typed(multisig_proxy::MultisigProxy)
+        .perform_with_signatures(5u64, expiry, action.clone(), signatures.clone())
+        .run();
+    state
+        .world
+        .tx()
+        .from(PROPOSER_ADDRESS)
+        .to(MULTISIG_ADDRESS)
+        .typed(multisig_proxy::MultisigProxy)
+        .perform_with_signatures(6u64, expiry, other_action.clone(), other_signatures)
+        .run();
+
+    state.expect_user_role(new_proposer, multisig_proxy::UserRole::Proposer);
+    state.expect_user_role(other_proposer, multisig_proxy::UserRole::Proposer);
+    // each action performed with signatures gets its own ID
+    state
+        .world
+        .query()
+        .to(MULTISIG_ADDRESS)
+        .typed(multisig_proxy::MultisigProxy)
+        .get_action_last_index()
+        .returns(ExpectValue(last_action_id + 2))
+        .run();
+    state
+        .world
+        .query()
+        .to(MULTISIG_ADDRESS)
+        .typed(multisig_proxy::MultisigProxy)
+        .signature_nonce_used(5u64)
+        .returns(ExpectValue(true))
+        .run();
+
+    // the nonce is used, so the same signatures can not be replayed
+    state
+        .world
+        .tx()
+        .from(PROPOSER_ADDRESS)
+        .to(MULTISIG_ADDRESS)
+        .typed(multisig_proxy::MultisigProxy)
+        .perform_with_signatures(5u64, expiry, action.clone(), signatures)
+        .with_result(ExpectError(4, "signature nonce already used"))
+        .run();
+
+    let mut signatures = MultiValueEncoded::new();
+    signatures.push(sign_action(&signer_a, 7, expiry, &action));
+    signatures.push(sign_action(&signer_b, 7, expiry, &action));
+    state.world.current_block().block_timestamp(expiry + 1);
+    state
+        .world
+        .tx()
+        .from(PROPOSER_ADDRESS)
+        .to(MULTISIG_ADDRESS)
+        .typed(multisig_proxy::MultisigProxy)
+        .perform_with_signatures(7u64, expiry, action, signatures)
+        .with_result(ExpectError(4, "signatures expired"))
+        .run();
+}
+
+#[test]
+fn test_perform_with_signatures_zero_quorum() {
+    let mut state = MultisigTestState::new();
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .typed(multisig_proxy::MultisigProxy)
+        .init(0usize, MultiValueVec::from(vec![BOARD_MEMBER_ADDRESS]))
+        .code(MULTISIG_CODE_PATH)
+        .new_address(MULTISIG_ADDRESS)
+        .run();
+
+    state
+        .world
+        .tx()
+        .from(BOARD_MEMBER_ADDRESS)
+        .to(MULTISIG_ADDRESS)
+        .typed(multisig_proxy::MultisigProxy)
+        .perform_with_signatures(
+            0u64,
+            u64::MAX,
+            multisig_proxy::Action::<StaticApi>::AddProposer(PROPOSER_ADDRESS.to_managed_address()),
+            MultiValueEncoded::new(),
+        )
+        .with_result(ExpectError(4, "quorum cannot be zero"))
+        .run();
+}
+
+impl MultisigTestState {
+    fn treasury(&mut self) -> Vec<EgldOrEsdtTokenPayment<StaticApi>> {
+        self.world
+            .query()
+            .to(MULTISIG_ADDRESS)
+            .typed(multisig_proxy::MultisigProxy)
+            .get_treasury()
+            .returns(ReturnsResult)
+            .run()
+            .into_iter()
+            .collect()
+    }
+}
+
+#[test]
+fn test_deposit_and_transfer_tokens() {
+    let mut state = MultisigTestState::new();
+    state.deploy_multisig_contract();
+
+    let depositor = TestAddress::new("depositor");
+    state
+        .world
+        .account(depositor)
+        .nonce(1)
+        .esdt_balance(FUNGIBLE_TOKEN_ID, 1_000)
+        .esdt_nft_balance(NFT_TOKEN_ID, 1, 1, ());
+
+    // tokens from addresses without a role are accepted, but not recorded
+    let outsider = TestAddress::new("outsider");
+    state
+        .world
+        .account(outsider)
+        .nonce(1)
+        .esdt_balance(UNSOLICITED_TOKEN_ID, 1_000);
+    state
+        .world
+        .tx()
+        .from(outsider)
+        .to(MULTISIG_ADDRESS)
+        .typed(multisig_proxy::MultisigProxy)
+        .deposit()
+        .single_esdt(&UNSOLICITED_TOKEN_ID.into(), 0, &BigUint::from(1_000u64))
+        .run();
+    assert_eq!(state.treasury(), vec![]);
+
+    let action_id: usize = state
+        .world
+        .tx()
+        .from(PROPOSER_ADDRESS)
+        .to(MULTISIG_ADDRESS)
+        .typed(multisig_proxy::MultisigProxy)
+        .propose_add_proposer(depositor, OptionalValue::<u64>::None)
+        .returns(ReturnsResult)
+        .run();
+    state.sign(action_id);
+    state.perform(action_id);
+
+    let mut payments = ManagedVec::<StaticApi, EsdtTokenPayment<StaticApi>>::new();
+    payments.push(EsdtTokenPayment::new(
+        FUNGIBLE_TOKEN_ID.to_token_identifier(),
+        0,
+        BigUint::from(1_000u64),
+    ));
+    payments.push(EsdtTokenPayment::new(
+        NFT_TOKEN_ID.to_token_identifier(),
+        1,
+        BigUint::from(1u64),
+    ));
+    state
+        .world
+        .tx()
+        .from(depositor)
+        .to(MULTISIG_ADDRESS)
+        .typed(multisig_proxy::MultisigProxy)
+        .deposit()
+        .multi_esdt(payments)
+        .run();
+
+    assert_eq!(
+        state.treasury(),
+        vec![
+            EgldOrEsdtTokenPayment::new(
+                EgldOrEsdtTokenIdentifier::esdt(FUNGIBLE_TOKEN_ID.to_token_identifier()),
+                0,
+                BigUint::from(1_000u64),
+            ),
+            EgldOrEsdtTokenPayment::new(
+                EgldOrEsdtTokenIdentifier::esdt(NFT_TOKEN_ID.to_token_identifier()),
+                1,
+                BigUint::from(1u64),
+            ),
+        ]
+    );
+
+    let receiver = TestAddress::new("receiver");
+    state.world.account(receiver).nonce(1);
+
+    let action_id: usize = state
+        .world
+        .tx()
+        .from(PROPOSER_ADDRESS)
+        .to(MULTISIG_ADDRESS)
+        .typed(multisig_proxy::MultisigProxy)
+        .propose_transfer_nft(
+            receiver,
+            NFT_TOKEN_ID,
+            1u64,
+            1u64,
+            Option::<GasLimit>::None,
+            FunctionCall::empty(),
+        )
+        .returns(ReturnsResult)
+        .run();
+    state.sign(action_id);
+    state.perform(action_id);
+
+    let mut tokens = MultiValueEncoded::<StaticApi, _>::new();
+    tokens.push(MultiValue3::from((
+        FUNGIBLE_TOKEN_ID.to_token_identifier(),
+        0u64,
+        BigUint::from(400u64),
+    )));
+    let action_id: usize = state
+        .world
+        .tx()
+        .from(PROPOSER_ADDRESS)
+        .to(MULTISIG_ADDRESS)
+        .typed(multisig_proxy::MultisigProxy)
+        .propose_transfer_multi_token(receiver, Option::<GasLimit>::None, tokens)
+        .returns(ReturnsResult)
+        .run();
+    state.sign(action_id);
+    state.perform(action_id);
+
+    state
+        .world
+        .check_account(receiver)
+        .esdt_balance(FUNGIBLE_TOKEN_ID, 400)
+        .esdt_nft_balance_and_attributes(NFT_TOKEN_ID, 1, 1, "");
+
+    // the NFT is gone, so it no longer shows up
+    assert_eq!(
+        state.treasury(),
+        vec![EgldOrEsdtTokenPayment::new(
+            EgldOrEsdtTokenIdentifier::esdt(FUNGIBLE_TOKEN_ID.to_token_identifier()),
+            0,
+            BigUint::from(600u64),
+        )]
+    );
+}
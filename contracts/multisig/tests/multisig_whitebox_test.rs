@@ -3,10 +3,12 @@ use factorial::Factorial;
 use multisig::{
     action::GasLimit, multisig_perform::MultisigPerformModule,
     multisig_propose::MultisigProposeModule, multisig_sign::MultisigSignModule,
+    multisig_state::MultisigStateModule, multisig_timelock::MultisigTimelockModule,
     user_role::UserRole, Multisig,
 };
 use multiversx_sc::{
     api::ManagedTypeApi,
+    imports::OptionalValue,
     types::{
         Address, BigUint, BoxedBytes, CodeMetadata, FunctionCall, ManagedBuffer, ManagedVec,
         TestAddress, TestSCAddress,
@@ -123,11 +125,17 @@ fn call_propose(
             action_id = match action {
                 ActionRaw::_Nothing => panic!("Invalid action"),
                 ActionRaw::AddBoardMember(addr) => {
-                    sc.propose_add_board_member(managed_address!(&addr))
+                    sc.propose_add_board_member(managed_address!(&addr), OptionalValue::None)
+                }
+                ActionRaw::AddProposer(addr) => {
+                    sc.propose_add_proposer(managed_address!(&addr), OptionalValue::None)
+                }
+                ActionRaw::RemoveUser(addr) => {
+                    sc.propose_remove_user(managed_address!(&addr), OptionalValue::None)
+                }
+                ActionRaw::ChangeQuorum(new_size) => {
+                    sc.propose_change_quorum(new_size, OptionalValue::None)
                 }
-                ActionRaw::AddProposer(addr) => sc.propose_add_proposer(managed_address!(&addr)),
-                ActionRaw::RemoveUser(addr) => sc.propose_remove_user(managed_address!(&addr)),
-                ActionRaw::ChangeQuorum(new_size) => sc.propose_change_quorum(new_size),
                 ActionRaw::SendTransferExecute(call_data) => sc.propose_transfer_execute(
                     managed_address!(&call_data.to),
                     BigUint::from_bytes_be(&call_data.egld_amount.to_bytes_be()),
@@ -356,6 +364,83 @@ fn test_try_remove_all_board_members() {
         });
 }
 
+#[test]
+fn test_pending_action_ids() {
+    let mut world = setup();
+
+    let discarded_action_id = call_propose(&mut world, ActionRaw::ChangeQuorum(1), None);
+    let performed_action_id = call_propose(&mut world, ActionRaw::ChangeQuorum(1), None);
+    world
+        .tx()
+        .from(OWNER)
+        .to(MULTISIG)
+        .whitebox(multisig::contract_obj, |sc| {
+            assert_eq!(sc.pending_action_ids().len(), 2);
+        });
+
+    world
+        .tx()
+        .from(BOARD_MEMBER)
+        .to(MULTISIG)
+        .whitebox(multisig::contract_obj, |sc| {
+            sc.discard_action(discarded_action_id)
+        });
+    world
+        .tx()
+        .from(BOARD_MEMBER)
+        .to(MULTISIG)
+        .whitebox(multisig::contract_obj, |sc| sc.sign(performed_action_id));
+    world
+        .tx()
+        .from(BOARD_MEMBER)
+        .to(MULTISIG)
+        .whitebox(multisig::contract_obj, |sc| {
+            assert!(sc.pending_action_ids().contains(&performed_action_id));
+            assert!(!sc.pending_action_ids().contains(&discarded_action_id));
+
+            let _ = sc.perform_action_endpoint(performed_action_id);
+            assert!(sc.pending_action_ids().is_empty());
+        });
+}
+
+#[test]
+fn test_cleanup_expired_clears_action_storage() {
+    let mut world = setup();
+
+    world.current_block().block_timestamp(1_000);
+
+    let mut action_id = 0;
+    world
+        .tx()
+        .from(PROPOSER)
+        .to(MULTISIG)
+        .whitebox(multisig::contract_obj, |sc| {
+            action_id = sc.propose_change_quorum(1, OptionalValue::Some(1_100));
+        });
+
+    world.current_block().block_timestamp(1_101);
+    world
+        .tx()
+        .from(OWNER)
+        .to(MULTISIG)
+        .whitebox(multisig::contract_obj, |sc| {
+            sc.cleanup_expired(action_id, action_id);
+
+            assert!(sc.action_mapper().get(action_id).is_nothing());
+            assert!(sc.quorum_for_action(action_id).is_empty());
+            assert!(sc.pending_action_ids().is_empty());
+        });
+
+    world
+        .tx()
+        .from(BOARD_MEMBER)
+        .to(MULTISIG)
+        .returns(ExpectMessage("action does not exist"))
+        .whitebox(multisig::contract_obj, |sc| {
+            let _ = sc.perform_action_endpoint(action_id);
+        });
+}
+
 #[test]
 fn test_change_quorum() {
     let mut world = setup();
@@ -6,9 +6,9 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                           33
+// Endpoints:                           59
 // Async Callback:                       1
-// Total number of exported functions:  36
+// Total number of exported functions:  62
 
 #![no_std]
 
@@ -23,6 +23,7 @@ multiversx_sc_wasm_adapter::endpoints! {
         deposit => deposit
         discardAction => discard_action_endpoint
         discardBatch => discard_batch
+        cleanupExpired => cleanup_expired
         getQuorum => quorum
         getNumBoardMembers => num_board_members
         getNumGroups => num_groups
@@ -30,17 +31,33 @@ multiversx_sc_wasm_adapter::endpoints! {
         getActionGroup => action_groups
         getLastGroupActionId => last_action_group_id
         getActionLastIndex => get_action_last_index
+        getActionExpiry => action_expiry
+        quorumReached => quorum_reached
+        getActionTypeQuorum => get_action_type_quorum
+        getSignerWeight => get_signer_weight
+        getTotalBoardWeight => get_total_board_weight
         proposeAddBoardMember => propose_add_board_member
         proposeAddProposer => propose_add_proposer
         proposeRemoveUser => propose_remove_user
         proposeChangeQuorum => propose_change_quorum
+        proposeChangeActionTypeQuorum => propose_change_action_type_quorum
+        proposeChangeSignerWeight => propose_change_signer_weight
+        proposeSetSpendingLimit => propose_set_spending_limit
+        proposeRemoveSpendingLimit => propose_remove_spending_limit
+        proposeChangeTimelockDelay => propose_change_timelock_delay
+        proposeChangeActionTypeTimelockDelay => propose_change_action_type_timelock_delay
         proposeTransferExecute => propose_transfer_execute
         proposeTransferExecuteEsdt => propose_transfer_execute_esdt
+        proposeTransferNft => propose_transfer_nft
+        proposeTransferMultiToken => propose_transfer_multi_token
         proposeAsyncCall => propose_async_call
         proposeSCDeployFromSource => propose_sc_deploy_from_source
         proposeSCUpgradeFromSource => propose_sc_upgrade_from_source
         proposeSyncCall => propose_sync_call
+        proposeWithExpiry => propose_with_expiry
+        proposeManageEsdt => propose_manage_esdt
         proposeBatch => propose_batch
+        proposeBatchWithExpiry => propose_batch_with_expiry
         sign => sign
         signBatch => sign_batch
         signAndPerform => sign_and_perform
@@ -49,9 +66,18 @@ multiversx_sc_wasm_adapter::endpoints! {
         unsignBatch => unsign_batch
         signed => signed
         unsignForOutdatedBoardMembers => unsign_for_outdated_board_members
-        quorumReached => quorum_reached
         performAction => perform_action_endpoint
         performBatch => perform_batch
+        getTimelockDelay => timelock_delay
+        getQuorumReachedTimestamp => quorum_reached_timestamp
+        getActionTypeTimelockDelay => get_action_type_timelock_delay
+        spendWithinLimit => spend_within_limit
+        getRemainingAllowance => get_remaining_allowance
+        getSpendingLimit => get_spending_limit
+        performWithSignatures => perform_with_signatures
+        getSignatureMessage => get_signature_message
+        isSignatureNonceUsed => signature_nonce_used
+        getTreasury => get_treasury
         dnsRegister => dns_register
     )
 }
@@ -15,15 +15,19 @@ pub trait PairMock {
     fn swap_tokens_fixed_input(
         &self,
         _token_out: TokenIdentifier,
-        _amount_out_min: BigUint,
+        amount_out_min: BigUint,
     ) -> EsdtTokenPayment {
         let payment = self.call_value().single_esdt();
         let first_token_id = self.first_token_id().get();
         let second_token_id = self.second_token_id().get();
+        let amount_out =
+            self.get_equivalent(payment.token_identifier.clone(), payment.amount.clone());
+        require!(amount_out >= amount_out_min, "Slippage exceeded");
+
         let output = if payment.token_identifier == first_token_id {
-            EsdtTokenPayment::new(second_token_id, 0, &payment.amount * 2u32)
+            EsdtTokenPayment::new(second_token_id, 0, amount_out)
         } else {
-            EsdtTokenPayment::new(first_token_id, 0, &payment.amount / 2u32)
+            EsdtTokenPayment::new(first_token_id, 0, amount_out)
         };
 
         self.tx()
@@ -38,9 +42,77 @@ pub trait PairMock {
         output
     }
 
+    #[only_owner]
+    #[endpoint(setLpTokenIdentifier)]
+    fn set_lp_token_identifier(&self, token_identifier: TokenIdentifier) {
+        self.lp_token_identifier().set(token_identifier);
+    }
+
+    /// Both tokens are required, in the pair order, and are added at the 1:2 swap price.
+    /// Returns the LP tokens and the refunds of the unused amounts.
+    #[payable]
+    #[endpoint(addLiquidity)]
+    fn add_liquidity(
+        &self,
+        first_token_amount_min: BigUint,
+        second_token_amount_min: BigUint,
+    ) -> MultiValue3<EsdtTokenPayment, EsdtTokenPayment, EsdtTokenPayment> {
+        let [first_payment, second_payment] = self.call_value().multi_esdt();
+        let first_token_id = self.first_token_id().get();
+        let second_token_id = self.second_token_id().get();
+        require!(
+            first_payment.token_identifier == first_token_id
+                && second_payment.token_identifier == second_token_id,
+            "Invalid payments"
+        );
+
+        let first_amount =
+            core::cmp::min(first_payment.amount.clone(), &second_payment.amount / 2u32);
+        let second_amount = &first_amount * 2u32;
+        require!(
+            first_amount > 0
+                && first_amount >= first_token_amount_min
+                && second_amount >= second_token_amount_min,
+            "Insufficient liquidity added"
+        );
+
+        let lp_payment =
+            EsdtTokenPayment::new(self.lp_token_identifier().get(), 0, first_amount.clone());
+        let first_refund =
+            EsdtTokenPayment::new(first_token_id, 0, &first_payment.amount - &first_amount);
+        let second_refund =
+            EsdtTokenPayment::new(second_token_id, 0, &second_payment.amount - &second_amount);
+
+        let mut payments = ManagedVec::from_single_item(lp_payment.clone());
+        for refund in [&first_refund, &second_refund] {
+            if refund.amount > 0 {
+                payments.push(refund.clone());
+            }
+        }
+        self.tx().to(ToCaller).payment(payments).transfer();
+
+        (lp_payment, first_refund, second_refund).into()
+    }
+
+    /// Amount of the other token of the pair worth `amount_in`, at the 1:2 swap price
+    #[view(getEquivalent)]
+    fn get_equivalent(&self, token_in: TokenIdentifier, amount_in: BigUint) -> BigUint {
+        if token_in == self.first_token_id().get() {
+            amount_in * 2u32
+        } else {
+            amount_in / 2u32
+        }
+    }
+
+    #[view(getFirstTokenId)]
     #[storage_mapper("firstTokenId")]
     fn first_token_id(&self) -> SingleValueMapper<TokenIdentifier>;
 
+    #[view(getSecondTokenId)]
     #[storage_mapper("secondTokenId")]
     fn second_token_id(&self) -> SingleValueMapper<TokenIdentifier>;
+
+    #[view(getLpTokenIdentifier)]
+    #[storage_mapper("lpTokenIdentifier")]
+    fn lp_token_identifier(&self) -> SingleValueMapper<TokenIdentifier>;
 }
@@ -5,9 +5,9 @@
 ////////////////////////////////////////////////////
 
 // Init:                                 1
-// Endpoints:                            1
+// Endpoints:                            7
 // Async Callback (empty):               1
-// Total number of exported functions:   3
+// Total number of exported functions:   9
 
 #![no_std]
 
@@ -19,6 +19,12 @@ multiversx_sc_wasm_adapter::endpoints! {
     (
         init => init
         swapTokensFixedInput => swap_tokens_fixed_input
+        setLpTokenIdentifier => set_lp_token_identifier
+        addLiquidity => add_liquidity
+        getEquivalent => get_equivalent
+        getFirstTokenId => first_token_id
+        getSecondTokenId => second_token_id
+        getLpTokenIdentifier => lp_token_identifier
     )
 }
 
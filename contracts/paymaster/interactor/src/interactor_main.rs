@@ -15,6 +15,7 @@ const STATE_FILE: &str = "state.toml";
 
 const ONE_UNIT: u64 = 1_000_000_000_000_000_000;
 const ONE_MILLION: u64 = 1_000_000;
+const INTENT_VALIDITY_SECONDS: u64 = 600;
 
 pub static WEGLD_TOKEN_ID: &[u8] = b"WEGLD-a28c59";
 pub static MEX_TOKEN_ID: &[u8] = b"MEX-a659d0";
@@ -139,23 +140,50 @@ impl ContractInteract {
 
         let dest = &self.config.egld_mex_pair_address;
         let endpoint_name = ManagedBuffer::new_from_bytes(SWAP_TOKENS_FIXED_INPUT_FUNC_NAME);
-        let endpoint_args = MultiValueVec::from(vec![
-            ManagedBuffer::new_from_bytes(WEGLD_TOKEN_ID),
-            ManagedBuffer::new_from_bytes(b"1"),
-        ]);
+        let mut endpoint_args = ManagedVec::new();
+        endpoint_args.push(ManagedBuffer::new_from_bytes(WEGLD_TOKEN_ID));
+        endpoint_args.push(ManagedBuffer::new_from_bytes(b"1"));
 
-        let mut payments = ManagedVec::<StaticApi, EsdtTokenPayment<StaticApi>>::new();
-        payments.push(EsdtTokenPayment::new(
+        let fee_payment = EsdtTokenPayment::new(
             TokenIdentifier::from(WEGLD_TOKEN_ID),
             token_nonce,
             BigUint::<StaticApi>::from(ONE_UNIT / 100), // 0.01 WEGLD
-        ));
-        payments.push(EsdtTokenPayment::new(
+        );
+        let mut intent_payments = ManagedVec::<StaticApi, EsdtTokenPayment<StaticApi>>::new();
+        intent_payments.push(EsdtTokenPayment::new(
             TokenIdentifier::from(MEX_TOKEN_ID),
             token_nonce,
             token_amount, // 1_000_000 MEX
         ));
 
+        let nonce = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(proxy::PaymasterContractProxy)
+            .user_nonce(&self.wallet_address)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        let intent = proxy::ForwardIntent {
+            user: ManagedAddress::from(&self.wallet_address),
+            relayer_addr: ManagedAddress::from(&relayer_addr.to_address()),
+            dest: ManagedAddress::from(&dest.to_address()),
+            min_gas_limit: 1_000_000u64,
+            endpoint_name,
+            endpoint_args,
+            payments: intent_payments.clone(),
+            max_fee: fee_payment.clone(),
+            nonce,
+            deadline: current_timestamp() + INTENT_VALIDITY_SECONDS,
+        };
+
+        let mut payments = ManagedVec::<StaticApi, EsdtTokenPayment<StaticApi>>::new();
+        payments.push(fee_payment);
+        payments.append_vec(intent_payments);
+
+        // The wallet submits its own intent, so no signature is needed
         let response = self
             .interactor
             .tx()
@@ -164,11 +192,8 @@ impl ContractInteract {
             .gas(30_000_000u64)
             .typed(proxy::PaymasterContractProxy)
             .forward_execution(
-                relayer_addr,
-                dest,
-                1_000_000u64,
-                endpoint_name,
-                endpoint_args,
+                intent,
+                OptionalValue::<ManagedByteArray<StaticApi, 64>>::None,
             )
             .payment(payments)
             .returns(ReturnsResultUnmanaged)
@@ -178,3 +203,10 @@ impl ContractInteract {
         println!("Result: {response:?}");
     }
 }
+
+fn current_timestamp() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs()
+}
@@ -81,27 +81,105 @@ where
     To: TxTo<Env>,
     Gas: TxGas<Env>,
 {
+    /// Forwards the call described by the intent. 
+    ///  
+    /// When the user submits the intent, the first payment is the relayer fee. 
+    /// It must be in the `max_fee` token and may not exceed it. 
+    /// The rest of the payments must be exactly the ones in the intent. 
+    ///  
+    /// When anyone else submits it, no payment is accepted: the `max_fee` and the intent payments 
+    /// are taken from the user's deposit. 
+    /// Refunds and back-transfers go to the user of the intent. 
     pub fn forward_execution<
-        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
-        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
-        Arg2: ProxyArg<u64>,
-        Arg3: ProxyArg<ManagedBuffer<Env::Api>>,
-        Arg4: ProxyArg<MultiValueEncoded<Env::Api, ManagedBuffer<Env::Api>>>,
+        Arg0: ProxyArg<ForwardIntent<Env::Api>>,
+        Arg1: ProxyArg<OptionalValue<ManagedByteArray<Env::Api, 64usize>>>,
     >(
         self,
-        relayer_addr: Arg0,
-        dest: Arg1,
-        min_gas_limit: Arg2,
-        endpoint_name: Arg3,
-        endpoint_args: Arg4,
+        intent: Arg0,
+        opt_signature: Arg1,
     ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
         self.wrapped_tx
             .raw_call("forwardExecution")
-            .argument(&relayer_addr)
-            .argument(&dest)
-            .argument(&min_gas_limit)
-            .argument(&endpoint_name)
-            .argument(&endpoint_args)
+            .argument(&intent)
+            .argument(&opt_signature)
+            .original_result()
+    }
+
+    /// Nonce the next intent of the user must carry. 
+    pub fn user_nonce<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        user: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getUserNonce")
+            .argument(&user)
+            .original_result()
+    }
+
+    pub fn deposit(
+        self,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("deposit")
+            .original_result()
+    }
+
+    pub fn withdraw<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<u64>,
+        Arg2: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        token_id: Arg0,
+        token_nonce: Arg1,
+        amount: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("withdraw")
+            .argument(&token_id)
+            .argument(&token_nonce)
+            .argument(&amount)
+            .original_result()
+    }
+
+    pub fn user_deposit<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<TokenIdentifier<Env::Api>>,
+        Arg2: ProxyArg<u64>,
+    >(
+        self,
+        user: Arg0,
+        token_id: Arg1,
+        token_nonce: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getUserDeposit")
+            .argument(&user)
+            .argument(&token_id)
+            .argument(&token_nonce)
             .original_result()
     }
 }
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct ForwardIntent<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub user: ManagedAddress<Api>,
+    pub relayer_addr: ManagedAddress<Api>,
+    pub dest: ManagedAddress<Api>,
+    pub min_gas_limit: u64,
+    pub endpoint_name: ManagedBuffer<Api>,
+    pub endpoint_args: ManagedVec<Api, ManagedBuffer<Api>>,
+    pub payments: ManagedVec<Api, EsdtTokenPayment<Api>>,
+    pub max_fee: EsdtTokenPayment<Api>,
+    pub nonce: u64,
+    pub deadline: u64,
+}
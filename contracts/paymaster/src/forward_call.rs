@@ -2,63 +2,91 @@ use multiversx_sc::imports::*;
 
 pub type PaymentsVec<M> = ManagedVec<M, EsdtTokenPayment<M>>;
 
+const FEE_PAYMENT_INDEX: usize = 0;
+
 static ERR_CALLBACK_MSG: &[u8] = b"Error received in callback:";
 
+/// Part of the remaining gas kept for the promise callback.
+const GAS_FOR_CALLBACK_DIVISOR: u64 = 4;
+/// Part of the remaining gas forwarded to the destination.
+const GAS_FOR_CALL_DIVISOR: u64 = 2;
+
 #[multiversx_sc::module]
 pub trait ForwardCall {
+    #[allow(clippy::too_many_arguments)]
     fn forward_call(
         &self,
+        original_caller: ManagedAddress,
+        relayer_addr: ManagedAddress,
+        fee_payment: EsdtTokenPayment,
         dest: ManagedAddress,
         min_gas_limit: u64,
         endpoint_name: ManagedBuffer,
         payments: PaymentsVec<Self::Api>,
         endpoint_args: MultiValueEncoded<ManagedBuffer>,
     ) {
-        self.require_min_gas_limit(min_gas_limit);
-        let original_caller = self.blockchain().get_caller();
+        let gas_left = self.blockchain().get_gas_left();
+        let gas_for_call = gas_left / GAS_FOR_CALL_DIVISOR;
+        let gas_for_callback = gas_left / GAS_FOR_CALLBACK_DIVISOR;
+        self.require_min_gas_limit(gas_for_call, min_gas_limit);
 
         self.tx()
             .to(&dest)
             .raw_call(endpoint_name)
             .arguments_raw(endpoint_args.to_arg_buffer())
             .payment(payments.clone())
-            .callback(
-                self.callbacks()
-                    .transfer_callback(original_caller, payments),
-            )
-            .async_call_and_exit();
+            .gas(gas_for_call)
+            .callback(self.callbacks().transfer_callback(
+                original_caller,
+                relayer_addr,
+                fee_payment,
+                payments,
+            ))
+            .gas_for_callback(gas_for_callback)
+            .register_promise();
     }
 
-    #[callback]
+    #[promises_callback]
     fn transfer_callback(
         &self,
         original_caller: ManagedAddress,
+        relayer_addr: ManagedAddress,
+        fee_payment: EsdtTokenPayment,
         payments: PaymentsVec<Self::Api>,
         #[call_result] result: ManagedAsyncCallResult<MultiValueEncoded<ManagedBuffer>>,
     ) -> MultiValueEncoded<ManagedBuffer> {
         match result {
             ManagedAsyncCallResult::Ok(return_values) => {
-                let back_transfers = self.blockchain().get_back_transfers();
+                // The relayer only gets paid once the destination call went through
+                self.tx().to(&relayer_addr).payment(fee_payment).transfer();
 
-                // Send the original input tokens back to the original caller
-                if !back_transfers.esdt_payments.is_empty() {
+                // Tokens sent back by the destination arrive as the callback payment
+                let back_transfers_esdt = self.call_value().all_esdt_transfers().clone_value();
+                if !back_transfers_esdt.is_empty() {
                     self.tx()
                         .to(&original_caller)
-                        .payment(&back_transfers.esdt_payments)
+                        .payment(&back_transfers_esdt)
                         .transfer();
                 }
-                if back_transfers.total_egld_amount > 0 {
+
+                let back_transfers_egld = self.call_value().egld_direct_non_strict().clone_value();
+                if back_transfers_egld > 0 {
                     self.tx()
                         .to(&original_caller)
-                        .egld(back_transfers.total_egld_amount)
+                        .egld(back_transfers_egld)
                         .transfer();
                 }
-                // Send the resulted tokens to the original caller
+
                 return_values
             }
             ManagedAsyncCallResult::Err(err) => {
-                // Send the resulted tokens to the original caller
-                self.tx().to(&original_caller).payment(payments).transfer();
+                // Send the original payments, fee included, back to the original caller
+                let mut refund_payments = payments;
+                refund_payments.push(fee_payment);
+                self.tx()
+                    .to(&original_caller)
+                    .payment(refund_payments)
+                    .transfer();
 
                 let mut err_result = MultiValueEncoded::new();
                 err_result.push(ManagedBuffer::new_from_bytes(ERR_CALLBACK_MSG));
@@ -69,10 +97,19 @@ pub trait ForwardCall {
         }
     }
 
-    fn require_min_gas_limit(&self, min_gas_limit: u64) {
-        let gas_left = self.blockchain().get_gas_left();
+    fn split_fee_payment(&self) -> (EsdtTokenPayment, PaymentsVec<Self::Api>) {
+        let mut payments = self.call_value().all_esdt_transfers().clone_value();
+        require!(!payments.is_empty(), "There is no fee for payment!");
+
+        let fee_payment = (*payments.get(FEE_PAYMENT_INDEX)).clone();
+        payments.remove(FEE_PAYMENT_INDEX);
+
+        (fee_payment, payments)
+    }
+
+    fn require_min_gas_limit(&self, gas_for_call: u64, min_gas_limit: u64) {
         require!(
-            gas_left >= min_gas_limit,
+            gas_for_call >= min_gas_limit,
             "Minimum required gas not provided"
         );
     }
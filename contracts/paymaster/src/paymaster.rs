@@ -4,59 +4,64 @@ use multiversx_sc::imports::*;
 
 pub mod forward_call;
 pub mod paymaster_proxy;
-const FEE_PAYMENT_INDEX: usize = 0;
+pub mod signed_intent;
+pub mod user_deposit;
 
 #[multiversx_sc::contract]
-pub trait PaymasterContract: forward_call::ForwardCall {
+pub trait PaymasterContract:
+    forward_call::ForwardCall + signed_intent::SignedIntentModule + user_deposit::UserDepositModule
+{
     #[init]
     fn init(&self) {}
 
     #[upgrade]
     fn upgrade(&self) {}
 
+    /// Forwards the call described by the intent.
+    ///
+    /// When the user submits the intent, the first payment is the relayer fee.
+    /// It must be in the `max_fee` token and may not exceed it.
+    /// The rest of the payments must be exactly the ones in the intent.
+    ///
+    /// When anyone else submits it, no payment is accepted: the `max_fee` and the intent payments
+    /// are taken from the user's deposit.
+    /// Refunds and back-transfers go to the user of the intent.
     #[endpoint(forwardExecution)]
     #[payable]
     fn forward_execution(
         &self,
-        relayer_addr: ManagedAddress,
-        dest: ManagedAddress,
-        min_gas_limit: u64,
-        endpoint_name: ManagedBuffer,
-        endpoint_args: MultiValueEncoded<ManagedBuffer>,
+        intent: signed_intent::ForwardIntent<Self::Api>,
+        opt_signature: OptionalValue<signed_intent::Signature<Self::Api>>,
     ) {
-        self.require_dest_same_shard(&dest);
-        let payments = self.call_value().all_esdt_transfers();
-        require!(!payments.is_empty(), "There is no fee for payment!");
+        let caller = self.blockchain().get_caller();
+        let is_relayed = caller != intent.user;
+        let (fee_payment, payments_without_fee) = if is_relayed {
+            require!(
+                self.call_value().all_esdt_transfers().is_empty(),
+                "Relayed intents are paid from the user deposit"
+            );
+            (intent.max_fee.clone(), intent.payments.clone())
+        } else {
+            self.split_fee_payment()
+        };
+        self.require_valid_intent(&intent, &fee_payment, &payments_without_fee, opt_signature);
 
-        let fee_payment = payments.get(FEE_PAYMENT_INDEX);
-        self.tx()
-            .to(&relayer_addr)
-            .payment(EsdtTokenPayment::new(
-                fee_payment.token_identifier.clone(),
-                fee_payment.token_nonce,
-                fee_payment.amount.clone(),
-            ))
-            .transfer();
-
-        let mut payments_without_fee = payments.clone();
-        payments_without_fee.remove(FEE_PAYMENT_INDEX);
+        if is_relayed {
+            self.take_from_deposit(&intent.user, &fee_payment);
+            for payment in payments_without_fee.iter() {
+                self.take_from_deposit(&intent.user, &payment);
+            }
+        }
 
         self.forward_call(
-            dest,
-            min_gas_limit,
-            endpoint_name,
+            intent.user,
+            intent.relayer_addr,
+            fee_payment,
+            intent.dest,
+            intent.min_gas_limit,
+            intent.endpoint_name,
             payments_without_fee,
-            endpoint_args,
-        );
-    }
-
-    fn require_dest_same_shard(&self, dest: &ManagedAddress) {
-        let own_sc_address = self.blockchain().get_sc_address();
-        let own_shard = self.blockchain().get_shard_of_address(&own_sc_address);
-        let dest_shard = self.blockchain().get_shard_of_address(dest);
-        require!(
-            own_shard == dest_shard,
-            "Destination must be in the same shard"
+            intent.endpoint_args.into(),
         );
     }
 }
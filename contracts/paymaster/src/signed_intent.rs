@@ -0,0 +1,96 @@
+use multiversx_sc::{api::ED25519_SIGNATURE_BYTE_LEN, derive_imports::*, imports::*};
+
+use crate::forward_call::PaymentsVec;
+
+pub type Signature<M> = ManagedByteArray<M, ED25519_SIGNATURE_BYTE_LEN>;
+
+/// Everything the user authorises. The relayer may not change any of it.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct ForwardIntent<M: ManagedTypeApi> {
+    pub user: ManagedAddress<M>,
+    pub relayer_addr: ManagedAddress<M>,
+    pub dest: ManagedAddress<M>,
+    pub min_gas_limit: u64,
+    pub endpoint_name: ManagedBuffer<M>,
+    pub endpoint_args: ManagedVec<M, ManagedBuffer<M>>,
+    pub payments: PaymentsVec<M>,
+    pub max_fee: EsdtTokenPayment<M>,
+    pub nonce: u64,
+    pub deadline: u64,
+}
+
+#[multiversx_sc::module]
+pub trait SignedIntentModule {
+    /// Checks the intent against the received payments and consumes its nonce.
+    ///
+    /// The user can submit the intent directly. Anyone else, the relayer included,
+    /// needs the user's signature over it.
+    fn require_valid_intent(
+        &self,
+        intent: &ForwardIntent<Self::Api>,
+        fee_payment: &EsdtTokenPayment,
+        payments_without_fee: &PaymentsVec<Self::Api>,
+        opt_signature: OptionalValue<Signature<Self::Api>>,
+    ) {
+        require!(
+            fee_payment.token_identifier == intent.max_fee.token_identifier
+                && fee_payment.token_nonce == intent.max_fee.token_nonce,
+            "Invalid fee token"
+        );
+        require!(
+            fee_payment.amount <= intent.max_fee.amount,
+            "Fee exceeds the signed maximum"
+        );
+        require!(
+            payments_without_fee == &intent.payments,
+            "Payments do not match the signed intent"
+        );
+
+        let current_timestamp = self.blockchain().get_block_timestamp();
+        require!(current_timestamp <= intent.deadline, "Intent expired");
+
+        let user_nonce_mapper = self.user_nonce(&intent.user);
+        let expected_nonce = user_nonce_mapper.get();
+        require!(intent.nonce == expected_nonce, "Invalid intent nonce");
+
+        match opt_signature {
+            OptionalValue::Some(signature) => {
+                self.require_valid_intent_signature(intent, &signature)
+            }
+            OptionalValue::None => {
+                let caller = self.blockchain().get_caller();
+                require!(caller == intent.user, "Missing intent signature");
+            }
+        }
+
+        user_nonce_mapper.set(expected_nonce + 1);
+    }
+
+    /// The signed message is the paymaster address followed by the top-encoded intent,
+    /// so a signature can not be replayed on another paymaster instance.
+    fn require_valid_intent_signature(
+        &self,
+        intent: &ForwardIntent<Self::Api>,
+        signature: &Signature<Self::Api>,
+    ) {
+        let mut message = self
+            .blockchain()
+            .get_sc_address()
+            .as_managed_buffer()
+            .clone();
+        let encoded_intent = self.serializer().top_encode_to_managed_buffer(intent);
+        message.append(&encoded_intent);
+
+        self.crypto().verify_ed25519(
+            intent.user.as_managed_buffer(),
+            &message,
+            signature.as_managed_buffer(),
+        );
+    }
+
+    /// Nonce the next intent of the user must carry.
+    #[view(getUserNonce)]
+    #[storage_mapper("userNonce")]
+    fn user_nonce(&self, user: &ManagedAddress) -> SingleValueMapper<u64>;
+}
@@ -0,0 +1,44 @@
+use multiversx_sc::imports::*;
+
+/// Tokens the users keep in the paymaster to pay for intents submitted by relayers.
+#[multiversx_sc::module]
+pub trait UserDepositModule {
+    #[payable]
+    #[endpoint]
+    fn deposit(&self) {
+        let payments = self.call_value().all_esdt_transfers();
+        require!(!payments.is_empty(), "No tokens to deposit");
+
+        let caller = self.blockchain().get_caller();
+        for payment in payments.iter() {
+            self.user_deposit(&caller, &payment.token_identifier, payment.token_nonce)
+                .update(|deposit| *deposit += &payment.amount);
+        }
+    }
+
+    #[endpoint]
+    fn withdraw(&self, token_id: TokenIdentifier, token_nonce: u64, amount: BigUint) {
+        let caller = self.blockchain().get_caller();
+        let payment = EsdtTokenPayment::new(token_id, token_nonce, amount);
+        self.take_from_deposit(&caller, &payment);
+
+        self.tx().to(&caller).payment(payment).transfer();
+    }
+
+    fn take_from_deposit(&self, user: &ManagedAddress, payment: &EsdtTokenPayment) {
+        self.user_deposit(user, &payment.token_identifier, payment.token_nonce)
+            .update(|deposit| {
+                require!(*deposit >= payment.amount, "Not enough tokens deposited");
+                *deposit -= &payment.amount;
+            });
+    }
+
+    #[view(getUserDeposit)]
+    #[storage_mapper("userDeposit")]
+    fn user_deposit(
+        &self,
+        user: &ManagedAddress,
+        token_id: &TokenIdentifier,
+        token_nonce: u64,
+    ) -> SingleValueMapper<BigUint>;
+}
@@ -1,14 +1,18 @@
 use adder::adder_proxy;
+use ed25519_dalek::{Signer, SigningKey};
 use imports::{
-    EgldOrEsdtTokenIdentifier, MxscPath, TestAddress, TestEsdtTransfer, TestSCAddress,
-    TestTokenIdentifier,
+    EgldOrEsdtTokenIdentifier, EsdtLocalRole, MxscPath, OptionalValue, StaticApi, TestAddress,
+    TestEsdtTransfer, TestSCAddress, TestTokenIdentifier,
 };
 use multiversx_sc::{
-    codec::{multi_types::MultiValueVec, top_encode_to_vec_u8_or_panic},
-    types::{BigUint, MultiValueEncoded},
+    codec::top_encode_to_vec_u8_or_panic,
+    types::{
+        Address, BigUint, EsdtTokenPayment, ManagedAddress, ManagedBuffer, ManagedByteArray,
+        ManagedVec,
+    },
 };
 use multiversx_sc_scenario::*;
-use multiversx_wegld_swap_sc::wegld_proxy;
+use multiversx_wegld_swap_sc::{wegld_proxy, EgldEsdtSwap};
 use paymaster::paymaster_proxy;
 
 const PAYMASTER_ADDRESS_EXPR: TestSCAddress = TestSCAddress::new("paymaster");
@@ -31,6 +35,9 @@ const FEE_AMOUNT: u64 = 20_000;
 const INITIAL_ADD_VALUE: u64 = 5;
 const ADDITIONAL_ADD_VALUE: u64 = 5;
 const UNWRAP_ENDPOINT_NAME: &[u8] = b"unwrap";
+const UNWRAP_EGLD_ENDPOINT_NAME: &[u8] = b"unwrapEgld";
+const SIGNER_SECRET_KEY: [u8; 32] = [7u8; 32];
+const INTENT_DEADLINE: u64 = 1_000;
 
 fn world() -> ScenarioWorld {
     let mut blockchain = ScenarioWorld::new();
@@ -43,6 +50,45 @@ fn world() -> ScenarioWorld {
     blockchain
 }
 
+type Signature = ManagedByteArray<StaticApi, 64>;
+
+/// Intent with nonce 0, paying at most `FEE_AMOUNT` to the relayer
+fn forward_intent(
+    user: Address,
+    dest: Address,
+    min_gas_limit: u64,
+    endpoint_name: &[u8],
+    endpoint_args: &[Vec<u8>],
+    payments: &[TestEsdtTransfer],
+) -> paymaster_proxy::ForwardIntent<StaticApi> {
+    let mut managed_args = ManagedVec::new();
+    for arg in endpoint_args {
+        managed_args.push(ManagedBuffer::new_from_bytes(arg));
+    }
+
+    let mut managed_payments = ManagedVec::new();
+    for payment in payments {
+        managed_payments.push(EsdtTokenPayment::from(*payment));
+    }
+
+    paymaster_proxy::ForwardIntent {
+        user: ManagedAddress::from(user),
+        relayer_addr: RELAYER_ADDRESS_EXPR.to_managed_address(),
+        dest: ManagedAddress::from(dest),
+        min_gas_limit,
+        endpoint_name: ManagedBuffer::new_from_bytes(endpoint_name),
+        endpoint_args: managed_args,
+        payments: managed_payments,
+        max_fee: EsdtTokenPayment::new(
+            FEE_TOKEN_ID_EXPR.to_token_identifier(),
+            0,
+            BigUint::from(FEE_AMOUNT),
+        ),
+        nonce: 0,
+        deadline: INTENT_DEADLINE,
+    }
+}
+
 struct PaymasterTestState {
     world: ScenarioWorld,
 }
@@ -118,6 +164,28 @@ impl PaymasterTestState {
         self
     }
 
+    fn setup_funded_wegld_contract(&mut self) -> &mut Self {
+        let roles = vec![EsdtLocalRole::Burn.name().to_string()];
+
+        self.world
+            .account(CALLEE_SC_WEGLD_ADDRESS_EXPR)
+            .nonce(1)
+            .code(WEGLD_PATH_EXPR)
+            .owner(OWNER_ADDRESS_EXPR)
+            .balance(BALANCE)
+            .esdt_roles(WEGLD_TOKEN_ID_EXPR, roles);
+
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS_EXPR)
+            .to(CALLEE_SC_WEGLD_ADDRESS_EXPR)
+            .whitebox(multiversx_wegld_swap_sc::contract_obj, |sc| {
+                sc.init(WEGLD_TOKEN_ID_EXPR.to_token_identifier());
+            });
+
+        self
+    }
+
     fn check_esdt_balance(
         &mut self,
         address: TestAddress,
@@ -158,11 +226,15 @@ fn test_forward_call_no_fee_payment() {
         .to(PAYMASTER_ADDRESS_EXPR)
         .typed(paymaster_proxy::PaymasterContractProxy)
         .forward_execution(
-            RELAYER_ADDRESS_EXPR,
-            CALLEE_USER_ADDRESS_EXPR,
-            0u64,
-            b"add",
-            MultiValueVec::<Vec<u8>>::new(),
+            forward_intent(
+                CALLER_ADDRESS_EXPR.to_address(),
+                CALLEE_USER_ADDRESS_EXPR.to_address(),
+                0u64,
+                b"add",
+                &[],
+                &[],
+            ),
+            OptionalValue::None::<Signature>,
         )
         .with_result(ExpectError(4, "There is no fee for payment!"))
         .run();
@@ -180,11 +252,15 @@ fn test_forward_call_user() {
         .to(PAYMASTER_ADDRESS_EXPR)
         .typed(paymaster_proxy::PaymasterContractProxy)
         .forward_execution(
-            RELAYER_ADDRESS_EXPR,
-            CALLEE_USER_ADDRESS_EXPR,
-            0u64,
-            b"add",
-            MultiValueVec::<Vec<u8>>::new(),
+            forward_intent(
+                CALLER_ADDRESS_EXPR.to_address(),
+                CALLEE_USER_ADDRESS_EXPR.to_address(),
+                0u64,
+                b"add",
+                &[],
+                &[],
+            ),
+            OptionalValue::None::<Signature>,
         )
         .egld_or_single_esdt(
             &EgldOrEsdtTokenIdentifier::esdt(FEE_TOKEN_ID_EXPR),
@@ -215,11 +291,15 @@ fn test_forward_call_sc_adder() {
         .to(PAYMASTER_ADDRESS_EXPR)
         .typed(paymaster_proxy::PaymasterContractProxy)
         .forward_execution(
-            RELAYER_ADDRESS_EXPR,
-            CALLEE_SC_ADDER_ADDRESS_EXPR,
-            0u64,
-            b"add",
-            MultiValueVec::from([top_encode_to_vec_u8_or_panic(&ADDITIONAL_ADD_VALUE)]),
+            forward_intent(
+                CALLER_ADDRESS_EXPR.to_address(),
+                CALLEE_SC_ADDER_ADDRESS_EXPR.to_address(),
+                0u64,
+                b"add",
+                &[top_encode_to_vec_u8_or_panic(&ADDITIONAL_ADD_VALUE)],
+                &[],
+            ),
+            OptionalValue::None::<Signature>,
         )
         .esdt(TestEsdtTransfer(FEE_TOKEN_ID_EXPR, 0, FEE_AMOUNT))
         .run();
@@ -253,11 +333,15 @@ fn test_forward_call_sc_adder_with_relayer_address() {
         .to(PAYMASTER_ADDRESS_EXPR)
         .typed(paymaster_proxy::PaymasterContractProxy)
         .forward_execution(
-            RELAYER_ADDRESS_EXPR,
-            CALLEE_SC_ADDER_ADDRESS_EXPR,
-            0u64,
-            b"add",
-            MultiValueVec::from([top_encode_to_vec_u8_or_panic(&ADDITIONAL_ADD_VALUE)]),
+            forward_intent(
+                CALLER_ADDRESS_EXPR.to_address(),
+                CALLEE_SC_ADDER_ADDRESS_EXPR.to_address(),
+                0u64,
+                b"add",
+                &[top_encode_to_vec_u8_or_panic(&ADDITIONAL_ADD_VALUE)],
+                &[],
+            ),
+            OptionalValue::None::<Signature>,
         )
         .esdt(TestEsdtTransfer(FEE_TOKEN_ID_EXPR, 0, FEE_AMOUNT))
         .run();
@@ -279,7 +363,7 @@ fn test_forward_call_sc_adder_with_relayer_address() {
 fn test_forward_call_wegld() {
     let mut state = PaymasterTestState::new();
     state.deploy_paymaster_contract();
-    state.deploy_adder_contract();
+    state.deploy_wegld_contract();
 
     state.check_esdt_balance(CALLER_ADDRESS_EXPR, FEE_TOKEN_ID_EXPR, BALANCE);
     state.check_esdt_balance(CALLER_ADDRESS_EXPR, WEGLD_TOKEN_ID_EXPR, BALANCE);
@@ -289,7 +373,7 @@ fn test_forward_call_wegld() {
         TestEsdtTransfer(WEGLD_TOKEN_ID_EXPR, 0, FEE_AMOUNT),
     ];
 
-    // Call fails because unwrap amount is 0
+    // Call fails because the WEGLD contract has no such endpoint
     state
         .world
         .tx()
@@ -297,21 +381,25 @@ fn test_forward_call_wegld() {
         .to(PAYMASTER_ADDRESS_EXPR)
         .typed(paymaster_proxy::PaymasterContractProxy)
         .forward_execution(
-            RELAYER_ADDRESS_EXPR,
-            CALLEE_SC_WEGLD_ADDRESS_EXPR,
-            0u64,
-            UNWRAP_ENDPOINT_NAME,
-            MultiValueEncoded::new(),
+            forward_intent(
+                CALLER_ADDRESS_EXPR.to_address(),
+                CALLEE_SC_WEGLD_ADDRESS_EXPR.to_address(),
+                0u64,
+                UNWRAP_ENDPOINT_NAME,
+                &[],
+                &[TestEsdtTransfer(WEGLD_TOKEN_ID_EXPR, 0, FEE_AMOUNT)],
+            ),
+            OptionalValue::None::<Signature>,
         )
         .multi_esdt(payments)
         .run();
 
-    // Fee is kept by the relayer
-    let new_fee_amount: u64 = 99_980_000;
-    state.check_esdt_balance(RELAYER_ADDRESS_EXPR, FEE_TOKEN_ID_EXPR, FEE_AMOUNT);
-    state.check_esdt_balance(CALLER_ADDRESS_EXPR, FEE_TOKEN_ID_EXPR, new_fee_amount);
+    // The destination call failed, so the fee is refunded to the caller
+    state.check_esdt_balance(RELAYER_ADDRESS_EXPR, FEE_TOKEN_ID_EXPR, 0);
+    state.check_esdt_balance(CALLER_ADDRESS_EXPR, FEE_TOKEN_ID_EXPR, BALANCE);
 
     // Caller has the original balance
+    state.check_esdt_balance(CALLER_ADDRESS_EXPR, WEGLD_TOKEN_ID_EXPR, BALANCE);
     state.check_egld_balance(CALLER_ADDRESS_EXPR, BALANCE);
 }
 
@@ -319,7 +407,7 @@ fn test_forward_call_wegld() {
 fn test_forward_call_fails_wegld_0_amount() {
     let mut state = PaymasterTestState::new();
     state.deploy_paymaster_contract();
-    state.deploy_adder_contract();
+    state.deploy_wegld_contract();
 
     state.check_esdt_balance(CALLER_ADDRESS_EXPR, FEE_TOKEN_ID_EXPR, BALANCE);
     state.check_esdt_balance(CALLER_ADDRESS_EXPR, WEGLD_TOKEN_ID_EXPR, BALANCE);
@@ -339,19 +427,22 @@ fn test_forward_call_fails_wegld_0_amount() {
         .to(PAYMASTER_ADDRESS_EXPR)
         .typed(paymaster_proxy::PaymasterContractProxy)
         .forward_execution(
-            RELAYER_ADDRESS_EXPR,
-            CALLEE_SC_WEGLD_ADDRESS_EXPR,
-            0u64,
-            UNWRAP_ENDPOINT_NAME,
-            MultiValueEncoded::new(),
+            forward_intent(
+                CALLER_ADDRESS_EXPR.to_address(),
+                CALLEE_SC_WEGLD_ADDRESS_EXPR.to_address(),
+                0u64,
+                UNWRAP_ENDPOINT_NAME,
+                &[],
+                &[TestEsdtTransfer(WEGLD_TOKEN_ID_EXPR, 0, failling_amount)],
+            ),
+            OptionalValue::None::<Signature>,
         )
         .multi_esdt(payments)
         .run();
 
-    // Fee is kept by the relayer
-    let new_fee_amount: u64 = 99_980_000;
-    state.check_esdt_balance(RELAYER_ADDRESS_EXPR, FEE_TOKEN_ID_EXPR, FEE_AMOUNT);
-    state.check_esdt_balance(CALLER_ADDRESS_EXPR, FEE_TOKEN_ID_EXPR, new_fee_amount);
+    // The destination call failed, so the fee is refunded to the caller
+    state.check_esdt_balance(RELAYER_ADDRESS_EXPR, FEE_TOKEN_ID_EXPR, 0);
+    state.check_esdt_balance(CALLER_ADDRESS_EXPR, FEE_TOKEN_ID_EXPR, BALANCE);
 
     // Caller has the original balance
     state.check_esdt_balance(CALLER_ADDRESS_EXPR, WEGLD_TOKEN_ID_EXPR, BALANCE);
@@ -389,20 +480,412 @@ fn test_forward_call_fails_check_amounts() {
         .to(PAYMASTER_ADDRESS_EXPR)
         .typed(paymaster_proxy::PaymasterContractProxy)
         .forward_execution(
-            RELAYER_ADDRESS_EXPR,
-            CALLEE_SC_WEGLD_ADDRESS_EXPR,
-            100u64,
-            UNWRAP_ENDPOINT_NAME,
-            MultiValueEncoded::new(),
+            forward_intent(
+                CALLER_ADDRESS_EXPR.to_address(),
+                CALLEE_SC_WEGLD_ADDRESS_EXPR.to_address(),
+                100u64,
+                UNWRAP_ENDPOINT_NAME,
+                &[],
+                &[TestEsdtTransfer(WEGLD_TOKEN_ID_EXPR, 0, sent_amount)],
+            ),
+            OptionalValue::None::<Signature>,
         )
         .multi_esdt(payments)
         .run();
 
-    // Fee is kept by the relayer
-    let new_fee_amount: u64 = 99_980_000;
-    state.check_esdt_balance(RELAYER_ADDRESS_EXPR, FEE_TOKEN_ID_EXPR, FEE_AMOUNT);
-    state.check_esdt_balance(CALLER_ADDRESS_EXPR, FEE_TOKEN_ID_EXPR, new_fee_amount);
+    // Fee is refunded to the caller, since the destination call failed
+    state.check_esdt_balance(RELAYER_ADDRESS_EXPR, FEE_TOKEN_ID_EXPR, 0);
+    state.check_esdt_balance(CALLER_ADDRESS_EXPR, FEE_TOKEN_ID_EXPR, BALANCE);
 
     // Caller has the original balance
     state.check_esdt_balance(CALLER_ADDRESS_EXPR, WEGLD_TOKEN_ID_EXPR, BALANCE);
 }
+
+#[test]
+fn test_forward_call_wegld_unwrap_back_transfers() {
+    let mut state = PaymasterTestState::new();
+    state.deploy_paymaster_contract();
+    state.setup_funded_wegld_contract();
+
+    let unwrap_amount = 1_000u64;
+    let payments = vec![
+        TestEsdtTransfer(FEE_TOKEN_ID_EXPR, 0, FEE_AMOUNT),
+        TestEsdtTransfer(WEGLD_TOKEN_ID_EXPR, 0, unwrap_amount),
+    ];
+
+    state
+        .world
+        .tx()
+        .from(CALLER_ADDRESS_EXPR)
+        .to(PAYMASTER_ADDRESS_EXPR)
+        .typed(paymaster_proxy::PaymasterContractProxy)
+        .forward_execution(
+            forward_intent(
+                CALLER_ADDRESS_EXPR.to_address(),
+                CALLEE_SC_WEGLD_ADDRESS_EXPR.to_address(),
+                0u64,
+                UNWRAP_EGLD_ENDPOINT_NAME,
+                &[],
+                &[TestEsdtTransfer(WEGLD_TOKEN_ID_EXPR, 0, unwrap_amount)],
+            ),
+            OptionalValue::None::<Signature>,
+        )
+        .multi_esdt(payments)
+        .run();
+
+    // Fee is paid to the relayer
+    state.check_esdt_balance(RELAYER_ADDRESS_EXPR, FEE_TOKEN_ID_EXPR, FEE_AMOUNT);
+    state.check_esdt_balance(CALLER_ADDRESS_EXPR, FEE_TOKEN_ID_EXPR, BALANCE - FEE_AMOUNT);
+
+    // The unwrapped EGLD is sent back to the caller
+    state.check_esdt_balance(
+        CALLER_ADDRESS_EXPR,
+        WEGLD_TOKEN_ID_EXPR,
+        BALANCE - unwrap_amount,
+    );
+    state.check_egld_balance(CALLER_ADDRESS_EXPR, BALANCE + unwrap_amount);
+    state
+        .world
+        .check_account(PAYMASTER_ADDRESS_EXPR)
+        .balance(0u64);
+}
+
+#[test]
+fn test_forward_call_wegld_unwrap_fails_refunds_fee() {
+    let mut state = PaymasterTestState::new();
+    state.deploy_paymaster_contract();
+    state.setup_funded_wegld_contract();
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS_EXPR)
+        .to(CALLEE_SC_WEGLD_ADDRESS_EXPR)
+        .typed(wegld_proxy::EgldEsdtSwapProxy)
+        .pause_endpoint()
+        .run();
+
+    let unwrap_amount = 1_000u64;
+    let payments = vec![
+        TestEsdtTransfer(FEE_TOKEN_ID_EXPR, 0, FEE_AMOUNT),
+        TestEsdtTransfer(WEGLD_TOKEN_ID_EXPR, 0, unwrap_amount),
+    ];
+
+    // Call fails because the WEGLD contract is paused
+    state
+        .world
+        .tx()
+        .from(CALLER_ADDRESS_EXPR)
+        .to(PAYMASTER_ADDRESS_EXPR)
+        .typed(paymaster_proxy::PaymasterContractProxy)
+        .forward_execution(
+            forward_intent(
+                CALLER_ADDRESS_EXPR.to_address(),
+                CALLEE_SC_WEGLD_ADDRESS_EXPR.to_address(),
+                0u64,
+                UNWRAP_EGLD_ENDPOINT_NAME,
+                &[],
+                &[TestEsdtTransfer(WEGLD_TOKEN_ID_EXPR, 0, unwrap_amount)],
+            ),
+            OptionalValue::None::<Signature>,
+        )
+        .multi_esdt(payments)
+        .run();
+
+    // Fee and payments are refunded to the caller
+    state.check_esdt_balance(RELAYER_ADDRESS_EXPR, FEE_TOKEN_ID_EXPR, 0);
+    state.check_esdt_balance(CALLER_ADDRESS_EXPR, FEE_TOKEN_ID_EXPR, BALANCE);
+    state.check_esdt_balance(CALLER_ADDRESS_EXPR, WEGLD_TOKEN_ID_EXPR, BALANCE);
+    state.check_egld_balance(CALLER_ADDRESS_EXPR, BALANCE);
+}
+
+fn signer_address(signing_key: &SigningKey) -> Address {
+    Address::from(signing_key.verifying_key().to_bytes())
+}
+
+fn adder_intent(user: &Address, nonce: u64) -> paymaster_proxy::ForwardIntent<StaticApi> {
+    let mut intent = forward_intent(
+        user.clone(),
+        CALLEE_SC_ADDER_ADDRESS_EXPR.to_address(),
+        0u64,
+        b"add",
+        &[top_encode_to_vec_u8_or_panic(&ADDITIONAL_ADD_VALUE)],
+        &[],
+    );
+    intent.nonce = nonce;
+
+    intent
+}
+
+fn sign_intent(
+    signing_key: &SigningKey,
+    intent: &paymaster_proxy::ForwardIntent<StaticApi>,
+) -> Signature {
+    let mut message = PAYMASTER_ADDRESS_EXPR.to_address().to_vec();
+    message.extend_from_slice(&top_encode_to_vec_u8_or_panic(intent));
+
+    ManagedByteArray::new_from_bytes(&signing_key.sign(&message).to_bytes())
+}
+
+impl PaymasterTestState {
+    fn setup_signer(&mut self, signing_key: &SigningKey) -> Address {
+        let user = signer_address(signing_key);
+        self.world
+            .account(&user)
+            .nonce(1)
+            .esdt_balance(FEE_TOKEN_ID_EXPR, BALANCE);
+
+        user
+    }
+}
+
+#[test]
+fn test_forward_signed_execution() {
+    let mut state = PaymasterTestState::new();
+    state.deploy_paymaster_contract();
+    state.deploy_adder_contract();
+
+    let signing_key = SigningKey::from_bytes(&SIGNER_SECRET_KEY);
+    let user = state.setup_signer(&signing_key);
+
+    let intent = adder_intent(&user, 0);
+    let signature = sign_intent(&signing_key, &intent);
+
+    state
+        .world
+        .tx()
+        .from(&user)
+        .to(PAYMASTER_ADDRESS_EXPR)
+        .typed(paymaster_proxy::PaymasterContractProxy)
+        .forward_execution(intent.clone(), OptionalValue::Some(signature.clone()))
+        .esdt(TestEsdtTransfer(FEE_TOKEN_ID_EXPR, 0, FEE_AMOUNT))
+        .run();
+
+    state
+        .world
+        .query()
+        .to(CALLEE_SC_ADDER_ADDRESS_EXPR)
+        .typed(adder_proxy::AdderProxy)
+        .sum()
+        .with_result(ExpectValue(INITIAL_ADD_VALUE + ADDITIONAL_ADD_VALUE))
+        .run();
+    state.check_esdt_balance(RELAYER_ADDRESS_EXPR, FEE_TOKEN_ID_EXPR, FEE_AMOUNT);
+
+    state
+        .world
+        .query()
+        .to(PAYMASTER_ADDRESS_EXPR)
+        .typed(paymaster_proxy::PaymasterContractProxy)
+        .user_nonce(&user)
+        .with_result(ExpectValue(1u64))
+        .run();
+
+    // The same intent can not be replayed
+    state
+        .world
+        .tx()
+        .from(&user)
+        .to(PAYMASTER_ADDRESS_EXPR)
+        .typed(paymaster_proxy::PaymasterContractProxy)
+        .forward_execution(intent, OptionalValue::Some(signature))
+        .esdt(TestEsdtTransfer(FEE_TOKEN_ID_EXPR, 0, FEE_AMOUNT))
+        .with_result(ExpectError(4, "Invalid intent nonce"))
+        .run();
+}
+
+#[test]
+fn test_forward_signed_execution_tampered_intent() {
+    let mut state = PaymasterTestState::new();
+    state.deploy_paymaster_contract();
+    state.deploy_adder_contract();
+
+    let signing_key = SigningKey::from_bytes(&SIGNER_SECRET_KEY);
+    let user = state.setup_signer(&signing_key);
+
+    let intent = adder_intent(&user, 0);
+    let signature = sign_intent(&signing_key, &intent);
+
+    // The relayer changes the endpoint, the fee receiver or the gas the user signed for
+    let mut tampered_endpoint = intent.clone();
+    tampered_endpoint.endpoint_name = ManagedBuffer::new_from_bytes(b"sum");
+    let mut tampered_relayer = intent.clone();
+    tampered_relayer.relayer_addr = CALLER_ADDRESS_EXPR.to_managed_address();
+    let mut tampered_gas = intent.clone();
+    tampered_gas.min_gas_limit = 1;
+
+    for tampered_intent in [tampered_endpoint, tampered_relayer, tampered_gas] {
+        state
+            .world
+            .tx()
+            .from(&user)
+            .to(PAYMASTER_ADDRESS_EXPR)
+            .typed(paymaster_proxy::PaymasterContractProxy)
+            .forward_execution(tampered_intent, OptionalValue::Some(signature.clone()))
+            .esdt(TestEsdtTransfer(FEE_TOKEN_ID_EXPR, 0, FEE_AMOUNT))
+            .with_result(ExpectError(10, "invalid signature"))
+            .run();
+    }
+
+    // The relayer asks for more than the signed maximum fee
+    state
+        .world
+        .tx()
+        .from(&user)
+        .to(PAYMASTER_ADDRESS_EXPR)
+        .typed(paymaster_proxy::PaymasterContractProxy)
+        .forward_execution(intent, OptionalValue::Some(signature))
+        .esdt(TestEsdtTransfer(FEE_TOKEN_ID_EXPR, 0, FEE_AMOUNT + 1))
+        .with_result(ExpectError(4, "Fee exceeds the signed maximum"))
+        .run();
+
+    state.check_esdt_balance(RELAYER_ADDRESS_EXPR, FEE_TOKEN_ID_EXPR, 0);
+}
+
+#[test]
+fn test_forward_execution_requires_signature_from_other_caller() {
+    let mut state = PaymasterTestState::new();
+    state.deploy_paymaster_contract();
+    state.deploy_adder_contract();
+
+    let signing_key = SigningKey::from_bytes(&SIGNER_SECRET_KEY);
+    let user = state.setup_signer(&signing_key);
+
+    // Someone other than the user submits the intent without a signature
+    state
+        .world
+        .tx()
+        .from(CALLER_ADDRESS_EXPR)
+        .to(PAYMASTER_ADDRESS_EXPR)
+        .typed(paymaster_proxy::PaymasterContractProxy)
+        .forward_execution(adder_intent(&user, 0), OptionalValue::None::<Signature>)
+        .with_result(ExpectError(4, "Missing intent signature"))
+        .run();
+}
+
+#[test]
+fn test_forward_signed_execution_expired_intent() {
+    let mut state = PaymasterTestState::new();
+    state.deploy_paymaster_contract();
+    state.deploy_adder_contract();
+
+    let signing_key = SigningKey::from_bytes(&SIGNER_SECRET_KEY);
+    let user = state.setup_signer(&signing_key);
+
+    let intent = adder_intent(&user, 0);
+    let signature = sign_intent(&signing_key, &intent);
+
+    state
+        .world
+        .current_block()
+        .block_timestamp(INTENT_DEADLINE + 1);
+
+    state
+        .world
+        .tx()
+        .from(&user)
+        .to(PAYMASTER_ADDRESS_EXPR)
+        .typed(paymaster_proxy::PaymasterContractProxy)
+        .forward_execution(intent, OptionalValue::Some(signature))
+        .esdt(TestEsdtTransfer(FEE_TOKEN_ID_EXPR, 0, FEE_AMOUNT))
+        .with_result(ExpectError(4, "Intent expired"))
+        .run();
+}
+
+#[test]
+fn test_forward_relayed_signed_execution() {
+    let mut state = PaymasterTestState::new();
+    state.deploy_paymaster_contract();
+    state.deploy_adder_contract();
+
+    let signing_key = SigningKey::from_bytes(&SIGNER_SECRET_KEY);
+    let user = state.setup_signer(&signing_key);
+
+    // The user deposits enough for one intent and a half
+    state
+        .world
+        .tx()
+        .from(&user)
+        .to(PAYMASTER_ADDRESS_EXPR)
+        .typed(paymaster_proxy::PaymasterContractProxy)
+        .deposit()
+        .esdt(TestEsdtTransfer(
+            FEE_TOKEN_ID_EXPR,
+            0,
+            FEE_AMOUNT + FEE_AMOUNT / 2,
+        ))
+        .run();
+
+    let intent = adder_intent(&user, 0);
+    let signature = sign_intent(&signing_key, &intent);
+
+    // The relayer may not pay with its own tokens
+    state
+        .world
+        .tx()
+        .from(CALLER_ADDRESS_EXPR)
+        .to(PAYMASTER_ADDRESS_EXPR)
+        .typed(paymaster_proxy::PaymasterContractProxy)
+        .forward_execution(intent.clone(), OptionalValue::Some(signature.clone()))
+        .esdt(TestEsdtTransfer(FEE_TOKEN_ID_EXPR, 0, FEE_AMOUNT))
+        .with_result(ExpectError(
+            4,
+            "Relayed intents are paid from the user deposit",
+        ))
+        .run();
+
+    state
+        .world
+        .tx()
+        .from(CALLER_ADDRESS_EXPR)
+        .to(PAYMASTER_ADDRESS_EXPR)
+        .typed(paymaster_proxy::PaymasterContractProxy)
+        .forward_execution(intent, OptionalValue::Some(signature))
+        .run();
+
+    state
+        .world
+        .query()
+        .to(CALLEE_SC_ADDER_ADDRESS_EXPR)
+        .typed(adder_proxy::AdderProxy)
+        .sum()
+        .with_result(ExpectValue(INITIAL_ADD_VALUE + ADDITIONAL_ADD_VALUE))
+        .run();
+
+    // The fee comes out of the user deposit, not out of the caller's wallet
+    state.check_esdt_balance(RELAYER_ADDRESS_EXPR, FEE_TOKEN_ID_EXPR, FEE_AMOUNT);
+    state.check_esdt_balance(CALLER_ADDRESS_EXPR, FEE_TOKEN_ID_EXPR, BALANCE);
+    state
+        .world
+        .query()
+        .to(PAYMASTER_ADDRESS_EXPR)
+        .typed(paymaster_proxy::PaymasterContractProxy)
+        .user_deposit(&user, FEE_TOKEN_ID_EXPR, 0u64)
+        .with_result(ExpectValue(FEE_AMOUNT / 2))
+        .run();
+
+    // The rest of the deposit does not cover the next intent
+    let intent = adder_intent(&user, 1);
+    let signature = sign_intent(&signing_key, &intent);
+    state
+        .world
+        .tx()
+        .from(CALLER_ADDRESS_EXPR)
+        .to(PAYMASTER_ADDRESS_EXPR)
+        .typed(paymaster_proxy::PaymasterContractProxy)
+        .forward_execution(intent, OptionalValue::Some(signature))
+        .with_result(ExpectError(4, "Not enough tokens deposited"))
+        .run();
+
+    state
+        .world
+        .tx()
+        .from(&user)
+        .to(PAYMASTER_ADDRESS_EXPR)
+        .typed(paymaster_proxy::PaymasterContractProxy)
+        .withdraw(FEE_TOKEN_ID_EXPR, 0u64, FEE_AMOUNT / 2)
+        .run();
+    state
+        .world
+        .check_account(&user)
+        .esdt_balance(FEE_TOKEN_ID_EXPR, BALANCE - FEE_AMOUNT);
+}
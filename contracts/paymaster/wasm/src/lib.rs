@@ -6,9 +6,10 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                            1
-// Async Callback:                       1
-// Total number of exported functions:   4
+// Endpoints:                            5
+// Async Callback (empty):               1
+// Promise callbacks:                    1
+// Total number of exported functions:   9
 
 #![no_std]
 
@@ -21,7 +22,12 @@ multiversx_sc_wasm_adapter::endpoints! {
         init => init
         upgrade => upgrade
         forwardExecution => forward_execution
+        getUserNonce => user_nonce
+        deposit => deposit
+        withdraw => withdraw
+        getUserDeposit => user_deposit
+        transfer_callback => transfer_callback
     )
 }
 
-multiversx_sc_wasm_adapter::async_callback! { paymaster }
+multiversx_sc_wasm_adapter::async_callback_empty! {}
@@ -343,13 +343,23 @@ pub trait PriceAggregator:
             .into()
     }
 
+    /// Same as `latestPriceFeed`, but returns nothing instead of failing
+    /// when the contract is paused or the token pair has no price
     #[view(latestPriceFeedOptional)]
     fn latest_price_feed_optional(
         &self,
         from: ManagedBuffer,
         to: ManagedBuffer,
     ) -> OptionalValue<MultiValue6<u32, ManagedBuffer, ManagedBuffer, u64, BigUint, u8>> {
-        Some(self.latest_price_feed(from, to)).into()
+        let token_pair = TokenPair {
+            from: from.clone(),
+            to: to.clone(),
+        };
+        if !self.not_paused() || !self.rounds().contains_key(&token_pair) {
+            return OptionalValue::None;
+        }
+
+        OptionalValue::Some(self.latest_price_feed(from, to))
     }
 
     #[only_owner]
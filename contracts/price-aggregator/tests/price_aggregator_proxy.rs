@@ -191,6 +191,8 @@ where
             .original_result()
     }
 
+    /// Same as `latestPriceFeed`, but returns nothing instead of failing 
+    /// when the contract is paused or the token pair has no price 
     pub fn latest_price_feed_optional<
         Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
         Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
@@ -27,6 +27,26 @@ impl<M: ManagedTypeApi> OngoingUpgradeOperation<M> {
     }
 }
 
+/// Upgrades the contracts of a template to one of its versions, one contract per call.
+/// After `canary_remaining` contracts, the rollout waits for the owner's approval.
+#[type_abi]
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode)]
+pub struct UpgradeRollout<M: ManagedTypeApi> {
+    pub version: usize,
+    pub arguments: ManagedArgBuffer<M>,
+    pub contracts_remaining: ManagedVec<M, ManagedAddress<M>>,
+    pub canary_remaining: usize,
+    pub awaiting_approval: bool,
+}
+
+/// Version 0 of a template is the template address itself, the versions added afterwards are numbered from 1.
+#[type_abi]
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode)]
+pub struct TemplateVersion<M: ManagedTypeApi> {
+    pub name: ManagedBuffer<M>,
+    pub source_address: ManagedAddress<M>,
+}
+
 #[multiversx_sc::module]
 pub trait ConfigModule {
     #[only_owner]
@@ -84,6 +104,58 @@ pub trait ConfigModule {
         self.templates_list().swap_remove(&template_address);
     }
 
+    /// Registers a new version of a template, deployed from the given source contract.
+    /// New contracts are deployed from the latest version, and upgraded to it by their deployers.
+    #[only_owner]
+    #[endpoint(addTemplateVersion)]
+    fn add_template_version(
+        &self,
+        template_address: ManagedAddress,
+        version_name: ManagedBuffer,
+        source_address: ManagedAddress,
+    ) -> usize {
+        require!(
+            self.templates_list().contains(&template_address),
+            "Template address not found"
+        );
+        require!(
+            self.blockchain().is_smart_contract(&source_address),
+            "Source address must be a SC"
+        );
+        require!(!version_name.is_empty(), "Version name cannot be empty");
+
+        let mut template_versions = self.template_versions(&template_address);
+        for template_version in template_versions.iter() {
+            require!(
+                template_version.name != version_name,
+                "Version name already used for this template"
+            );
+        }
+
+        template_versions.push(&TemplateVersion {
+            name: version_name,
+            source_address,
+        })
+    }
+
+    fn get_latest_template_version(&self, template_address: &ManagedAddress) -> usize {
+        self.template_versions(template_address).len()
+    }
+
+    fn get_template_version_source(
+        &self,
+        template_address: &ManagedAddress,
+        version: usize,
+    ) -> ManagedAddress {
+        if version == 0 {
+            return template_address.clone();
+        }
+
+        self.template_versions(template_address)
+            .get(version)
+            .source_address
+    }
+
     #[view(getDeployerContractsByTemplate)]
     fn get_deployer_contracts_by_template(
         &self,
@@ -120,10 +192,36 @@ pub trait ConfigModule {
         contract_address: &ManagedAddress,
     ) -> SingleValueMapper<ManagedAddress>;
 
+    #[view(getTemplateVersions)]
+    #[storage_mapper("templateVersions")]
+    fn template_versions(
+        &self,
+        template_address: &ManagedAddress,
+    ) -> VecMapper<TemplateVersion<Self::Api>>;
+
+    #[view(getContractVersion)]
+    #[storage_mapper("contractVersion")]
+    fn contract_version(&self, contract_address: &ManagedAddress) -> SingleValueMapper<usize>;
+
+    /// The versions a contract ran before its current one, the most recent last, used for rollbacks.
+    #[view(getContractVersionHistory)]
+    #[storage_mapper("contractVersionHistory")]
+    fn contract_version_history(&self, contract_address: &ManagedAddress) -> VecMapper<usize>;
+
+    #[storage_mapper("upgradeInProgress")]
+    fn upgrade_in_progress(&self, contract_address: &ManagedAddress) -> SingleValueMapper<bool>;
+
     #[view(getOngoingUpgradeOperations)]
     #[storage_mapper("ongoingUpgradeOperation")]
     fn ongoing_upgrade_operation(&self) -> SingleValueMapper<OngoingUpgradeOperation<Self::Api>>;
 
+    #[view(getUpgradeRollout)]
+    #[storage_mapper("upgradeRollout")]
+    fn upgrade_rollout(
+        &self,
+        template_address: &ManagedAddress,
+    ) -> SingleValueMapper<UpgradeRollout<Self::Api>>;
+
     #[view(getDefaultGasForSaveOperation)]
     #[storage_mapper("defaultGasForSaveOperation")]
     fn default_gas_for_save_operation(&self) -> SingleValueMapper<u64>;
@@ -146,6 +244,11 @@ pub trait ConfigModule {
         deployer_address: &ManagedAddress,
     ) -> MapMapper<ManagedAddress, ManagedVec<ManagedAddress>>;
 
+    /// Mirrors the nonce of this contract, which increases with every contract it deploys.
+    #[view(getDeployNonce)]
+    #[storage_mapper("deployNonce")]
+    fn deploy_nonce(&self) -> SingleValueMapper<u64>;
+
     #[view(getAllBlacklistedDeployers)]
     #[storage_mapper("blacklistedDeployersList")]
     fn blacklisted_deployers_list(&self) -> UnorderedSetMapper<ManagedAddress>;
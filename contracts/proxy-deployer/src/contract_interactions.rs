@@ -6,10 +6,18 @@ use multiversx_sc_modules::pause;
 
 use crate::config::{self, OngoingUpgradeOperation};
 
+const SC_ADDRESS_NUM_LEADING_ZEROS: usize = 8;
+const WASM_VM_TYPE: [u8; 2] = [5, 0];
+const VM_TYPE_END: usize = SC_ADDRESS_NUM_LEADING_ZEROS + WASM_VM_TYPE.len();
+const SHARD_ID_START: usize = 30;
+use crate::deploy_limits;
+
 #[multiversx_sc::module]
 pub trait ContractInteractionsModule:
-    config::ConfigModule + events::EventsModule + pause::PauseModule
+    config::ConfigModule + deploy_limits::DeployLimitsModule + events::EventsModule + pause::PauseModule
 {
+    /// Pays the deploy fee of the template, if it has one.
+    #[payable]
     #[endpoint(contractDeploy)]
     fn contract_deploy(
         &self,
@@ -34,17 +42,29 @@ pub trait ContractInteractionsModule:
                 "There is an ongoing upgrade operation for this template address"
             );
         };
+        require!(
+            self.upgrade_rollout(&template_address).is_empty(),
+            "There is an upgrade rollout in progress for this template address"
+        );
+        self.check_deploy_limits_and_collect_fee(
+            &self.blockchain().get_caller(),
+            &template_address,
+        );
 
+        let latest_version = self.get_latest_template_version(&template_address);
+        let source_address = self.get_template_version_source(&template_address, latest_version);
         let gas_left = self.blockchain().get_gas_left();
         let new_contract_address = self
             .tx()
             .raw_deploy()
             .arguments_raw(args.to_arg_buffer())
             .gas(gas_left)
-            .from_source(template_address.clone())
-            .code_metadata(self.blockchain().get_code_metadata(&template_address))
+            .from_source(source_address.clone())
+            .code_metadata(self.blockchain().get_code_metadata(&source_address))
             .returns(ReturnsNewManagedAddress)
             .sync_call();
+        self.deploy_nonce()
+            .update(|deploy_nonce| *deploy_nonce += 1);
 
         let caller = self.blockchain().get_caller();
         let owner = self.blockchain().get_owner_address();
@@ -58,6 +78,8 @@ pub trait ContractInteractionsModule:
             });
         self.contract_template(&new_contract_address)
             .set(&template_address);
+        self.contract_version(&new_contract_address)
+            .set(latest_version);
         let mut deployed_addresses = match self
             .deployer_template_addresses(&caller)
             .get(&template_address)
@@ -80,6 +102,14 @@ pub trait ContractInteractionsModule:
         new_contract_address
     }
 
+    /// Returns the address of the next contract deployed through `contractDeploy`.
+    /// Addresses derive from the address and the nonce of this contract only,
+    /// so any other deploy in between moves the address to the one after it.
+    #[view(predictDeployAddress)]
+    fn predict_deploy_address(&self) -> ManagedAddress {
+        self.compute_deploy_address(self.deploy_nonce().get())
+    }
+
     #[endpoint(contractUpgrade)]
     fn contract_upgrade(
         &self,
@@ -95,21 +125,14 @@ pub trait ContractInteractionsModule:
         require!(!contract_template_mapper.is_empty(), "No template found");
         let template_address = contract_template_mapper.get();
 
-        self.tx()
-            .to(contract_address.clone())
-            .egld(BigUint::zero())
-            .gas(self.blockchain().get_gas_left())
-            .raw_upgrade()
-            .from_source(template_address.clone())
-            .code_metadata(self.blockchain().get_code_metadata(&contract_address))
-            .arguments_raw(args.to_arg_buffer())
-            .upgrade_async_call_and_exit();
-
-        self.emit_upgrade_contract_event(
-            self.blockchain().get_caller(),
-            template_address,
+        let latest_version = self.get_latest_template_version(&template_address);
+        self.upgrade_to_version(
             contract_address,
-            args.into_vec_of_buffers(),
+            template_address,
+            latest_version,
+            args.to_arg_buffer(),
+            self.blockchain().get_gas_left(),
+            false,
         );
     }
 
@@ -173,6 +196,8 @@ pub trait ContractInteractionsModule:
 
         let mut contract_processed = false;
         let template_address = contract_template_mapper.take();
+        self.contract_version(&contract_address).clear();
+        self.contract_version_history(&contract_address).clear();
         let deployer_template_addresses_mapper = self.deployer_template_addresses(&caller);
         let mut deployer_template_addresses =
             match deployer_template_addresses_mapper.get(&template_address) {
@@ -233,15 +258,16 @@ pub trait ContractInteractionsModule:
             let contract_address = ongoing_upgrade_operation.contracts_remaining.get(0).clone();
             // If the contract_template storage is empty, it means the contracts ownership was transfered
             if !self.contract_template(&contract_address).is_empty() {
-                self.tx()
-                    .to(contract_address.clone())
-                    .egld(BigUint::zero())
-                    .gas(gas_per_action)
-                    .raw_upgrade()
-                    .from_source(ongoing_upgrade_operation.template_address.clone())
-                    .code_metadata(self.blockchain().get_code_metadata(&contract_address))
-                    .arguments_raw(ongoing_upgrade_operation.arguments.clone())
-                    .upgrade_async_call_and_exit();
+                let template_address = ongoing_upgrade_operation.template_address.clone();
+                let latest_version = self.get_latest_template_version(&template_address);
+                self.upgrade_to_version(
+                    contract_address.clone(),
+                    template_address,
+                    latest_version,
+                    ongoing_upgrade_operation.arguments.clone(),
+                    gas_per_action,
+                    false,
+                );
                 ongoing_upgrade_operation
                     .processed_contracts
                     .push(contract_address);
@@ -307,6 +333,103 @@ pub trait ContractInteractionsModule:
         )
     }
 
+    /// Upgrades the contract from the source of the given version.
+    /// The upgrade is an async call, nothing runs after it.
+    /// The callback records the new version once the upgrade succeeded.
+    fn upgrade_to_version(
+        &self,
+        contract_address: ManagedAddress,
+        template_address: ManagedAddress,
+        version: usize,
+        arguments: ManagedArgBuffer<Self::Api>,
+        gas: u64,
+        is_rollback: bool,
+    ) {
+        let upgrade_in_progress_mapper = self.upgrade_in_progress(&contract_address);
+        require!(
+            !upgrade_in_progress_mapper.get(),
+            "There is an upgrade in progress for this contract"
+        );
+        upgrade_in_progress_mapper.set(true);
+
+        let source_address = self.get_template_version_source(&template_address, version);
+        let code_metadata = self.blockchain().get_code_metadata(&contract_address);
+        let caller = self.blockchain().get_caller();
+        let callback = self.callbacks().upgrade_to_version_callback(
+            contract_address.clone(),
+            template_address,
+            version,
+            is_rollback,
+            caller,
+            arguments.clone().into_vec_of_buffers(),
+        );
+        // The upgrade call does not take a callback, so the closure is saved the way `async_call_and_exit` does it
+        callback.save_to_storage::<Self::Api>();
+        self.tx()
+            .to(contract_address)
+            .egld(BigUint::zero())
+            .gas(gas)
+            .raw_upgrade()
+            .from_source(source_address)
+            .code_metadata(code_metadata)
+            .arguments_raw(arguments)
+            .upgrade_async_call_and_exit();
+    }
+
+    /// On success, the contract runs the new version. A regular upgrade keeps the version it replaced
+    /// in the history, a rollback drops the version it went back to from the history.
+    #[callback]
+    fn upgrade_to_version_callback(
+        &self,
+        #[call_result] result: ManagedAsyncCallResult<IgnoreValue>,
+        contract_address: ManagedAddress,
+        template_address: ManagedAddress,
+        version: usize,
+        is_rollback: bool,
+        caller: ManagedAddress,
+        arguments: ManagedVec<ManagedBuffer>,
+    ) {
+        self.upgrade_in_progress(&contract_address).clear();
+        // If the contract_template storage is empty, it means the contracts ownership was transfered
+        if result.is_err() || self.contract_template(&contract_address).is_empty() {
+            return;
+        }
+
+        let version_mapper = self.contract_version(&contract_address);
+        let mut version_history_mapper = self.contract_version_history(&contract_address);
+        if is_rollback {
+            version_history_mapper.swap_remove(version_history_mapper.len());
+        } else {
+            version_history_mapper.push(&version_mapper.get());
+        }
+        version_mapper.set(version);
+
+        self.emit_upgrade_contract_event(
+            caller,
+            template_address,
+            contract_address,
+            arguments,
+            version,
+        );
+    }
+
+    /// Same scheme as the protocol: the keccak256 hash of the creator address and its nonce,
+    /// with the VM type as prefix and the shard identifier of the creator as suffix.
+    fn compute_deploy_address(&self, creator_nonce: u64) -> ManagedAddress {
+        let sc_address = self.blockchain().get_sc_address();
+        let mut hash_input = sc_address.as_managed_buffer().clone();
+        hash_input.append_bytes(&creator_nonce.to_le_bytes());
+        let hash = self.crypto().keccak256(hash_input).to_byte_array();
+        let sc_address_bytes = sc_address.to_byte_array();
+
+        let mut address_bytes = [0u8; 32];
+        address_bytes[SC_ADDRESS_NUM_LEADING_ZEROS..VM_TYPE_END].copy_from_slice(&WASM_VM_TYPE);
+        address_bytes[VM_TYPE_END..SHARD_ID_START]
+            .copy_from_slice(&hash[VM_TYPE_END..SHARD_ID_START]);
+        address_bytes[SHARD_ID_START..].copy_from_slice(&sc_address_bytes[SHARD_ID_START..]);
+        ManagedAddress::from(&address_bytes)
+    }
+
     fn can_call_endpoint(&self, opt_contract_address: Option<ManagedAddress>) {
         let caller = self.blockchain().get_caller();
         let owner = self.blockchain().get_owner_address();
@@ -0,0 +1,184 @@
+use multiversx_sc::derive_imports::*;
+use multiversx_sc::imports::*;
+
+use crate::config;
+
+#[type_abi]
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode)]
+pub struct DeployFee<M: ManagedTypeApi> {
+    pub token_id: EgldOrEsdtTokenIdentifier<M>,
+    pub amount: BigUint<M>,
+}
+
+#[multiversx_sc::module]
+pub trait DeployLimitsModule: config::ConfigModule {
+    /// Deployers pay this fee with every deploy of the template, in a single payment.
+    #[only_owner]
+    #[endpoint(setDeployFee)]
+    fn set_deploy_fee(
+        &self,
+        template_address: ManagedAddress,
+        token_id: EgldOrEsdtTokenIdentifier,
+        amount: BigUint,
+    ) {
+        require!(
+            self.templates_list().contains(&template_address),
+            "Template address not found"
+        );
+        require!(token_id.is_valid(), "Invalid token identifier");
+        require!(amount > 0, "Fee amount cannot be zero");
+
+        self.deploy_fee(&template_address)
+            .set(DeployFee { token_id, amount });
+    }
+
+    #[only_owner]
+    #[endpoint(removeDeployFee)]
+    fn remove_deploy_fee(&self, template_address: ManagedAddress) {
+        self.deploy_fee(&template_address).clear();
+    }
+
+    /// Caps the number of contracts a deployer can deploy from the template. Zero means no cap.
+    /// Contracts transferred to another owner still count for their deployer.
+    #[only_owner]
+    #[endpoint(setDeployQuota)]
+    fn set_deploy_quota(
+        &self,
+        template_address: ManagedAddress,
+        max_contracts_per_deployer: usize,
+    ) {
+        require!(
+            self.templates_list().contains(&template_address),
+            "Template address not found"
+        );
+
+        self.deploy_quota(&template_address)
+            .set(max_contracts_per_deployer);
+    }
+
+    /// In whitelist-only mode, only whitelisted addresses can deploy contracts.
+    #[only_owner]
+    #[endpoint(setWhitelistOnly)]
+    fn set_whitelist_only(&self, whitelist_only: bool) {
+        self.whitelist_only().set(whitelist_only);
+    }
+
+    #[only_owner]
+    #[endpoint(addDeployerToWhitelist)]
+    fn add_deployer_to_whitelist(&self, address: ManagedAddress) {
+        require!(
+            self.whitelisted_deployers_list().insert(address),
+            "Address already whitelisted"
+        );
+    }
+
+    #[only_owner]
+    #[endpoint(removeDeployerFromWhitelist)]
+    fn remove_deployer_from_whitelist(&self, address: ManagedAddress) {
+        require!(
+            self.whitelisted_deployers_list().swap_remove(&address),
+            "Address is not whitelisted"
+        );
+    }
+
+    /// Sends all the collected deploy fees to the owner.
+    #[only_owner]
+    #[endpoint(withdrawFees)]
+    fn withdraw_fees(&self) {
+        let owner = self.blockchain().get_caller();
+        for token_id in self.fee_tokens().iter() {
+            let amount = self.collected_fees(&token_id).take();
+            if amount > 0 {
+                self.tx()
+                    .to(&owner)
+                    .egld_or_single_esdt(&token_id, 0, &amount)
+                    .transfer();
+            }
+        }
+        self.fee_tokens().clear();
+    }
+
+    #[view(getCollectedFees)]
+    fn get_collected_fees(&self) -> MultiValueEncoded<EgldOrEsdtTokenPayment> {
+        let mut collected_fees = MultiValueEncoded::new();
+        for token_id in self.fee_tokens().iter() {
+            let amount = self.collected_fees(&token_id).get();
+            collected_fees.push(EgldOrEsdtTokenPayment::new(token_id, 0, amount));
+        }
+
+        collected_fees
+    }
+
+    /// Checks the whitelist and the quota of the template, then collects the deploy fee.
+    fn check_deploy_limits_and_collect_fee(
+        &self,
+        deployer: &ManagedAddress,
+        template_address: &ManagedAddress,
+    ) {
+        if self.whitelist_only().get() {
+            require!(
+                self.whitelisted_deployers_list().contains(deployer),
+                "Only whitelisted addresses can deploy"
+            );
+        }
+
+        let deploy_quota = self.deploy_quota(template_address).get();
+        let deploy_count_mapper = self.deploy_count(deployer, template_address);
+        if deploy_quota > 0 {
+            require!(
+                deploy_count_mapper.get() < deploy_quota,
+                "Deploy quota reached for this template"
+            );
+        }
+        deploy_count_mapper.update(|deploy_count| *deploy_count += 1);
+
+        let payment = self.call_value().egld_or_single_esdt();
+        let deploy_fee_mapper = self.deploy_fee(template_address);
+        if deploy_fee_mapper.is_empty() {
+            require!(payment.amount == 0, "No deploy fee for this template");
+            return;
+        }
+
+        let deploy_fee = deploy_fee_mapper.get();
+        require!(
+            payment.token_identifier == deploy_fee.token_id && payment.amount == deploy_fee.amount,
+            "Wrong deploy fee payment"
+        );
+        self.collected_fees(&payment.token_identifier)
+            .update(|collected_fees| *collected_fees += &payment.amount);
+        self.fee_tokens().insert(payment.token_identifier.clone());
+    }
+
+    #[view(getDeployFee)]
+    #[storage_mapper("deployFee")]
+    fn deploy_fee(
+        &self,
+        template_address: &ManagedAddress,
+    ) -> SingleValueMapper<DeployFee<Self::Api>>;
+
+    #[view(getDeployQuota)]
+    #[storage_mapper("deployQuota")]
+    fn deploy_quota(&self, template_address: &ManagedAddress) -> SingleValueMapper<usize>;
+
+    #[view(getDeployCount)]
+    #[storage_mapper("deployCount")]
+    fn deploy_count(
+        &self,
+        deployer: &ManagedAddress,
+        template_address: &ManagedAddress,
+    ) -> SingleValueMapper<usize>;
+
+    #[view(isWhitelistOnly)]
+    #[storage_mapper("whitelistOnly")]
+    fn whitelist_only(&self) -> SingleValueMapper<bool>;
+
+    #[view(getAllWhitelistedDeployers)]
+    #[storage_mapper("whitelistedDeployersList")]
+    fn whitelisted_deployers_list(&self) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[storage_mapper("collectedFees")]
+    fn collected_fees(&self, token_id: &EgldOrEsdtTokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("feeTokens")]
+    fn fee_tokens(&self) -> UnorderedSetMapper<EgldOrEsdtTokenIdentifier>;
+}
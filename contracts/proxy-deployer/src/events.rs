@@ -17,6 +17,7 @@ pub struct UpgradeContractEvent<M: ManagedTypeApi> {
     template: ManagedAddress<M>,
     upgraded_address: ManagedAddress<M>,
     arguments: ManagedVec<M, ManagedBuffer<M>>,
+    version: usize,
 }
 
 #[type_abi]
@@ -66,12 +67,14 @@ pub trait EventsModule {
         template: ManagedAddress,
         upgraded_address: ManagedAddress,
         arguments: ManagedVec<ManagedBuffer>,
+        version: usize,
     ) {
         let upgrade_contract_event = UpgradeContractEvent {
             caller: caller.clone(),
             template,
             upgraded_address,
             arguments,
+            version,
         };
 
         self.upgrade_contract_event(
@@ -6,13 +6,17 @@ use multiversx_sc_modules::pause;
 
 pub mod config;
 pub mod contract_interactions;
+pub mod deploy_limits;
 pub mod events;
 pub mod proxy_deployer_proxy;
+pub mod upgrade_rollout;
 
 #[multiversx_sc::contract]
 pub trait ProxyDeployer:
     contract_interactions::ContractInteractionsModule
+    + upgrade_rollout::UpgradeRolloutModule
     + config::ConfigModule
+    + deploy_limits::DeployLimitsModule
     + events::EventsModule
     + pause::PauseModule
 {
@@ -22,6 +26,12 @@ pub trait ProxyDeployer:
             .set(default_gas_for_save);
     }
 
+    /// The deploy nonce must be provided when upgrading a contract
+    /// that deployed contracts before it started tracking it.
     #[upgrade]
-    fn upgrade(&self) {}
+    fn upgrade(&self, opt_deploy_nonce: OptionalValue<u64>) {
+        if let OptionalValue::Some(deploy_nonce) = opt_deploy_nonce {
+            self.deploy_nonce().set(deploy_nonce);
+        }
+    }
 }
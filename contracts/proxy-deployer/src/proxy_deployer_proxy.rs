@@ -66,12 +66,18 @@ where
     To: TxTo<Env>,
     Gas: TxGas<Env>,
 {
-    pub fn upgrade(
+    /// The deploy nonce must be provided when upgrading a contract 
+    /// that deployed contracts before it started tracking it. 
+    pub fn upgrade<
+        Arg0: ProxyArg<OptionalValue<u64>>,
+    >(
         self,
+        opt_deploy_nonce: Arg0,
     ) -> TxTypedUpgrade<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
             .raw_upgrade()
+            .argument(&opt_deploy_nonce)
             .original_result()
     }
 }
@@ -85,6 +91,7 @@ where
     To: TxTo<Env>,
     Gas: TxGas<Env>,
 {
+    /// Pays the deploy fee of the template, if it has one. 
     pub fn contract_deploy<
         Arg0: ProxyArg<ManagedAddress<Env::Api>>,
         Arg1: ProxyArg<MultiValueEncoded<Env::Api, ManagedBuffer<Env::Api>>>,
@@ -92,15 +99,26 @@ where
         self,
         template_address: Arg0,
         args: Arg1,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+    ) -> TxTypedCall<Env, From, To, (), Gas, ManagedAddress<Env::Api>> {
         self.wrapped_tx
-            .payment(NotPayable)
             .raw_call("contractDeploy")
             .argument(&template_address)
             .argument(&args)
             .original_result()
     }
 
+    /// Returns the address of the next contract deployed through `contractDeploy`. 
+    /// Addresses derive from the address and the nonce of this contract only, 
+    /// so any other deploy in between moves the address to the one after it. 
+    pub fn predict_deploy_address(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("predictDeployAddress")
+            .original_result()
+    }
+
     pub fn contract_upgrade<
         Arg0: ProxyArg<ManagedAddress<Env::Api>>,
         Arg1: ProxyArg<MultiValueEncoded<Env::Api, ManagedBuffer<Env::Api>>>,
@@ -188,6 +206,108 @@ where
             .original_result()
     }
 
+    /// Starts upgrading the contracts of a template that do not run the given version yet. 
+    /// Each `continueUpgradeRollout` call upgrades one contract, so the rollout can be resumed at any time. 
+    /// With a non-zero canary count, the rollout stops after that many contracts, until it is approved. 
+    pub fn start_upgrade_rollout<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<usize>,
+        Arg2: ProxyArg<usize>,
+        Arg3: ProxyArg<MultiValueEncoded<Env::Api, ManagedBuffer<Env::Api>>>,
+    >(
+        self,
+        template_address: Arg0,
+        version: Arg1,
+        canary_count: Arg2,
+        args: Arg3,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("startUpgradeRollout")
+            .argument(&template_address)
+            .argument(&version)
+            .argument(&canary_count)
+            .argument(&args)
+            .original_result()
+    }
+
+    /// Upgrades the next contract of the rollout. 
+    /// Contracts whose ownership was transferred in the meantime are skipped. 
+    pub fn continue_upgrade_rollout<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        template_address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("continueUpgradeRollout")
+            .argument(&template_address)
+            .original_result()
+    }
+
+    /// Lets the rollout continue past the canary contracts. 
+    pub fn approve_upgrade_rollout<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        template_address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("approveUpgradeRollout")
+            .argument(&template_address)
+            .original_result()
+    }
+
+    /// Stops the rollout. The contracts upgraded so far keep the new version, 
+    /// they can be reverted one by one with `rollbackContractUpgrade`. 
+    pub fn cancel_upgrade_rollout<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        template_address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("cancelUpgradeRollout")
+            .argument(&template_address)
+            .original_result()
+    }
+
+    /// Upgrades a contract back to the version it ran before its last upgrade. 
+    /// Each rollback goes one version further back in the history of the contract. 
+    pub fn rollback_contract_upgrade<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<MultiValueEncoded<Env::Api, ManagedBuffer<Env::Api>>>,
+    >(
+        self,
+        contract_address: Arg0,
+        args: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("rollbackContractUpgrade")
+            .argument(&contract_address)
+            .argument(&args)
+            .original_result()
+    }
+
+    /// Number of deployed contracts running each version of the template. 
+    /// Versions that no contract runs are left out. 
+    pub fn get_version_distribution<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        template_address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<usize, usize>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getVersionDistribution")
+            .argument(&template_address)
+            .original_result()
+    }
+
     pub fn add_deployer_to_blacklist<
         Arg0: ProxyArg<ManagedAddress<Env::Api>>,
     >(
@@ -253,6 +373,27 @@ where
             .original_result()
     }
 
+    /// Registers a new version of a template, deployed from the given source contract. 
+    /// New contracts are deployed from the latest version, and upgraded to it by their deployers. 
+    pub fn add_template_version<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        template_address: Arg0,
+        version_name: Arg1,
+        source_address: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("addTemplateVersion")
+            .argument(&template_address)
+            .argument(&version_name)
+            .argument(&source_address)
+            .original_result()
+    }
+
     pub fn get_deployer_contracts_by_template<
         Arg0: ProxyArg<ManagedAddress<Env::Api>>,
         Arg1: ProxyArg<ManagedAddress<Env::Api>>,
@@ -308,6 +449,46 @@ where
             .original_result()
     }
 
+    pub fn template_versions<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        template_address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, TemplateVersion<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTemplateVersions")
+            .argument(&template_address)
+            .original_result()
+    }
+
+    pub fn contract_version<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        contract_address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getContractVersion")
+            .argument(&contract_address)
+            .original_result()
+    }
+
+    /// The versions a contract ran before its current one, the most recent last, used for rollbacks. 
+    pub fn contract_version_history<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        contract_address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, usize>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getContractVersionHistory")
+            .argument(&contract_address)
+            .original_result()
+    }
+
     pub fn ongoing_upgrade_operation(
         self,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OngoingUpgradeOperation<Env::Api>> {
@@ -317,6 +498,19 @@ where
             .original_result()
     }
 
+    pub fn upgrade_rollout<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        template_address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, UpgradeRollout<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getUpgradeRollout")
+            .argument(&template_address)
+            .original_result()
+    }
+
     pub fn default_gas_for_save_operation(
         self,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
@@ -344,6 +538,16 @@ where
             .original_result()
     }
 
+    /// Mirrors the nonce of this contract, which increases with every contract it deploys. 
+    pub fn deploy_nonce(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getDeployNonce")
+            .original_result()
+    }
+
     pub fn blacklisted_deployers_list(
         self,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
@@ -353,6 +557,176 @@ where
             .original_result()
     }
 
+    /// Deployers pay this fee with every deploy of the template, in a single payment. 
+    pub fn set_deploy_fee<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+        Arg2: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        template_address: Arg0,
+        token_id: Arg1,
+        amount: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setDeployFee")
+            .argument(&template_address)
+            .argument(&token_id)
+            .argument(&amount)
+            .original_result()
+    }
+
+    pub fn remove_deploy_fee<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        template_address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("removeDeployFee")
+            .argument(&template_address)
+            .original_result()
+    }
+
+    /// Caps the number of contracts a deployer can deploy from the template. Zero means no cap. 
+    /// Contracts transferred to another owner still count for their deployer. 
+    pub fn set_deploy_quota<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<usize>,
+    >(
+        self,
+        template_address: Arg0,
+        max_contracts_per_deployer: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setDeployQuota")
+            .argument(&template_address)
+            .argument(&max_contracts_per_deployer)
+            .original_result()
+    }
+
+    /// In whitelist-only mode, only whitelisted addresses can deploy contracts. 
+    pub fn set_whitelist_only<
+        Arg0: ProxyArg<bool>,
+    >(
+        self,
+        whitelist_only: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setWhitelistOnly")
+            .argument(&whitelist_only)
+            .original_result()
+    }
+
+    pub fn add_deployer_to_whitelist<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("addDeployerToWhitelist")
+            .argument(&address)
+            .original_result()
+    }
+
+    pub fn remove_deployer_from_whitelist<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("removeDeployerFromWhitelist")
+            .argument(&address)
+            .original_result()
+    }
+
+    /// Sends all the collected deploy fees to the owner. 
+    pub fn withdraw_fees(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("withdrawFees")
+            .original_result()
+    }
+
+    pub fn get_collected_fees(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, EgldOrEsdtTokenPayment<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getCollectedFees")
+            .original_result()
+    }
+
+    pub fn deploy_fee<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        template_address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, DeployFee<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getDeployFee")
+            .argument(&template_address)
+            .original_result()
+    }
+
+    pub fn deploy_quota<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        template_address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getDeployQuota")
+            .argument(&template_address)
+            .original_result()
+    }
+
+    pub fn deploy_count<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        deployer: Arg0,
+        template_address: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getDeployCount")
+            .argument(&deployer)
+            .argument(&template_address)
+            .original_result()
+    }
+
+    pub fn whitelist_only(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isWhitelistOnly")
+            .original_result()
+    }
+
+    pub fn whitelisted_deployers_list(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAllWhitelistedDeployers")
+            .original_result()
+    }
+
     pub fn pause_endpoint(
         self,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
@@ -381,6 +755,16 @@ where
     }
 }
 
+#[type_abi]
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode)]
+pub struct TemplateVersion<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub name: ManagedBuffer<Api>,
+    pub source_address: ManagedAddress<Api>,
+}
+
 #[type_abi]
 #[derive(NestedEncode, NestedDecode, TopEncode, TopDecode)]
 pub struct OngoingUpgradeOperation<Api>
@@ -393,6 +777,29 @@ where
     pub processed_contracts: ManagedVec<Api, ManagedAddress<Api>>,
 }
 
+#[type_abi]
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode)]
+pub struct UpgradeRollout<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub version: usize,
+    pub arguments: ManagedArgBuffer<Api>,
+    pub contracts_remaining: ManagedVec<Api, ManagedAddress<Api>>,
+    pub canary_remaining: usize,
+    pub awaiting_approval: bool,
+}
+
+#[type_abi]
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode)]
+pub struct DeployFee<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub token_id: EgldOrEsdtTokenIdentifier<Api>,
+    pub amount: BigUint<Api>,
+}
+
 #[type_abi]
 #[derive(TopEncode)]
 pub struct DeployContractEvent<Api>
@@ -415,6 +822,7 @@ where
     pub template: ManagedAddress<Api>,
     pub upgraded_address: ManagedAddress<Api>,
     pub arguments: ManagedVec<Api, ManagedBuffer<Api>>,
+    pub version: usize,
 }
 
 #[type_abi]
@@ -0,0 +1,218 @@
+use multiversx_sc::imports::*;
+
+use multiversx_sc_modules::pause;
+
+use crate::config::{self, UpgradeRollout};
+use crate::contract_interactions;
+use crate::deploy_limits;
+use crate::events;
+
+#[multiversx_sc::module]
+pub trait UpgradeRolloutModule:
+    contract_interactions::ContractInteractionsModule
+    + config::ConfigModule
+    + deploy_limits::DeployLimitsModule
+    + events::EventsModule
+    + pause::PauseModule
+{
+    /// Starts upgrading the contracts of a template that do not run the given version yet.
+    /// Each `continueUpgradeRollout` call upgrades one contract, so the rollout can be resumed at any time.
+    /// With a non-zero canary count, the rollout stops after that many contracts, until it is approved.
+    #[only_owner]
+    #[endpoint(startUpgradeRollout)]
+    fn start_upgrade_rollout(
+        &self,
+        template_address: ManagedAddress,
+        version: usize,
+        canary_count: usize,
+        args: MultiValueEncoded<ManagedBuffer>,
+    ) {
+        require!(
+            self.templates_list().contains(&template_address),
+            "Template address not found"
+        );
+        require!(
+            version <= self.get_latest_template_version(&template_address),
+            "Unknown template version"
+        );
+        let rollout_mapper = self.upgrade_rollout(&template_address);
+        require!(
+            rollout_mapper.is_empty(),
+            "There is an upgrade rollout in progress for this template address"
+        );
+        let ongoing_upgrade_operation_mapper = self.ongoing_upgrade_operation();
+        if !ongoing_upgrade_operation_mapper.is_empty() {
+            require!(
+                ongoing_upgrade_operation_mapper.get().template_address != template_address,
+                "There is an ongoing upgrade operation for this template address"
+            );
+        }
+
+        let mut contracts_remaining = ManagedVec::new();
+        for contract_address in self
+            .deployed_contracts_list_by_template(&template_address)
+            .get()
+            .iter()
+        {
+            if self.contract_version(&contract_address).get() != version {
+                contracts_remaining.push(contract_address.clone());
+            }
+        }
+        require!(
+            !contracts_remaining.is_empty(),
+            "All contracts already run this version"
+        );
+
+        rollout_mapper.set(UpgradeRollout {
+            version,
+            arguments: args.to_arg_buffer(),
+            contracts_remaining,
+            canary_remaining: canary_count,
+            awaiting_approval: false,
+        });
+    }
+
+    /// Upgrades the next contract of the rollout.
+    /// Contracts whose ownership was transferred in the meantime are skipped.
+    #[only_owner]
+    #[endpoint(continueUpgradeRollout)]
+    fn continue_upgrade_rollout(&self, template_address: ManagedAddress) {
+        let rollout_mapper = self.upgrade_rollout(&template_address);
+        require!(
+            !rollout_mapper.is_empty(),
+            "No upgrade rollout in progress for this template address"
+        );
+        let mut rollout = rollout_mapper.get();
+        require!(
+            !rollout.awaiting_approval,
+            "The canary contracts must be approved first"
+        );
+
+        let mut opt_contract_address = None;
+        while opt_contract_address.is_none() && !rollout.contracts_remaining.is_empty() {
+            let contract_address = rollout.contracts_remaining.get(0).clone();
+            rollout.contracts_remaining.remove(0);
+            // If the contract_template storage is empty, it means the contracts ownership was transfered
+            if !self.contract_template(&contract_address).is_empty() {
+                opt_contract_address = Some(contract_address);
+            }
+        }
+
+        let contract_address = match opt_contract_address {
+            Some(contract_address) => contract_address,
+            None => {
+                rollout_mapper.clear();
+                return;
+            }
+        };
+
+        if rollout.canary_remaining > 0 {
+            rollout.canary_remaining -= 1;
+            rollout.awaiting_approval = rollout.canary_remaining == 0;
+        }
+        let version = rollout.version;
+        let arguments = rollout.arguments.clone();
+        if rollout.contracts_remaining.is_empty() {
+            rollout_mapper.clear();
+        } else {
+            rollout_mapper.set(rollout);
+        }
+
+        self.upgrade_to_version(
+            contract_address,
+            template_address,
+            version,
+            arguments,
+            self.blockchain().get_gas_left(),
+            false,
+        );
+    }
+
+    /// Lets the rollout continue past the canary contracts.
+    #[only_owner]
+    #[endpoint(approveUpgradeRollout)]
+    fn approve_upgrade_rollout(&self, template_address: ManagedAddress) {
+        let rollout_mapper = self.upgrade_rollout(&template_address);
+        require!(
+            !rollout_mapper.is_empty(),
+            "No upgrade rollout in progress for this template address"
+        );
+        rollout_mapper.update(|rollout| {
+            require!(
+                rollout.awaiting_approval,
+                "The rollout is not awaiting approval"
+            );
+            rollout.awaiting_approval = false;
+        });
+    }
+
+    /// Stops the rollout. The contracts upgraded so far keep the new version,
+    /// they can be reverted one by one with `rollbackContractUpgrade`.
+    #[only_owner]
+    #[endpoint(cancelUpgradeRollout)]
+    fn cancel_upgrade_rollout(&self, template_address: ManagedAddress) {
+        let rollout_mapper = self.upgrade_rollout(&template_address);
+        require!(
+            !rollout_mapper.is_empty(),
+            "No upgrade rollout in progress for this template address"
+        );
+        rollout_mapper.clear();
+    }
+
+    /// Upgrades a contract back to the version it ran before its last upgrade.
+    /// Each rollback goes one version further back in the history of the contract.
+    #[only_owner]
+    #[endpoint(rollbackContractUpgrade)]
+    fn rollback_contract_upgrade(
+        &self,
+        contract_address: ManagedAddress,
+        args: MultiValueEncoded<ManagedBuffer>,
+    ) {
+        let contract_template_mapper = self.contract_template(&contract_address);
+        require!(!contract_template_mapper.is_empty(), "No template found");
+        let version_history_mapper = self.contract_version_history(&contract_address);
+        require!(
+            !version_history_mapper.is_empty(),
+            "No previous version for this contract"
+        );
+        let previous_version = version_history_mapper.get(version_history_mapper.len());
+
+        let template_address = contract_template_mapper.get();
+        self.upgrade_to_version(
+            contract_address,
+            template_address,
+            previous_version,
+            args.to_arg_buffer(),
+            self.blockchain().get_gas_left(),
+            true,
+        );
+    }
+
+    /// Number of deployed contracts running each version of the template.
+    /// Versions that no contract runs are left out.
+    #[view(getVersionDistribution)]
+    fn get_version_distribution(
+        &self,
+        template_address: ManagedAddress,
+    ) -> MultiValueEncoded<MultiValue2<usize, usize>> {
+        let deployed_contracts = self
+            .deployed_contracts_list_by_template(&template_address)
+            .get();
+        let latest_version = self.get_latest_template_version(&template_address);
+
+        let mut result = MultiValueEncoded::new();
+        for version in 0..=latest_version {
+            let mut contract_count = 0;
+            for contract_address in deployed_contracts.iter() {
+                if self.contract_version(&contract_address).get() == version {
+                    contract_count += 1;
+                }
+            }
+            if contract_count > 0 {
+                result.push((version, contract_count).into());
+            }
+        }
+
+        result
+    }
+}
@@ -1,7 +1,10 @@
 use imports::{MxscPath, ReturnsResult, TestAddress, TestSCAddress};
 use multiversx_sc::{
     codec::{multi_types::OptionalValue, top_encode_to_vec_u8_or_panic},
-    types::{Address, CodeMetadata, ManagedAddress, ManagedBuffer, MultiValueEncoded},
+    types::{
+        Address, BigUint, CodeMetadata, EgldOrEsdtTokenIdentifier, EgldOrEsdtTokenPayment,
+        ManagedAddress, ManagedBuffer, MultiValueEncoded,
+    },
 };
 
 use multiversx_sc_scenario::{api::StaticApi, scenario_model::*, *};
@@ -11,6 +14,7 @@ use proxy_deployer::proxy_deployer_proxy;
 
 const PROXY_DEPLOYER_ADDRESS_EXPR: TestSCAddress = TestSCAddress::new("proxy_deployer");
 const TEMPLATE_CONTRACT_ADDRESS_EXPR: TestSCAddress = TestSCAddress::new("template_contract");
+const TEMPLATE_CONTRACT_V2_ADDRESS_EXPR: TestSCAddress = TestSCAddress::new("template_contract_v2");
 const DEPLOYED_CONTRACT_ADDRESS_EXPR1: TestSCAddress = TestSCAddress::new("deployed_contract1");
 const DEPLOYED_CONTRACT_ADDRESS_EXPR2: TestSCAddress = TestSCAddress::new("deployed_contract2");
 const OWNER_ADDRESS_EXPR: TestAddress = TestAddress::new("owner");
@@ -39,7 +43,7 @@ impl ProxyDeployerTestState {
         let mut world = world();
         world.start_trace();
         world.account(OWNER_ADDRESS_EXPR).nonce(1);
-        world.account(USER_ADDRESS_EXPR).nonce(1);
+        world.account(USER_ADDRESS_EXPR).nonce(1).balance(10_000u64);
 
         Self {
             world,
@@ -104,6 +108,53 @@ impl ProxyDeployerTestState {
         self
     }
 
+    fn deploy_contract_with_fee(
+        &mut self,
+        creator_nonce: u64,
+        deployed_address: TestSCAddress,
+        fee: u64,
+    ) -> &mut Self {
+        let mut args = MultiValueEncoded::new();
+        args.push(ManagedBuffer::from(top_encode_to_vec_u8_or_panic(&1u64)));
+        self.world
+            .new_address(PROXY_DEPLOYER_ADDRESS_EXPR, creator_nonce, deployed_address);
+        self.world
+            .tx()
+            .from(USER_ADDRESS_EXPR)
+            .to(PROXY_DEPLOYER_ADDRESS_EXPR)
+            .typed(proxy_deployer_proxy::ProxyDeployerProxy)
+            .contract_deploy(TEMPLATE_CONTRACT_ADDRESS_EXPR.to_managed_address(), args)
+            .egld(fee)
+            .run();
+
+        self
+    }
+
+    fn deploy_contract_expect_error(&mut self, fee: u64, err_message: &str) {
+        let mut args = MultiValueEncoded::new();
+        args.push(ManagedBuffer::from(top_encode_to_vec_u8_or_panic(&1u64)));
+        self.world
+            .tx()
+            .from(USER_ADDRESS_EXPR)
+            .to(PROXY_DEPLOYER_ADDRESS_EXPR)
+            .typed(proxy_deployer_proxy::ProxyDeployerProxy)
+            .contract_deploy(TEMPLATE_CONTRACT_ADDRESS_EXPR.to_managed_address(), args)
+            .egld(fee)
+            .with_result(ExpectError(4, err_message))
+            .run();
+    }
+
+    fn predict_deploy_address(&mut self) -> Address {
+        self.world
+            .query()
+            .to(PROXY_DEPLOYER_ADDRESS_EXPR)
+            .typed(proxy_deployer_proxy::ProxyDeployerProxy)
+            .predict_deploy_address()
+            .returns(ReturnsResult)
+            .run()
+            .to_address()
+    }
+
     fn upgrade_contract(
         &mut self,
         user: TestAddress,
@@ -121,6 +172,75 @@ impl ProxyDeployerTestState {
         self
     }
 
+    fn add_template_version(&mut self, version_name: &str) -> &mut Self {
+        self.world
+            .new_address(OWNER_ADDRESS_EXPR, 3, TEMPLATE_CONTRACT_V2_ADDRESS_EXPR);
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS_EXPR)
+            .typed(adder_proxy::AdderProxy)
+            .init(0u64)
+            .code(DEPLOYED_CONTRACT_PATH_EXPR)
+            .new_address(TEMPLATE_CONTRACT_V2_ADDRESS_EXPR)
+            .run();
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS_EXPR)
+            .to(PROXY_DEPLOYER_ADDRESS_EXPR)
+            .typed(proxy_deployer_proxy::ProxyDeployerProxy)
+            .add_template_version(
+                TEMPLATE_CONTRACT_ADDRESS_EXPR.to_managed_address(),
+                ManagedBuffer::from(version_name),
+                TEMPLATE_CONTRACT_V2_ADDRESS_EXPR.to_managed_address(),
+            )
+            .with_result(ExpectValue(1usize))
+            .run();
+
+        self
+    }
+
+    fn continue_rollout(&mut self) -> &mut Self {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS_EXPR)
+            .to(PROXY_DEPLOYER_ADDRESS_EXPR)
+            .typed(proxy_deployer_proxy::ProxyDeployerProxy)
+            .continue_upgrade_rollout(TEMPLATE_CONTRACT_ADDRESS_EXPR.to_managed_address())
+            .run();
+
+        self
+    }
+
+    fn rollback_contract(&mut self, contract_address: &Address, value: u64) -> &mut Self {
+        let mut args = MultiValueEncoded::new();
+        args.push(ManagedBuffer::from(top_encode_to_vec_u8_or_panic(&value)));
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS_EXPR)
+            .to(PROXY_DEPLOYER_ADDRESS_EXPR)
+            .typed(proxy_deployer_proxy::ProxyDeployerProxy)
+            .rollback_contract_upgrade(contract_address, args)
+            .run();
+
+        self
+    }
+
+    fn check_version_distribution(&mut self, expected: &[(usize, usize)]) {
+        let distribution = self
+            .world
+            .query()
+            .to(PROXY_DEPLOYER_ADDRESS_EXPR)
+            .typed(proxy_deployer_proxy::ProxyDeployerProxy)
+            .get_version_distribution(TEMPLATE_CONTRACT_ADDRESS_EXPR.to_managed_address())
+            .returns(ReturnsResult)
+            .run();
+        let distribution = distribution
+            .into_iter()
+            .map(|entry| entry.into_tuple())
+            .collect::<Vec<_>>();
+        assert_eq!(distribution, expected);
+    }
+
     fn upgrade_by_template(
         &mut self,
         template_address: TestSCAddress,
@@ -302,3 +422,302 @@ fn proxy_deployer_check_metadata_test() {
     state.upgrade_contract(USER_ADDRESS_EXPR, &contract_address, upgrade_args);
     state.check_contract_storage(DEPLOYED_CONTRACT_ADDRESS_EXPR1, 5u64);
 }
+
+#[test]
+fn proxy_deployer_versioned_rollout_test() {
+    let mut state = ProxyDeployerTestState::new();
+    state.deploy_proxy_deployer_contract();
+
+    let mut deploy_args = MultiValueEncoded::new();
+    deploy_args.push(ManagedBuffer::from(top_encode_to_vec_u8_or_panic(&1u64)));
+    state.deploy_contract(
+        USER_ADDRESS_EXPR,
+        0,
+        DEPLOYED_CONTRACT_ADDRESS_EXPR1,
+        TEMPLATE_CONTRACT_ADDRESS_EXPR,
+        deploy_args.clone(),
+    );
+    state.deploy_contract(
+        USER_ADDRESS_EXPR,
+        1,
+        DEPLOYED_CONTRACT_ADDRESS_EXPR2,
+        TEMPLATE_CONTRACT_ADDRESS_EXPR,
+        deploy_args.clone(),
+    );
+    let contract_address1 = state.deployed_contracts[0].to_owned();
+
+    // Contracts deployed from the template address run version 0
+    state.add_template_version("v2");
+    state.check_version_distribution(&[(0, 2)]);
+
+    // Start a rollout to the new version, with one canary contract
+    let mut upgrade_args = MultiValueEncoded::new();
+    upgrade_args.push(ManagedBuffer::from(top_encode_to_vec_u8_or_panic(&5u64)));
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS_EXPR)
+        .to(PROXY_DEPLOYER_ADDRESS_EXPR)
+        .typed(proxy_deployer_proxy::ProxyDeployerProxy)
+        .start_upgrade_rollout(
+            TEMPLATE_CONTRACT_ADDRESS_EXPR.to_managed_address(),
+            1usize,
+            1usize,
+            upgrade_args,
+        )
+        .run();
+
+    // No deploys for the template during the rollout
+    state
+        .world
+        .tx()
+        .from(USER_ADDRESS_EXPR)
+        .to(PROXY_DEPLOYER_ADDRESS_EXPR)
+        .typed(proxy_deployer_proxy::ProxyDeployerProxy)
+        .contract_deploy(
+            TEMPLATE_CONTRACT_ADDRESS_EXPR.to_managed_address(),
+            deploy_args,
+        )
+        .with_result(ExpectError(
+            4,
+            "There is an upgrade rollout in progress for this template address",
+        ))
+        .run();
+
+    // The canary contract is upgraded, then the rollout waits for approval
+    state.continue_rollout();
+    state.check_contract_storage(DEPLOYED_CONTRACT_ADDRESS_EXPR1, 5u64);
+    state.check_version_distribution(&[(0, 1), (1, 1)]);
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS_EXPR)
+        .to(PROXY_DEPLOYER_ADDRESS_EXPR)
+        .typed(proxy_deployer_proxy::ProxyDeployerProxy)
+        .continue_upgrade_rollout(TEMPLATE_CONTRACT_ADDRESS_EXPR.to_managed_address())
+        .with_result(ExpectError(
+            4,
+            "The canary contracts must be approved first",
+        ))
+        .run();
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS_EXPR)
+        .to(PROXY_DEPLOYER_ADDRESS_EXPR)
+        .typed(proxy_deployer_proxy::ProxyDeployerProxy)
+        .approve_upgrade_rollout(TEMPLATE_CONTRACT_ADDRESS_EXPR.to_managed_address())
+        .run();
+    state.continue_rollout();
+    state.check_contract_storage(DEPLOYED_CONTRACT_ADDRESS_EXPR2, 5u64);
+    state.check_version_distribution(&[(1, 2)]);
+
+    // Roll the canary contract back to the template address
+    state.rollback_contract(&contract_address1, 7u64);
+    state.check_contract_storage(DEPLOYED_CONTRACT_ADDRESS_EXPR1, 7u64);
+    state.check_version_distribution(&[(0, 1), (1, 1)]);
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS_EXPR)
+        .to(PROXY_DEPLOYER_ADDRESS_EXPR)
+        .typed(proxy_deployer_proxy::ProxyDeployerProxy)
+        .rollback_contract_upgrade(&contract_address1, MultiValueEncoded::new())
+        .with_result(ExpectError(4, "No previous version for this contract"))
+        .run();
+
+    // A failed upgrade leaves the version of the contract unchanged
+    state.upgrade_contract(
+        USER_ADDRESS_EXPR,
+        &contract_address1,
+        MultiValueEncoded::new(),
+    );
+    state.check_contract_storage(DEPLOYED_CONTRACT_ADDRESS_EXPR1, 7u64);
+    state.check_version_distribution(&[(0, 1), (1, 1)]);
+
+    // Each rollback goes one version further back
+    let contract_address2 = state.deployed_contracts[1].to_owned();
+    let mut upgrade_args = MultiValueEncoded::new();
+    upgrade_args.push(ManagedBuffer::from(top_encode_to_vec_u8_or_panic(&9u64)));
+    state.upgrade_contract(USER_ADDRESS_EXPR, &contract_address2, upgrade_args);
+    state.check_contract_storage(DEPLOYED_CONTRACT_ADDRESS_EXPR2, 9u64);
+    let version_history = state
+        .world
+        .query()
+        .to(PROXY_DEPLOYER_ADDRESS_EXPR)
+        .typed(proxy_deployer_proxy::ProxyDeployerProxy)
+        .contract_version_history(&contract_address2)
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(version_history.into_iter().collect::<Vec<_>>(), [0, 1]);
+
+    state.rollback_contract(&contract_address2, 11u64);
+    state.check_contract_storage(DEPLOYED_CONTRACT_ADDRESS_EXPR2, 11u64);
+    state.check_version_distribution(&[(0, 1), (1, 1)]);
+    state.rollback_contract(&contract_address2, 13u64);
+    state.check_contract_storage(DEPLOYED_CONTRACT_ADDRESS_EXPR2, 13u64);
+    state.check_version_distribution(&[(0, 2)]);
+}
+
+#[test]
+fn proxy_deployer_fees_and_quotas_test() {
+    let mut state = ProxyDeployerTestState::new();
+    state.deploy_proxy_deployer_contract();
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS_EXPR)
+        .to(PROXY_DEPLOYER_ADDRESS_EXPR)
+        .typed(proxy_deployer_proxy::ProxyDeployerProxy)
+        .set_deploy_fee(
+            TEMPLATE_CONTRACT_ADDRESS_EXPR.to_managed_address(),
+            EgldOrEsdtTokenIdentifier::egld(),
+            BigUint::from(1_000u64),
+        )
+        .run();
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS_EXPR)
+        .to(PROXY_DEPLOYER_ADDRESS_EXPR)
+        .typed(proxy_deployer_proxy::ProxyDeployerProxy)
+        .set_deploy_quota(TEMPLATE_CONTRACT_ADDRESS_EXPR.to_managed_address(), 1usize)
+        .run();
+
+    // The fee must be paid exactly, and the quota is one contract per deployer
+    state.deploy_contract_expect_error(0, "Wrong deploy fee payment");
+    state.deploy_contract_expect_error(500, "Wrong deploy fee payment");
+    state.deploy_contract_with_fee(0, DEPLOYED_CONTRACT_ADDRESS_EXPR1, 1_000);
+    state.check_contract_storage(DEPLOYED_CONTRACT_ADDRESS_EXPR1, 1u64);
+    state.deploy_contract_expect_error(1_000, "Deploy quota reached for this template");
+
+    // Transferring the contract away does not free the quota
+    state
+        .world
+        .tx()
+        .from(USER_ADDRESS_EXPR)
+        .to(PROXY_DEPLOYER_ADDRESS_EXPR)
+        .typed(proxy_deployer_proxy::ProxyDeployerProxy)
+        .change_owner(
+            DEPLOYED_CONTRACT_ADDRESS_EXPR1.to_managed_address(),
+            OWNER_ADDRESS_EXPR.to_managed_address(),
+            OptionalValue::<ManagedAddress<StaticApi>>::None,
+        )
+        .run();
+    state.deploy_contract_expect_error(1_000, "Deploy quota reached for this template");
+
+    let collected_fees = state
+        .world
+        .query()
+        .to(PROXY_DEPLOYER_ADDRESS_EXPR)
+        .typed(proxy_deployer_proxy::ProxyDeployerProxy)
+        .get_collected_fees()
+        .returns(ReturnsResult)
+        .run()
+        .into_iter()
+        .collect::<Vec<_>>();
+    assert_eq!(
+        collected_fees,
+        vec![EgldOrEsdtTokenPayment::new(
+            EgldOrEsdtTokenIdentifier::egld(),
+            0,
+            BigUint::from(1_000u64)
+        )]
+    );
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS_EXPR)
+        .to(PROXY_DEPLOYER_ADDRESS_EXPR)
+        .typed(proxy_deployer_proxy::ProxyDeployerProxy)
+        .withdraw_fees()
+        .run();
+    state
+        .world
+        .check_account(OWNER_ADDRESS_EXPR)
+        .balance(1_000u64);
+    state
+        .world
+        .check_account(USER_ADDRESS_EXPR)
+        .balance(9_000u64);
+
+    // Whitelist-only mode
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS_EXPR)
+        .to(PROXY_DEPLOYER_ADDRESS_EXPR)
+        .typed(proxy_deployer_proxy::ProxyDeployerProxy)
+        .set_deploy_quota(TEMPLATE_CONTRACT_ADDRESS_EXPR.to_managed_address(), 0usize)
+        .run();
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS_EXPR)
+        .to(PROXY_DEPLOYER_ADDRESS_EXPR)
+        .typed(proxy_deployer_proxy::ProxyDeployerProxy)
+        .set_whitelist_only(true)
+        .run();
+    state.deploy_contract_expect_error(1_000, "Only whitelisted addresses can deploy");
+
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS_EXPR)
+        .to(PROXY_DEPLOYER_ADDRESS_EXPR)
+        .typed(proxy_deployer_proxy::ProxyDeployerProxy)
+        .add_deployer_to_whitelist(USER_ADDRESS_EXPR.to_managed_address())
+        .run();
+    state.deploy_contract_with_fee(1, DEPLOYED_CONTRACT_ADDRESS_EXPR2, 1_000);
+    state.check_contract_storage(DEPLOYED_CONTRACT_ADDRESS_EXPR2, 1u64);
+}
+
+#[test]
+fn proxy_deployer_predict_deploy_address_test() {
+    let mut state = ProxyDeployerTestState::new();
+    state.deploy_proxy_deployer_contract();
+
+    let predicted_address = state.predict_deploy_address();
+
+    let mut deploy_args = MultiValueEncoded::new();
+    deploy_args.push(ManagedBuffer::from(top_encode_to_vec_u8_or_panic(&1u64)));
+    state
+        .world
+        .new_address(PROXY_DEPLOYER_ADDRESS_EXPR, 0, predicted_address.clone());
+    let deployed_address = state
+        .world
+        .tx()
+        .from(USER_ADDRESS_EXPR)
+        .to(PROXY_DEPLOYER_ADDRESS_EXPR)
+        .typed(proxy_deployer_proxy::ProxyDeployerProxy)
+        .contract_deploy(
+            TEMPLATE_CONTRACT_ADDRESS_EXPR.to_managed_address(),
+            deploy_args,
+        )
+        .returns(ReturnsResult)
+        .run()
+        .to_address();
+    assert_eq!(deployed_address, predicted_address);
+    state
+        .world
+        .query()
+        .to(&deployed_address)
+        .typed(adder_proxy::AdderProxy)
+        .sum()
+        .with_result(ExpectValue(1u64))
+        .run();
+
+    // Every deploy moves the predicted address
+    assert_ne!(state.predict_deploy_address(), predicted_address);
+    state
+        .world
+        .query()
+        .to(PROXY_DEPLOYER_ADDRESS_EXPR)
+        .typed(proxy_deployer_proxy::ProxyDeployerProxy)
+        .deploy_nonce()
+        .with_result(ExpectValue(1u64))
+        .run();
+}
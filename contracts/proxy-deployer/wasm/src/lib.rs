@@ -6,9 +6,9 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                           23
-// Async Callback (empty):               1
-// Total number of exported functions:  26
+// Endpoints:                           49
+// Async Callback:                       1
+// Total number of exported functions:  52
 
 #![no_std]
 
@@ -21,29 +21,55 @@ multiversx_sc_wasm_adapter::endpoints! {
         init => init
         upgrade => upgrade
         contractDeploy => contract_deploy
+        predictDeployAddress => predict_deploy_address
         contractUpgrade => contract_upgrade
         contractCallByAddress => contract_call_by_address
         changeOwnerAddress => change_owner
         upgradeContractsByTemplate => upgrade_contracts_by_template
         clearOngoingUpgradeOperation => clear_ongoing_upgrade_operation
+        startUpgradeRollout => start_upgrade_rollout
+        continueUpgradeRollout => continue_upgrade_rollout
+        approveUpgradeRollout => approve_upgrade_rollout
+        cancelUpgradeRollout => cancel_upgrade_rollout
+        rollbackContractUpgrade => rollback_contract_upgrade
+        getVersionDistribution => get_version_distribution
         addDeployerToBlacklist => add_deployer_to_blacklist
         removeDeployerFromBlacklist => remove_deployer_from_blacklist
         setDefaultGasForSaveOperation => set_default_gas_for_save_operation
         addTemplateAddress => add_template_address
         removeTemplateAddress => remove_template_address
+        addTemplateVersion => add_template_version
         getDeployerContractsByTemplate => get_deployer_contracts_by_template
         getAllDeployerContracts => get_all_deployer_contracts
         getAllDeployedContractsByTemplate => deployed_contracts_list_by_template
         getContractTemplate => contract_template
+        getTemplateVersions => template_versions
+        getContractVersion => contract_version
+        getContractVersionHistory => contract_version_history
         getOngoingUpgradeOperations => ongoing_upgrade_operation
+        getUpgradeRollout => upgrade_rollout
         getDefaultGasForSaveOperation => default_gas_for_save_operation
         getAllDeployers => deployers_list
         getAllTemplates => templates_list
+        getDeployNonce => deploy_nonce
         getAllBlacklistedDeployers => blacklisted_deployers_list
+        setDeployFee => set_deploy_fee
+        removeDeployFee => remove_deploy_fee
+        setDeployQuota => set_deploy_quota
+        setWhitelistOnly => set_whitelist_only
+        addDeployerToWhitelist => add_deployer_to_whitelist
+        removeDeployerFromWhitelist => remove_deployer_from_whitelist
+        withdrawFees => withdraw_fees
+        getCollectedFees => get_collected_fees
+        getDeployFee => deploy_fee
+        getDeployQuota => deploy_quota
+        getDeployCount => deploy_count
+        isWhitelistOnly => whitelist_only
+        getAllWhitelistedDeployers => whitelisted_deployers_list
         pause => pause_endpoint
         unpause => unpause_endpoint
         isPaused => paused_status
     )
 }
 
-multiversx_sc_wasm_adapter::async_callback_empty! {}
+multiversx_sc_wasm_adapter::async_callback! { proxy_deployer }